@@ -27,20 +27,28 @@
 
 #include <filesystem>
 #include <functional>
+#include <map>
 #include <memory>
+#include <optional>
 #include <string>
+#include <vector>
 
 #include "loot/api_decorator.h"
+#include "loot/enum/error_category.h"
 #include "loot/enum/game_type.h"
 #include "loot/enum/log_level.h"
+#include "loot/exception/archive_parse_error.h"
 #include "loot/exception/condition_syntax_error.h"
 #include "loot/exception/cyclic_interaction_error.h"
 #include "loot/exception/error_categories.h"
 #include "loot/exception/file_access_error.h"
 #include "loot/exception/git_state_error.h"
+#include "loot/exception/sorting_cancelled_error.h"
 #include "loot/exception/undefined_group_error.h"
 #include "loot/game_interface.h"
 #include "loot/loot_version.h"
+#include "loot/struct/build_info.h"
+#include "loot/struct/log_record.h"
 
 namespace loot {
 /**@}*/
@@ -56,9 +64,74 @@ namespace loot {
  * @param callback
  *        The function called when logging. The first parameter is the
  *        level of the message being logged, and the second is the message.
+ * @param minimumLevel
+ *        The lowest level of message that the callback is called for.
+ *        Messages below this level are not formatted, so raising the level
+ *        avoids paying for messages that would be discarded.
  */
 LOOT_API void SetLoggingCallback(
-    std::function<void(LogLevel, const char*)> callback);
+    std::function<void(LogLevel, const char*)> callback,
+    LogLevel minimumLevel = LogLevel::trace);
+
+/**
+ * @brief Set the callback function that is called when logging, passing it
+ *        the full details of each logged message.
+ * @details This replaces any callback previously set by either overload of
+ *          this function.
+ * @param callback
+ *        The function called when logging. Its parameter holds the level,
+ *        target, timestamp and text of the message being logged.
+ * @param minimumLevel
+ *        The lowest level of message that the callback is called for.
+ */
+LOOT_API void SetLoggingCallback(
+    std::function<void(const LogRecord&)> callback,
+    LogLevel minimumLevel = LogLevel::trace);
+
+/**
+ * @brief Limit how often the logging callback is called.
+ * @details Some operations (e.g. sorting a large load order) can log the
+ *          same message many times. The settings given apply to the current
+ *          logging callback and any that are set later. When messages are
+ *          collapsed or suppressed, the callback is later passed a summary
+ *          message that gives how many were affected. Summaries are passed
+ *          when the next different message is logged, and at the end of
+ *          GameInterface::LoadPlugins() and GameInterface::SortPlugins(). The
+ *          log buffer is not affected by these settings.
+ * @param collapseRepeats
+ *        If true, a message that has the same level and text as the message
+ *        before it is counted instead of being passed to the callback. Only
+ *        exact repeats are collapsed, so messages that differ only in the
+ *        plugin or group names they contain are all passed to the callback,
+ *        though they are still subject to the rate limit.
+ * @param maxRecordsPerSecond
+ *        The maximum number of messages passed to the callback in each
+ *        second. Messages over the limit are counted instead. If zero, there
+ *        is no limit.
+ */
+LOOT_API void SetLogThrottling(bool collapseRepeats,
+                               size_t maxRecordsPerSecond);
+
+/**
+ * @brief Set how many of the most recently logged messages are kept in
+ *        memory.
+ * @details Messages are buffered independently of any logging callback, so
+ *          that recent messages can be retrieved (e.g. to attach them to a bug
+ *          report) even if no callback has been set. The buffer is disabled
+ *          by default. If the capacity is reduced, the oldest buffered
+ *          messages are discarded.
+ * @param capacity
+ *        The maximum number of messages to keep. If zero, the buffer is
+ *        disabled and any buffered messages are discarded.
+ */
+LOOT_API void SetLogBufferCapacity(size_t capacity);
+
+/**
+ * @brief Get the messages held in the log buffer.
+ * @returns The buffered messages, from oldest to newest. If the buffer is
+ *          disabled, an empty vector is returned.
+ */
+LOOT_API std::vector<LogRecord> GetBufferedLogRecords();
 
 /**@}*/
 /**********************************************************************//**
@@ -83,6 +156,87 @@ LOOT_API bool IsCompatible(const unsigned int major,
                            const unsigned int minor,
                            const unsigned int patch);
 
+/**
+ *  @brief Get the version of loot-condition-interpreter that the API was
+ *         built with.
+ *  @details The version of loot-condition-interpreter determines which
+ *           condition string syntax is supported.
+ *  @returns A string of the form "major.minor.patch".
+ */
+LOOT_API std::string GetConditionInterpreterVersion();
+
+/**
+ *  @brief Get details of how the API was built.
+ *  @details This is intended to be included in bug reports, alongside the
+ *           version and revision given by LootVersion.
+ *  @returns The build details.
+ */
+LOOT_API BuildInfo GetBuildInfo();
+
+/**
+ *  @brief Get the names of the optional features that the API provides.
+ *  @details Capabilities allow clients that support several versions of the
+ *           API to check if a feature is available without comparing version
+ *           numbers. Once added, a capability is never removed without a
+ *           major version change. The capabilities are:
+ *
+ *           - ``archive-assets``: GetArchiveAssetPaths() and
+ *             GameInterface::GetAssetsOverriddenByLooseFiles()
+ *           - ``archive-cache``: GameInterface::SaveArchiveCache() and
+ *             GameInterface::LoadArchiveCache()
+ *           - ``build-info``: GetBuildInfo()
+ *           - ``condition-cache-stats``:
+ *             DatabaseInterface::GetConditionCacheStats()
+ *           - ``diagnostics-report``: GameInterface::GetDiagnosticsReport()
+ *           - ``effective-bash-tags``: GameInterface::GetEffectiveBashTags()
+ *           - ``error-categories``: GetErrorCategory()
+ *           - ``evaluated-metadata``:
+ *             DatabaseInterface::GetEvaluatedMetadata()
+ *           - ``game-version``: GameInterface::SetGameVersion() and
+ *             GameInterface::GetGameVersion()
+ *           - ``load-order-verification``: GameInterface::VerifyLoadOrder()
+ *           - ``log-buffer``: SetLogBufferCapacity() and
+ *             GetBufferedLogRecords()
+ *           - ``log-throttling``: SetLogThrottling()
+ *           - ``master-dependency-graph``:
+ *             GameInterface::GetMasterDependencyGraph()
+ *           - ``master-info``: GameInterface::GetMasterInfo()
+ *           - ``message-suppression``: Message::GetId(),
+ *             DatabaseInterface::GetSuppressedMessageIds() and
+ *             DatabaseInterface::SetSuppressedMessageIds()
+ *           - ``metadata-merge-modes``: the
+ *             DatabaseInterface::GetPluginMetadata() overload that takes
+ *             merge modes, and DatabaseInterface::GetOverriddenPluginMetadata()
+ *           - ``minimal-movement-sort``: sorting keeps unconstrained
+ *             plugins in their previous relative order, and
+ *             GameInterface::GetLastSortMovedPluginCount()
+ *           - ``operation-timings``: GameInterface::GetOperationTimings()
+ *           - ``orphaned-user-metadata``:
+ *             GameInterface::GetOrphanedUserMetadata()
+ *           - ``plugin-filename-functions``: HasPluginFileExtension(),
+ *             IsGhostedFilename() and TrimGhostExtension()
+ *           - ``plugin-integrity-issues``:
+ *             PluginInterface::GetIntegrityIssues()
+ *           - ``plugin-slot-usage``: GameInterface::GetPluginSlotUsage()
+ *           - ``redundant-user-rules``:
+ *             GameInterface::FindRedundantUserRules()
+ *           - ``sorting-progress``: the GameInterface::SortPlugins() overload
+ *             that takes a progress callback
+ *           - ``thread-safe-game-handles``: the ``thread_safe`` parameter of
+ *             CreateGameHandle() and loot_create_game_ex()
+ *  @returns The capability names, in alphabetical order.
+ */
+LOOT_API std::vector<std::string> GetCapabilities();
+
+/**
+ *  @brief Check if the API provides an optional feature.
+ *  @param capability
+ *         The name of the capability to check for. See GetCapabilities() for
+ *         the names.
+ *  @returns True if the API has the capability, false otherwise.
+ */
+LOOT_API bool HasCapability(const std::string& capability);
+
 /**@}*/
 /**********************************************************************//**
  *  @name Lifecycle Management Functions
@@ -104,12 +258,133 @@ LOOT_API bool IsCompatible(const unsigned int major,
  *         attempt to look up the path that `%%LOCALAPPDATA%` corresponds to.
  *         This parameter is provided so that systems lacking that environmental
  *         variable (eg. Linux) can still use the API.
+ *  @param thread_safe
+ *         If true, the returned handle and the database it gives access to
+ *         share a mutex that each of their functions locks for the duration
+ *         of the call, so they can be used from multiple threads at once.
+ *         Calls block until any call that is running on another thread
+ *         finishes, so a sorting progress callback must not call back into
+ *         the handle. Plugin objects returned by the handle are not
+ *         protected. If false, the handle must not be used from more than
+ *         one thread at a time.
  *  @returns The new game handle.
  */
 LOOT_API std::shared_ptr<GameInterface> CreateGameHandle(
     const GameType game,
     const std::filesystem::path& game_path,
-    const std::filesystem::path& game_local_path = "");
+    const std::filesystem::path& game_local_path = "",
+    const bool thread_safe = false);
+
+/**@}*/
+/**********************************************************************//**
+ *  @name Condition Language Functions
+ *************************************************************************/
+/**@{*/
+
+/**
+ *  @brief Get the names of the functions that can be used in condition
+ *         strings.
+ *  @details The functions are those supported by the version of
+ *           loot-condition-interpreter given by
+ *           GetConditionInterpreterVersion(). The list is maintained by hand
+ *           rather than read from loot-condition-interpreter, which does not
+ *           provide a way to list them.
+ *  @returns The function names, in the order that they are documented.
+ */
+LOOT_API std::vector<std::string> GetSupportedConditionFunctions();
+
+/**
+ *  @brief Get the operators that can be used in condition strings.
+ *  @details This includes the logical operators ("and", "or" and "not") and
+ *           the comparison operators that can be passed to version functions.
+ *           Like GetSupportedConditionFunctions(), the list is maintained by
+ *           hand for the version of loot-condition-interpreter given by
+ *           GetConditionInterpreterVersion().
+ *  @returns The operators, with the logical operators listed first.
+ */
+LOOT_API std::vector<std::string> GetSupportedConditionOperators();
+
+/**@}*/
+/**********************************************************************//**
+ *  @name Plugin Filename Functions
+ *************************************************************************/
+/**@{*/
+
+/**
+ *  @brief Check if a filename has a plugin file extension for the given game.
+ *  @details The valid extensions are ``.esp`` and ``.esm``, and also ``.esl``
+ *           for Skyrim Special Edition, Skyrim VR, Fallout 4 and Fallout 4 VR.
+ *           Any ``.ghost`` extension is ignored. The file's contents are not
+ *           checked: use GameInterface::IsValidPlugin() to do that.
+ *  @param game
+ *         The game to check the extension for.
+ *  @param filename
+ *         The filename to check.
+ *  @returns True if the filename has a plugin file extension, false otherwise.
+ */
+LOOT_API bool HasPluginFileExtension(const GameType game,
+                                     const std::string& filename);
+
+/**
+ *  @brief Check if a filename is ghosted.
+ *  @param filename
+ *         The filename to check.
+ *  @returns True if the filename ends with ``.ghost`` (case-insensitively),
+ *           false otherwise.
+ */
+LOOT_API bool IsGhostedFilename(const std::string& filename);
+
+/**
+ *  @brief Get a filename without its ``.ghost`` extension.
+ *  @details This gives the name that LOOT uses for a ghosted plugin.
+ *  @param filename
+ *         The filename to trim.
+ *  @returns The filename without any ``.ghost`` extension.
+ */
+LOOT_API std::string TrimGhostExtension(const std::string& filename);
+
+/**@}*/
+/**********************************************************************//**
+ *  @name Archive Functions
+ *************************************************************************/
+/**@{*/
+
+/**
+ *  @brief Get the paths of the assets contained in the given archives.
+ *  @details Supports the BSA and BA2 formats used by all games apart from
+ *           Morrowind. Archives don't always store the names of the folders
+ *           and files they contain, and any unknown name is given as its
+ *           hash in hexadecimal form instead. An ArchiveParseError is thrown
+ *           if an archive's contents are invalid or use an unsupported
+ *           variant of its format.
+ *  @param archive_paths
+ *         The paths of the archives to read.
+ *  @returns A map of archive paths to the paths of the assets each archive
+ *           contains, using backslashes as separators.
+ */
+LOOT_API std::map<std::filesystem::path, std::vector<std::string>>
+GetArchiveAssetPaths(const std::vector<std::filesystem::path>& archive_paths);
+
+/**@}*/
+/**********************************************************************//**
+ *  @name Error Handling Functions
+ *************************************************************************/
+/**@{*/
+
+/**
+ *  @brief Get the category of an exception thrown by libloot.
+ *  @details Exceptions that hold a std::error_code (including those from
+ *           libloadorder and libgit2) are categorised as I/O errors, as are
+ *           FileAccessError exceptions. The std::regex_error thrown for an
+ *           invalid regular expression plugin name is categorised as an
+ *           invalid argument.
+ *  @param error
+ *         The exception to categorise.
+ *  @returns The exception's category, or no value if it is not one that
+ *           libloot throws.
+ */
+LOOT_API std::optional<ErrorCategory> GetErrorCategory(
+    const std::exception& error);
 }
 
 #endif
@@ -0,0 +1,593 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_CAPI_H
+#define LOOT_CAPI_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#include "loot/api_decorator.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**********************************************************************//**
+ *  @name C API Types
+ *************************************************************************/
+/**@{*/
+
+/**
+ * @brief The codes returned by C API functions.
+ * @details Any code other than LOOT_OK indicates that the function failed,
+ *          and loot_get_last_error_message() can be used to get more detail.
+ *          The values of existing codes will not change.
+ *
+ *          Each code corresponds to the type of exception that the C++ API
+ *          threw: for example, LOOT_ERROR_CYCLIC_INTERACTION corresponds to
+ *          loot::CyclicInteractionError. LOOT_ERROR_METADATA_PARSE is used for
+ *          masterlist and userlist syntax errors.
+ *
+ *          LOOT_ERROR_FILE_ACCESS is used when libloot could not read or write
+ *          a file that it was asked to handle, and the error message names
+ *          the file (loot::FileAccessError). LOOT_ERROR_IO is used for
+ *          filesystem and stream errors that were not attributed to a file
+ *          (std::filesystem::filesystem_error and std::ios_base::failure).
+ *          loot::GetErrorCategory() categorises both as I/O errors.
+ *
+ *          LOOT_ERROR_GAME_NOT_FOUND is returned by loot_create_game() if the
+ *          game path is not a directory, and LOOT_ERROR_PLUGIN_NOT_LOADED is
+ *          returned by functions that need a plugin to have been loaded.
+ *          Other invalid arguments, including invalid plugin name regular
+ *          expressions, are reported as LOOT_ERROR_INVALID_ARGUMENT, and other
+ *          system errors as LOOT_ERROR_SYSTEM.
+ */
+typedef enum loot_error_code {
+  LOOT_OK = 0,
+  LOOT_ERROR_INVALID_ARGUMENT = 1,
+  LOOT_ERROR_FILE_ACCESS = 2,
+  LOOT_ERROR_ARCHIVE_PARSE = 3,
+  LOOT_ERROR_CONDITION_SYNTAX = 4,
+  LOOT_ERROR_CYCLIC_INTERACTION = 5,
+  LOOT_ERROR_UNDEFINED_GROUP = 6,
+  LOOT_ERROR_GIT_STATE = 7,
+  LOOT_ERROR_SYSTEM = 8,
+  LOOT_ERROR_UNKNOWN = 9,
+  LOOT_ERROR_SORTING_CANCELLED = 10,
+  LOOT_ERROR_METADATA_PARSE = 11,
+  LOOT_ERROR_IO = 12,
+  LOOT_ERROR_GAME_NOT_FOUND = 13,
+  LOOT_ERROR_PLUGIN_NOT_LOADED = 14,
+} loot_error_code;
+
+/**
+ * @brief How to handle an output file that already exists.
+ * @details The values correspond to those of loot::WriteMode.
+ */
+typedef enum loot_write_mode {
+  /** @brief Only write the file if it does not already exist. */
+  LOOT_WRITE_MODE_CREATE = 0,
+  /** @brief Write the file, replacing its content if it exists. */
+  LOOT_WRITE_MODE_CREATE_OR_TRUNCATE = 1,
+} loot_write_mode;
+
+/** @brief An opaque handle to a game and its metadata database. */
+typedef struct loot_game loot_game;
+
+/** @brief An opaque handle to a plugin's metadata. */
+typedef struct loot_plugin_metadata loot_plugin_metadata;
+
+/**
+ * @brief An array of strings allocated by libloot.
+ * @details Free it using loot_free_string_array().
+ */
+typedef struct loot_string_array {
+  char** items;
+  size_t count;
+} loot_string_array;
+
+/** @brief A message with its text in a single language. */
+typedef struct loot_message {
+  /** @brief The message's type, using the values of loot::MessageType. */
+  unsigned int type;
+  /** @brief The message's text. */
+  char* text;
+} loot_message;
+
+/**
+ * @brief An array of messages allocated by libloot.
+ * @details Free it using loot_free_message_array().
+ */
+typedef struct loot_message_array {
+  loot_message* items;
+  size_t count;
+} loot_message_array;
+
+/**
+ * @brief The function type used for logging callbacks.
+ * @details The first parameter is the level of the message, using the values
+ *          of loot::LogLevel. The second is the message, and the third is the
+ *          context pointer that was passed when setting the callback.
+ */
+typedef void (*loot_logging_callback)(unsigned int, const char*, void*);
+
+/**
+ * @brief The function type used for sorting progress callbacks.
+ * @details The parameters are the name of the phase that is about to start,
+ *          the zero-based index of that phase, the total number of phases,
+ *          and the context pointer that was passed when sorting. Return false
+ *          to cancel sorting.
+ */
+typedef bool (*loot_sorting_progress_callback)(const char*,
+                                               size_t,
+                                               size_t,
+                                               void*);
+
+/**@}*/
+/**********************************************************************//**
+ *  @name C API Memory and Error Functions
+ *************************************************************************/
+/**@{*/
+
+/**
+ * @brief Get the message for the last error that occurred on this thread.
+ * @returns A null-terminated string that is valid until the next C API call
+ *          on this thread. It is empty if no error has occurred.
+ */
+LOOT_API const char* loot_get_last_error_message(void);
+
+/** @brief Free a string that was allocated by libloot. */
+LOOT_API void loot_free_string(char* string);
+
+/** @brief Free a string array that was allocated by libloot. */
+LOOT_API void loot_free_string_array(loot_string_array* array);
+
+/** @brief Free a message array that was allocated by libloot. */
+LOOT_API void loot_free_message_array(loot_message_array* array);
+
+/**@}*/
+/**********************************************************************//**
+ *  @name C API General Functions
+ *************************************************************************/
+/**@{*/
+
+/**
+ * @brief Get the libloot version.
+ * @param version
+ *        Set to a string of the form "major.minor.patch", which must be
+ *        freed using loot_free_string().
+ */
+LOOT_API loot_error_code loot_get_version(char** version);
+
+/** @brief Equivalent to loot::IsCompatible(). */
+LOOT_API bool loot_is_compatible(unsigned int major,
+                                 unsigned int minor,
+                                 unsigned int patch);
+
+/**
+ * @brief Equivalent to loot::HasCapability().
+ * @details Returns false if capability is null.
+ */
+LOOT_API bool loot_has_capability(const char* capability);
+
+/**
+ * @brief Set the callback function that is called when logging.
+ * @param callback
+ *        The function to call, or null to stop logging.
+ * @param context
+ *        A pointer that is passed to the callback as-is.
+ */
+LOOT_API loot_error_code loot_set_logging_callback(
+    loot_logging_callback callback,
+    void* context);
+
+/**@}*/
+/**********************************************************************//**
+ *  @name C API Game Functions
+ *************************************************************************/
+/**@{*/
+
+/**
+ * @brief Equivalent to loot::CreateGameHandle().
+ * @param game_type
+ *        The game type, using the values of loot::GameType.
+ * @param game_path
+ *        The UTF-8 encoded path to the game's install directory.
+ * @param game_local_path
+ *        The UTF-8 encoded path to the game's local data directory, or null.
+ * @param game
+ *        Set to the new game handle, which must be freed using
+ *        loot_destroy_game().
+ */
+LOOT_API loot_error_code loot_create_game(unsigned int game_type,
+                                          const char* game_path,
+                                          const char* game_local_path,
+                                          loot_game** game);
+
+/**
+ * @brief Equivalent to loot::CreateGameHandle(), including its
+ *        ``thread_safe`` parameter.
+ * @details loot_create_game() is equivalent to calling this with
+ *          ``thread_safe`` set to false.
+ * @param game_type
+ *        The game type, using the values of loot::GameType.
+ * @param game_path
+ *        The UTF-8 encoded path to the game's install directory.
+ * @param game_local_path
+ *        The UTF-8 encoded path to the game's local data directory, or null.
+ * @param thread_safe
+ *        If true, the game handle can be used from multiple threads at once.
+ * @param game
+ *        Set to the new game handle, which must be freed using
+ *        loot_destroy_game().
+ */
+LOOT_API loot_error_code loot_create_game_ex(unsigned int game_type,
+                                             const char* game_path,
+                                             const char* game_local_path,
+                                             bool thread_safe,
+                                             loot_game** game);
+
+/** @brief Free a game handle. */
+LOOT_API void loot_destroy_game(loot_game* game);
+
+/** @brief Equivalent to loot::GameInterface::IsValidPlugin(). */
+LOOT_API loot_error_code loot_game_is_valid_plugin(loot_game* game,
+                                                   const char* plugin,
+                                                   bool* is_valid);
+
+/** @brief Equivalent to loot::GameInterface::LoadPlugins(). */
+LOOT_API loot_error_code loot_game_load_plugins(loot_game* game,
+                                                const char* const* plugins,
+                                                size_t plugin_count,
+                                                bool load_headers_only);
+
+/**
+ * @brief Get information about a loaded plugin.
+ * @details Any of the output parameters may be null if that information is
+ *          not needed. `version` is set to null if the plugin has no version.
+ *          Strings and arrays must be freed using loot_free_string() and
+ *          loot_free_string_array() respectively.
+ */
+LOOT_API loot_error_code loot_game_get_plugin_info(loot_game* game,
+                                                   const char* plugin,
+                                                   char** version,
+                                                   loot_string_array* masters,
+                                                   bool* is_master,
+                                                   bool* is_light_plugin,
+                                                   bool* is_empty);
+
+/** @brief Equivalent to loot::GameInterface::IdentifyMainMasterFile(). */
+LOOT_API loot_error_code loot_game_identify_main_master_file(
+    loot_game* game,
+    const char* master_file);
+
+/**
+ * @brief Equivalent to loot::GameInterface::SortPlugins().
+ * @param sorted_plugins
+ *        Set to the sorted plugins, which must be freed using
+ *        loot_free_string_array().
+ */
+LOOT_API loot_error_code loot_game_sort_plugins(
+    loot_game* game,
+    const char* const* plugins,
+    size_t plugin_count,
+    loot_string_array* sorted_plugins);
+
+/**
+ * @brief Equivalent to loot::GameInterface::SortPlugins() with a progress
+ *        callback.
+ * @details If the callback cancels sorting, LOOT_ERROR_SORTING_CANCELLED is
+ *          returned and sorted_plugins is left unchanged.
+ * @param callback
+ *        The function to call before each phase of sorting. May be null.
+ * @param context
+ *        A pointer that is passed to the callback unchanged.
+ * @param sorted_plugins
+ *        Set to the sorted plugins, which must be freed using
+ *        loot_free_string_array().
+ */
+LOOT_API loot_error_code loot_game_sort_plugins_with_progress(
+    loot_game* game,
+    const char* const* plugins,
+    size_t plugin_count,
+    loot_sorting_progress_callback callback,
+    void* context,
+    loot_string_array* sorted_plugins);
+
+/** @brief Equivalent to loot::GameInterface::LoadCurrentLoadOrderState(). */
+LOOT_API loot_error_code loot_game_load_current_load_order_state(
+    loot_game* game);
+
+/** @brief Equivalent to loot::GameInterface::IsPluginActive(). */
+LOOT_API loot_error_code loot_game_is_plugin_active(loot_game* game,
+                                                    const char* plugin,
+                                                    bool* is_active);
+
+/**
+ * @brief Equivalent to loot::GameInterface::GetLoadOrder().
+ * @param load_order
+ *        Set to the load order, which must be freed using
+ *        loot_free_string_array().
+ */
+LOOT_API loot_error_code loot_game_get_load_order(
+    loot_game* game,
+    loot_string_array* load_order);
+
+/** @brief Equivalent to loot::GameInterface::SetLoadOrder(). */
+LOOT_API loot_error_code loot_game_set_load_order(loot_game* game,
+                                                  const char* const* plugins,
+                                                  size_t plugin_count);
+
+/**@}*/
+/**********************************************************************//**
+ *  @name C API Database Functions
+ *************************************************************************/
+/**@{*/
+
+/**
+ * @brief Equivalent to loot::DatabaseInterface::LoadLists().
+ * @param userlist_path
+ *        The UTF-8 encoded path to the userlist, or null.
+ */
+LOOT_API loot_error_code loot_db_load_lists(loot_game* game,
+                                            const char* masterlist_path,
+                                            const char* userlist_path);
+
+/** @brief Equivalent to loot::DatabaseInterface::WriteUserMetadata(). */
+LOOT_API loot_error_code loot_db_write_user_metadata(loot_game* game,
+                                                     const char* output_path,
+                                                     loot_write_mode mode);
+
+/** @brief Equivalent to loot::DatabaseInterface::WriteMinimalList(). */
+LOOT_API loot_error_code loot_db_write_minimal_list(loot_game* game,
+                                                    const char* output_path,
+                                                    loot_write_mode mode);
+
+/** @brief Equivalent to loot::DatabaseInterface::UpdateMasterlist(). */
+LOOT_API loot_error_code loot_db_update_masterlist(loot_game* game,
+                                                   const char* masterlist_path,
+                                                   const char* remote_url,
+                                                   const char* remote_branch,
+                                                   bool* was_updated);
+
+/**
+ * @brief Equivalent to loot::DatabaseInterface::GetMasterlistRevision().
+ * @details `revision_id` and `revision_date` must be freed using
+ *          loot_free_string().
+ */
+LOOT_API loot_error_code loot_db_get_masterlist_revision(
+    loot_game* game,
+    const char* masterlist_path,
+    bool get_short_id,
+    char** revision_id,
+    char** revision_date,
+    bool* is_modified);
+
+/** @brief Equivalent to loot::DatabaseInterface::IsLatestMasterlist(). */
+LOOT_API loot_error_code loot_db_is_latest_masterlist(
+    loot_game* game,
+    const char* masterlist_path,
+    const char* branch,
+    bool* is_latest);
+
+/** @brief Equivalent to loot::DatabaseInterface::GetKnownBashTags(). */
+LOOT_API loot_error_code loot_db_get_known_bash_tags(loot_game* game,
+                                                     loot_string_array* tags);
+
+/**
+ * @brief Equivalent to loot::DatabaseInterface::GetGeneralMessages().
+ * @param language
+ *        The language code to choose message text for.
+ */
+LOOT_API loot_error_code loot_db_get_general_messages(
+    loot_game* game,
+    const char* language,
+    bool evaluate_conditions,
+    loot_message_array* messages);
+
+/**
+ * @brief Get the names of the groups defined in the loaded metadata.
+ */
+LOOT_API loot_error_code loot_db_get_group_names(loot_game* game,
+                                                 bool include_user_metadata,
+                                                 loot_string_array* groups);
+
+/** @brief Equivalent to loot::DatabaseInterface::ValidateCondition(). */
+LOOT_API loot_error_code loot_db_validate_condition(loot_game* game,
+                                                   const char* condition);
+
+/**
+ * @brief Evaluate a condition string.
+ * @param result
+ *        Set to the result of the evaluation.
+ */
+LOOT_API loot_error_code loot_db_evaluate_condition(loot_game* game,
+                                                   const char* condition,
+                                                   bool* result);
+
+/** @brief Equivalent to loot::DatabaseInterface::ClearConditionCache(). */
+LOOT_API loot_error_code loot_db_clear_condition_cache(loot_game* game);
+
+/**
+ * @brief Equivalent to loot::DatabaseInterface::GetPluginMetadata().
+ * @param metadata
+ *        Set to the plugin's metadata, or null if there is none. A non-null
+ *        handle must be freed using loot_destroy_plugin_metadata().
+ */
+LOOT_API loot_error_code loot_db_get_plugin_metadata(
+    loot_game* game,
+    const char* plugin,
+    bool include_user_metadata,
+    bool evaluate_conditions,
+    loot_plugin_metadata** metadata);
+
+/**
+ * @brief Equivalent to loot::DatabaseInterface::GetPluginUserMetadata().
+ * @param metadata
+ *        Set to the plugin's user metadata, or null if there is none. A
+ *        non-null handle must be freed using loot_destroy_plugin_metadata().
+ */
+LOOT_API loot_error_code loot_db_get_plugin_user_metadata(
+    loot_game* game,
+    const char* plugin,
+    bool evaluate_conditions,
+    loot_plugin_metadata** metadata);
+
+/** @brief Equivalent to loot::DatabaseInterface::SetPluginUserMetadata(). */
+LOOT_API loot_error_code loot_db_set_plugin_user_metadata(
+    loot_game* game,
+    const loot_plugin_metadata* metadata);
+
+/**
+ * @brief Equivalent to loot::DatabaseInterface::DiscardPluginUserMetadata().
+ */
+LOOT_API loot_error_code loot_db_discard_plugin_user_metadata(
+    loot_game* game,
+    const char* plugin);
+
+/** @brief Equivalent to loot::DatabaseInterface::DiscardAllUserMetadata(). */
+LOOT_API loot_error_code loot_db_discard_all_user_metadata(loot_game* game);
+
+/**@}*/
+/**********************************************************************//**
+ *  @name C API Plugin Metadata Functions
+ *************************************************************************/
+/**@{*/
+
+/**
+ * @brief Create an empty metadata object for the given plugin.
+ * @param metadata
+ *        Set to the new handle, which must be freed using
+ *        loot_destroy_plugin_metadata().
+ */
+LOOT_API loot_error_code loot_create_plugin_metadata(
+    const char* plugin,
+    loot_plugin_metadata** metadata);
+
+/** @brief Free a plugin metadata handle. */
+LOOT_API void loot_destroy_plugin_metadata(loot_plugin_metadata* metadata);
+
+/**
+ * @brief Get the plugin's group.
+ * @param group
+ *        Set to the group name, or null if no group is set. A non-null
+ *        string must be freed using loot_free_string().
+ */
+LOOT_API loot_error_code loot_plugin_metadata_get_group(
+    const loot_plugin_metadata* metadata,
+    char** group);
+
+/**
+ * @brief Set the plugin's group.
+ * @param group
+ *        The group name, or null to unset the group.
+ */
+LOOT_API loot_error_code loot_plugin_metadata_set_group(
+    loot_plugin_metadata* metadata,
+    const char* group);
+
+/** @brief Get the filenames of the plugin's load after metadata. */
+LOOT_API loot_error_code loot_plugin_metadata_get_load_after(
+    const loot_plugin_metadata* metadata,
+    loot_string_array* files);
+
+/**
+ * @brief Set the plugin's load after metadata.
+ * @details The files are given no display names or conditions.
+ */
+LOOT_API loot_error_code loot_plugin_metadata_set_load_after(
+    loot_plugin_metadata* metadata,
+    const char* const* files,
+    size_t file_count);
+
+/** @brief Get the filenames of the plugin's requirements. */
+LOOT_API loot_error_code loot_plugin_metadata_get_requirements(
+    const loot_plugin_metadata* metadata,
+    loot_string_array* files);
+
+/**
+ * @brief Set the plugin's requirements.
+ * @details The files are given no display names or conditions.
+ */
+LOOT_API loot_error_code loot_plugin_metadata_set_requirements(
+    loot_plugin_metadata* metadata,
+    const char* const* files,
+    size_t file_count);
+
+/** @brief Get the filenames of the plugin's incompatibilities. */
+LOOT_API loot_error_code loot_plugin_metadata_get_incompatibilities(
+    const loot_plugin_metadata* metadata,
+    loot_string_array* files);
+
+/**
+ * @brief Set the plugin's incompatibilities.
+ * @details The files are given no display names or conditions.
+ */
+LOOT_API loot_error_code loot_plugin_metadata_set_incompatibilities(
+    loot_plugin_metadata* metadata,
+    const char* const* files,
+    size_t file_count);
+
+/**
+ * @brief Get the plugin's Bash Tag suggestions.
+ * @param added_tags
+ *        Set to the names of tags suggested for addition.
+ * @param removed_tags
+ *        Set to the names of tags suggested for removal.
+ */
+LOOT_API loot_error_code loot_plugin_metadata_get_tags(
+    const loot_plugin_metadata* metadata,
+    loot_string_array* added_tags,
+    loot_string_array* removed_tags);
+
+/**
+ * @brief Set the plugin's Bash Tag suggestions.
+ * @details The tags are given no conditions.
+ */
+LOOT_API loot_error_code loot_plugin_metadata_set_tags(
+    loot_plugin_metadata* metadata,
+    const char* const* added_tags,
+    size_t added_tag_count,
+    const char* const* removed_tags,
+    size_t removed_tag_count);
+
+/**
+ * @brief Get the plugin's messages.
+ * @param language
+ *        The language code to choose message text for.
+ */
+LOOT_API loot_error_code loot_plugin_metadata_get_messages(
+    const loot_plugin_metadata* metadata,
+    const char* language,
+    loot_message_array* messages);
+
+/**@}*/
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
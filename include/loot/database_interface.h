@@ -29,10 +29,13 @@
 #include <string>
 #include <vector>
 
+#include "loot/enum/write_mode.h"
 #include "loot/exception/cyclic_interaction_error.h"
 #include "loot/metadata/group.h"
 #include "loot/metadata/message.h"
 #include "loot/metadata/plugin_metadata.h"
+#include "loot/struct/condition_cache_stats.h"
+#include "loot/struct/evaluated_metadata.h"
 #include "loot/struct/masterlist_info.h"
 #include "loot/struct/simple_message.h"
 
@@ -68,8 +71,23 @@ public:
    *         If `false` and `outputFile` already exists, no data will be
    *         written. Otherwise, data will be written.
    */
+  void WriteUserMetadata(const std::filesystem::path& outputFile,
+                         const bool overwrite) const {
+    WriteUserMetadata(
+        outputFile, overwrite ? WriteMode::createOrTruncate : WriteMode::create);
+  }
+
+  /**
+   * Writes a metadata file containing all loaded user-added metadata.
+   * @details A FileAccessError is thrown if `outputFile` already exists and
+   *          the write mode does not allow it to be replaced.
+   * @param outputFile
+   *         The path to which the file shall be written.
+   * @param mode
+   *         How to handle `outputFile` already existing.
+   */
   virtual void WriteUserMetadata(const std::filesystem::path& outputFile,
-                                 const bool overwrite) const = 0;
+                                 const WriteMode mode) const = 0;
 
   /**
    *  @brief Writes a minimal metadata file that only contains plugins with
@@ -81,8 +99,25 @@ public:
    *         If `false` and `outputFile` already exists, no data will be
    *         written. Otherwise, data will be written.
    */
+  void WriteMinimalList(const std::filesystem::path& outputFile,
+                        const bool overwrite) const {
+    WriteMinimalList(
+        outputFile, overwrite ? WriteMode::createOrTruncate : WriteMode::create);
+  }
+
+  /**
+   *  @brief Writes a minimal metadata file that only contains plugins with
+   *         Bash Tag suggestions and/or dirty info, plus the suggestions and
+   *         info themselves.
+   *  @details A FileAccessError is thrown if `outputFile` already exists and
+   *           the write mode does not allow it to be replaced.
+   *  @param outputFile
+   *         The path to which the file shall be written.
+   *  @param mode
+   *         How to handle `outputFile` already existing.
+   */
   virtual void WriteMinimalList(const std::filesystem::path& outputFile,
-                                const bool overwrite) const = 0;
+                                const WriteMode mode) const = 0;
 
   /**
    *  @}
@@ -184,6 +219,28 @@ public:
   virtual std::vector<Message> GetGeneralMessages(
       bool evaluateConditions = false) const = 0;
 
+  /**
+   *  @brief Gets the IDs of messages that the user has suppressed.
+   *  @details Messages with a matching ID are omitted from the messages
+   *           returned by GetGeneralMessages(), GetPluginMetadata() and
+   *           GetEvaluatedMetadata(), whether or not user metadata is
+   *           included. They are not omitted by GetPluginUserMetadata(), so
+   *           that user metadata can be edited and set again without losing
+   *           messages.
+   *  @returns A vector of message IDs.
+   */
+  virtual std::vector<std::string> GetSuppressedMessageIds() const = 0;
+
+  /**
+   *  @brief Sets the IDs of messages that the user has suppressed.
+   *  @details The IDs are stored in the userlist, replacing any that were
+   *           previously set, and are saved by WriteUserMetadata(). Messages
+   *           without an ID cannot be suppressed.
+   *  @param ids
+   *         The IDs of the messages to suppress.
+   */
+  virtual void SetSuppressedMessageIds(const std::vector<std::string>& ids) = 0;
+
   /**
    * @brief Gets the groups that are defined in the loaded metadata lists.
    * @param includeUserMetadata
@@ -259,6 +316,48 @@ public:
       bool includeUserMetadata = true,
       bool evaluateConditions = false) const = 0;
 
+  /**
+   *  @brief Get all a plugin's loaded metadata, merging its user metadata
+   *         with its masterlist metadata using the given merge modes.
+   *  @param plugin
+   *         The filename of the plugin to look up metadata for.
+   *  @param mergeModes
+   *         How each type of list metadata in the user metadata is merged
+   *         with the same type of metadata in the masterlist.
+   *  @param evaluateConditions
+   *         If true, any metadata conditions are evaluated before the metadata
+   *         is returned, otherwise unevaluated metadata is returned. Evaluating
+   *         plugin metadata conditions does not clear the condition cache.
+   *  @returns If the plugin has metadata, an optional containing that metadata,
+   *           otherwise an optional containing no value.
+   */
+  virtual std::optional<PluginMetadata> GetPluginMetadata(
+      const std::string& plugin,
+      const MetadataMergeModes& mergeModes,
+      bool evaluateConditions = false) const = 0;
+
+  /**
+   *  @brief Get the masterlist metadata for a plugin that is left out when its
+   *         user metadata is merged with it.
+   *  @details This previews the effect of merging with the given merge modes:
+   *           a masterlist group is overridden if the user metadata sets a
+   *           different group, and a type of masterlist list metadata is
+   *           overridden if its merge mode is MergeMode::replace and the user
+   *           metadata has metadata of that type. Conditions are not
+   *           evaluated.
+   *  @param plugin
+   *         The filename of the plugin to look up metadata for.
+   *  @param mergeModes
+   *         How each type of list metadata in the user metadata is merged
+   *         with the same type of metadata in the masterlist.
+   *  @returns If any of the plugin's masterlist metadata is overridden, an
+   *           optional containing that metadata, otherwise an optional
+   *           containing no value.
+   */
+  virtual std::optional<PluginMetadata> GetOverriddenPluginMetadata(
+      const std::string& plugin,
+      const MetadataMergeModes& mergeModes) const = 0;
+
   /**
    *  @brief Get a plugin's metadata loaded from the given userlist.
    *  @param plugin
@@ -274,6 +373,27 @@ public:
       const std::string& plugin,
       bool evaluateConditions = false) const = 0;
 
+  /**
+   *  @brief Get the general messages and the metadata for the given plugins,
+   *         with all their conditions evaluated at the same time.
+   *  @details The condition cache is cleared once before any conditions are
+   *           evaluated, so the returned metadata is consistent with a single
+   *           state of the game. This is equivalent to calling
+   *           GetGeneralMessages() and then GetPluginMetadata() for each
+   *           plugin, with conditions evaluated.
+   *  @param plugins
+   *         The filenames of the plugins to get metadata for.
+   *  @param includeUserMetadata
+   *         If true, any user metadata the plugins have is included in the
+   *         returned metadata, otherwise the metadata returned only includes
+   *         metadata from the masterlist.
+   *  @returns The evaluated general messages and plugin metadata. Plugins that
+   *           have no metadata are not included.
+   */
+  virtual EvaluatedMetadata GetEvaluatedMetadata(
+      const std::vector<std::string>& plugins,
+      bool includeUserMetadata = true) const = 0;
+
   /**
    *  @brief Sets a plugin's user metadata, overwriting any existing user
    *         metadata.
@@ -298,6 +418,90 @@ public:
    */
   virtual void DiscardAllUserMetadata() = 0;
 
+  /**
+   *  @}
+   *  @name Conditions
+   *  @{
+   */
+
+  /**
+   * @brief Check that a condition string is syntactically valid.
+   * @details The condition is parsed but not evaluated, so the filesystem is
+   *          not accessed. As well as the condition's structure, parsing
+   *          checks that the functions it uses exist and are given valid
+   *          arguments. If the condition is invalid, a ConditionSyntaxError
+   *          is thrown with loot-condition-interpreter's error message.
+   *          loot-condition-interpreter does not report the position at which
+   *          parsing failed, so the exception does not provide one, though the
+   *          message may quote the part of the condition that could not be
+   *          parsed.
+   * @param condition
+   *        The condition string to check.
+   */
+  virtual void ValidateCondition(const std::string& condition) const = 0;
+
+  /**
+   * @brief Evaluate multiple condition strings.
+   * @details This is a convenience function that evaluates each condition in
+   *          turn, using and adding to the condition cache in the same way
+   *          as metadata evaluation does. The condition cache is not cleared
+   *          before evaluation. If a condition is invalid, a
+   *          ConditionSyntaxError is thrown and no results are returned,
+   *          though the conditions before it will have been evaluated and
+   *          cached.
+   * @param conditions
+   *        The condition strings to evaluate. An empty string always
+   *        evaluates to true.
+   * @returns A vector of results, one per condition in the same order as the
+   *          given conditions.
+   */
+  virtual std::vector<bool> EvaluateConditions(
+      const std::vector<std::string>& conditions) const = 0;
+
+  /**
+   * @brief Evaluate multiple condition strings as if the given plugins were
+   *        the only active plugins.
+   * @details This can be used to preview the effect of activating or
+   *          deactivating plugins without changing the load order. Conditions
+   *          that check if plugins are active use the given plugins instead of
+   *          the current load order state, while other conditions are
+   *          evaluated as usual. The condition cache is neither used nor
+   *          changed. If any condition is invalid, a ConditionSyntaxError is
+   *          thrown and no results are returned.
+   * @param conditions
+   *        The condition strings to evaluate. An empty string always
+   *        evaluates to true.
+   * @param activePlugins
+   *        The filenames of the plugins to treat as active.
+   * @returns A vector of results, one per condition in the same order as the
+   *          given conditions.
+   */
+  virtual std::vector<bool> EvaluateConditionsWithActivePlugins(
+      const std::vector<std::string>& conditions,
+      const std::vector<std::string>& activePlugins) const = 0;
+
+  /**
+   * @brief Clear the condition cache.
+   * @details The results of evaluating conditions are cached until the cache
+   *          is cleared. The cache is cleared automatically when plugins are
+   *          loaded or the load order state is loaded, but should also be
+   *          cleared if the contents of the game's data path change in some
+   *          other way, e.g. if a mod is installed.
+   */
+  virtual void ClearConditionCache() = 0;
+
+  /**
+   * @brief Get statistics about the condition cache.
+   * @details libloot caches the result of each whole condition string that it
+   *          evaluates, and the statistics only describe that cache.
+   *          loot-condition-interpreter also caches the results of the
+   *          individual functions in a condition string, but does not provide
+   *          statistics about its cache. Both caches are cleared together.
+   * @returns The number of cached condition results, and the number of cache
+   *          hits and misses since the cache was last cleared.
+   */
+  virtual ConditionCacheStats GetConditionCacheStats() const = 0;
+
   /** @} */
 };
 }
@@ -0,0 +1,54 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_ERROR_CATEGORY
+#define LOOT_ERROR_CATEGORY
+
+/**
+ * The namespace used by libloot.
+ */
+namespace loot {
+/**
+ * @brief Codes used to group the exceptions that libloot throws by their
+ *        cause, so that they can be handled without inspecting their
+ *        messages.
+ */
+enum struct ErrorCategory : unsigned int {
+  /** A file or directory could not be read or written. */
+  io,
+  /** The contents of a metadata file, plugin or archive were invalid. */
+  parse,
+  /** A metadata condition string was invalid or could not be evaluated. */
+  condition,
+  /** Sorting failed or was cancelled. */
+  sorting,
+  /** A function was given an invalid argument. */
+  invalidArgument,
+  /** An operation was not possible in the current state, e.g. of a Git
+      repository. */
+  state,
+};
+}
+
+#endif
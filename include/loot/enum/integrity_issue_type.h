@@ -0,0 +1,47 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_INTEGRITY_ISSUE_TYPE
+#define LOOT_INTEGRITY_ISSUE_TYPE
+
+namespace loot {
+/**
+ * @brief Codes used to identify the structural problems that can be found in
+ *        a plugin.
+ */
+enum struct IntegrityIssueType : unsigned int {
+  /** A record or group extends past the end of the data that contains it. */
+  truncatedData,
+  /**
+   * A record's FormID has a mod index that is greater than the plugin's
+   * number of masters, so it does not belong to any master or to the plugin
+   * itself.
+   */
+  invalidFormId,
+  /** A record's subrecord sizes don't add up to the record's data size. */
+  subrecordSizeMismatch,
+};
+}
+
+#endif
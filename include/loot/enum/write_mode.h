@@ -0,0 +1,40 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_WRITE_MODE
+#define LOOT_WRITE_MODE
+
+namespace loot {
+/**
+ * @brief Codes used to specify how a file is written if it already exists.
+ */
+enum struct WriteMode : unsigned int {
+  /** Only write the file if it does not already exist. */
+  create,
+  /** Write the file, replacing the existing file's content if it exists. */
+  createOrTruncate,
+};
+}
+
+#endif
@@ -0,0 +1,67 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_EXCEPTION_ARCHIVE_PARSE_ERROR
+#define LOOT_EXCEPTION_ARCHIVE_PARSE_ERROR
+
+#include <filesystem>
+#include <string>
+
+#include "loot/api_decorator.h"
+#include "loot/exception/file_access_error.h"
+
+namespace loot {
+/**
+ * @brief An exception class thrown if an archive could be read but its
+ *        contents could not be parsed.
+ */
+class ArchiveParseError : public FileAccessError {
+public:
+  /**
+   * @brief Construct an exception for an archive that could not be parsed.
+   * @param archivePath The path of the archive.
+   * @param details A description of why the archive could not be parsed.
+   */
+  LOOT_API ArchiveParseError(const std::filesystem::path& archivePath,
+                             const std::string& details);
+
+  /**
+   * Get the path of the archive that could not be parsed.
+   * @return A filesystem path.
+   */
+  LOOT_API std::filesystem::path GetArchivePath() const;
+
+  /**
+   * Get a description of why the archive could not be parsed.
+   * @return A string description.
+   */
+  LOOT_API std::string GetDetails() const;
+
+private:
+  const std::filesystem::path archivePath_;
+  const std::string details_;
+};
+}
+
+#endif
@@ -25,16 +25,40 @@
 #ifndef LOOT_EXCEPTION_FILE_ACCESS_ERROR
 #define LOOT_EXCEPTION_FILE_ACCESS_ERROR
 
+#include <filesystem>
 #include <stdexcept>
+#include <string>
+
+#include "loot/api_decorator.h"
 
 namespace loot {
 /**
  * @brief An exception class thrown if an error is encountered while reading or
  *        writing a file.
+ * @details If the error was caused by another exception, the thrown object
+ *          also derives from std::nested_exception, so the cause can be
+ *          retrieved using std::rethrow_if_nested().
  */
 class FileAccessError : public std::runtime_error {
 public:
   using std::runtime_error::runtime_error;
+
+  /**
+   * @brief Construct an exception for an error involving the given path.
+   * @param what A description of the error.
+   * @param path The path of the file that could not be accessed.
+   */
+  LOOT_API FileAccessError(const std::string& what,
+                           const std::filesystem::path& path);
+
+  /**
+   * Get the path of the file that could not be accessed.
+   * @return A filesystem path. It is empty if the path is not known.
+   */
+  LOOT_API std::filesystem::path GetPath() const;
+
+private:
+  std::filesystem::path path_;
 };
 }
 
@@ -0,0 +1,59 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_EXCEPTION_SORTING_CANCELLED_ERROR
+#define LOOT_EXCEPTION_SORTING_CANCELLED_ERROR
+
+#include <stdexcept>
+
+#include "loot/api_decorator.h"
+
+namespace loot {
+/**
+ * @brief An exception class thrown if sorting is cancelled by its progress
+ *        callback.
+ */
+class SortingCancelledError : public std::runtime_error {
+public:
+  /**
+   * @brief Construct an exception for cancelled sorting.
+   * @param phase The name of the sorting phase that was about to start.
+   */
+  LOOT_API SortingCancelledError(const std::string& phase) :
+      std::runtime_error("Sorting was cancelled before the \"" + phase +
+                         "\" phase"),
+      phase_(phase) {}
+
+  /**
+   * Get the name of the sorting phase that was about to start.
+   * @return A phase name.
+   */
+  LOOT_API std::string GetPhase() const { return phase_; }
+
+private:
+  const std::string phase_;
+};
+}
+
+#endif
@@ -47,7 +47,7 @@ public:
    * Get the name of the undefined group.
    * @return A group name.
    */
-  LOOT_API std::string GetGroupName() { return groupName_; }
+  LOOT_API std::string GetGroupName() const { return groupName_; }
 
 private:
   const std::string groupName_;
@@ -24,12 +24,33 @@
 #ifndef LOOT_GAME_INTERFACE
 #define LOOT_GAME_INTERFACE
 
+#include <filesystem>
+#include <functional>
 #include <optional>
 
 #include "loot/database_interface.h"
+#include "loot/exception/sorting_cancelled_error.h"
 #include "loot/plugin_interface.h"
+#include "loot/struct/effective_bash_tags.h"
+#include "loot/struct/load_order_violation.h"
+#include "loot/struct/master_dependency_node.h"
+#include "loot/struct/master_info.h"
+#include "loot/struct/operation_timing.h"
+#include "loot/struct/orphaned_user_metadata.h"
+#include "loot/struct/plugin_slot_usage.h"
+#include "loot/struct/redundant_user_rule.h"
 
 namespace loot {
+/**
+ * @brief The type of function used to report sorting progress.
+ * @details The first parameter is the name of the phase that is about to
+ *          start, the second is the zero-based index of that phase, and the
+ *          third is the total number of phases. Return false to cancel
+ *          sorting.
+ */
+using SortingProgressCallback =
+    std::function<bool(const std::string&, size_t, size_t)>;
+
 /** @brief The interface provided for accessing game-specific functionality. */
 class GameInterface {
 public:
@@ -45,6 +66,18 @@ public:
    */
   virtual std::shared_ptr<DatabaseInterface> GetDatabase() = 0;
 
+  /**
+   * @brief Get the user metadata that refers to plugins that are not
+   *        installed or to groups that are not defined.
+   * @details This can be used to clean up the userlist after plugins have
+   *          been uninstalled. User metadata for plugins that are identified
+   *          using regular expressions is not checked for missing plugins,
+   *          but is checked for undefined groups.
+   * @returns The orphaned plugins and groups, each in the order that they
+   *          were first found.
+   */
+  virtual OrphanedUserMetadata GetOrphanedUserMetadata() const = 0;
+
   /**
    * @}
    * @name Plugin Data Access
@@ -62,6 +95,30 @@ public:
    */
   virtual bool IsValidPlugin(const std::string& plugin) const = 0;
 
+  /**
+   * @brief Set the version of the game's executable.
+   * @details The game version is used by ``GetPluginSlotUsage()`` and when
+   *          checking if loaded plugins are valid as light plugins: Skyrim
+   *          Special Edition 1.6.1130 and later allow plugins with a header
+   *          version of 1.71 or greater to use object IDs from 0x000 to
+   *          0xFFF, instead of 0x800 to 0xFFF. The wider range is only
+   *          checked for plugins that have been fully loaded. libloot cannot
+   *          read the version from the executable, so if it is not set then
+   *          the narrower range is used. The version only affects plugins
+   *          that are loaded after it is set.
+   * @param version
+   *        The game version as dot-separated numbers, e.g. ``1.6.1130.0``. An
+   *        empty string unsets the game version.
+   */
+  virtual void SetGameVersion(const std::string& version) = 0;
+
+  /**
+   * @brief Get the game version that was set by ``SetGameVersion()``.
+   * @returns An optional containing the game version if one has been set,
+   *          otherwise an optional containing no value.
+   */
+  virtual std::optional<std::string> GetGameVersion() const = 0;
+
   /**
    * @brief Parses plugins and loads their data.
    * @details Any previously-loaded plugin data is discarded when this function
@@ -97,6 +154,138 @@ public:
   virtual std::vector<std::shared_ptr<const PluginInterface>> GetLoadedPlugins()
       const = 0;
 
+  /**
+   * @brief Get a loaded plugin's masters, along with whether each master is
+   *        installed, light and active.
+   * @details Whether a master is light can only be determined if it has also
+   *          been loaded. Active states are taken from the load order state
+   *          that was last loaded by ``LoadCurrentLoadOrderState()``.
+   * @param pluginName
+   *        The filename of the plugin, which must have been loaded.
+   * @returns The plugin's masters in the same order they are listed in the
+   *          plugin.
+   */
+  virtual std::vector<MasterInfo> GetMasterInfo(
+      const std::string& pluginName) const = 0;
+
+  /**
+   * @brief Get the graph of a loaded plugin's transitive masters, along with
+   *        whether each of them is installed, loaded and active.
+   * @details Each plugin appears in the graph once, however many plugins in
+   *          the graph have it as a master, and nodes refer to their masters
+   *          by filename. A master's own masters can only be included if that
+   *          master has also been loaded. Active states are taken from the
+   *          load order state that was last loaded by
+   *          ``LoadCurrentLoadOrderState()``.
+   * @param pluginName
+   *        The filename of the plugin, which must have been loaded.
+   * @returns The graph's nodes in breadth-first order, starting with the
+   *          node for the given plugin.
+   */
+  virtual std::vector<MasterDependencyNode> GetMasterDependencyGraph(
+      const std::string& pluginName) const = 0;
+
+  /**
+   * @brief Get the Bash Tags that should be added to and removed from a
+   *        loaded plugin, combining all the sources of tags.
+   * @details The sources are, from lowest to highest precedence: the tags in
+   *          the plugin's description, the masterlist's tag suggestions, the
+   *          userlist's tag suggestions, and the plugin's
+   *          ``BashTags/<plugin basename>.txt`` file in the data folder. Tag
+   *          suggestion conditions are evaluated using the current load
+   *          order state, so only suggestions with conditions that evaluate
+   *          to true are used. A tag that is added by one source and removed
+   *          by a source with a higher precedence is removed, and vice versa.
+   * @param pluginName
+   *        The filename of the plugin, which must have been loaded.
+   * @returns The tags to add and the tags to remove.
+   */
+  virtual EffectiveBashTags GetEffectiveBashTags(
+      const std::string& pluginName) const = 0;
+
+  /**
+   * @brief Get the assets in a plugin's archives that are overridden by loose
+   *        files.
+   * @details Loose files in the game's data path take precedence over the
+   *          same assets in archives, so if all of a plugin's conflicting
+   *          assets are overridden, the plugin's position relative to the
+   *          other plugins that load those assets makes no difference to them.
+   *          Assets are only checked if their archive stores their folder and
+   *          file names, and loose file paths are matched case-insensitively.
+   *          Only loose files in the game's data path are checked, and there
+   *          is no reverse lookup from a loose file to the archived assets
+   *          that it overrides.
+   * @param pluginName
+   *        The filename of the plugin, which must have been fully loaded.
+   * @returns The paths of the overridden assets, using backslashes as
+   *          separators.
+   */
+  virtual std::vector<std::string> GetAssetsOverriddenByLooseFiles(
+      const std::string& pluginName) const = 0;
+
+  /**
+   * @brief Get the archives that a plugin loads.
+   * @details The archives that a plugin loads are identified by their
+   *          filenames, following the rules used by the game: for example,
+   *          Skyrim plugins only load the BSA with the same basename, while
+   *          Fallout 4 plugins load all BA2s with filenames that start with
+   *          the plugin's basename. Morrowind plugins don't load archives.
+   * @param pluginName
+   *        The filename of the plugin, which does not need to have been
+   *        loaded.
+   * @returns The paths of the archives that the plugin loads.
+   */
+  virtual std::vector<std::filesystem::path> GetAssociatedArchives(
+      const std::string& pluginName) = 0;
+
+  /**
+   * @brief Save the assets read from archives to a file.
+   * @details The assets that LOOT reads from archives when loading plugins are
+   *          cached until the archives change, and are kept when plugins are
+   *          next loaded. Saving them allows the cache to be reused by a
+   *          later ``LoadArchiveCache()`` call, e.g. in another session.
+   * @param cachePath
+   *        The path to which the cache should be written. Any existing file
+   *        is overwritten.
+   */
+  virtual void SaveArchiveCache(const std::filesystem::path& cachePath)
+      const = 0;
+
+  /**
+   * @brief Load archive assets from a file previously written by
+   *        ``SaveArchiveCache()``.
+   * @details Any assets that are currently cached are discarded. Cached
+   *          archives that have changed size or been modified since the cache
+   *          was saved are read again when plugins are next loaded.
+   * @param cachePath
+   *        The path of the cache file to load.
+   */
+  virtual void LoadArchiveCache(const std::filesystem::path& cachePath) = 0;
+
+  /**
+   * @brief Get a report of the current state of the game and its metadata,
+   *        for attaching to bug reports.
+   * @details The report includes the libloot version, the game type, the
+   *          game, local, data, masterlist and userlist paths, the current
+   *          load order, summaries of the loaded plugins, counts of the
+   *          masterlist and userlist entries, and any messages held in the log
+   *          buffer (see SetLogBufferCapacity()). Paths that are not set are
+   *          given as null.
+   * @returns The report as a JSON document.
+   */
+  virtual std::string GetDiagnosticsReport() const = 0;
+
+  /**
+   * @brief Get how long the most recent run of each timed operation took.
+   * @details The timed operations are loading plugins, loading the
+   *          masterlist and userlist, sorting plugins and each phase of
+   *          sorting. Each timing is also logged at the debug level when it
+   *          is recorded.
+   * @returns A vector of timings in the order that each operation was first
+   *          run. Operations that have not been run are not included.
+   */
+  virtual std::vector<OperationTiming> GetOperationTimings() const = 0;
+
   /**
    *  @}
    *  @name Sorting
@@ -117,6 +306,12 @@ public:
    *           loaded, and reads the contents of each plugin. No changes are
    *           applied to the load order used by the game. This function does
    *           not load or evaluate the masterlist or userlist.
+   *
+   *           Once a valid order has been calculated, adjacent plugins that
+   *           have no metadata or plugin data constraining their relative
+   *           positions are swapped back into their relative order in the
+   *           given load order, so that sorting moves as few plugins as it
+   *           can.
    *  @param plugins
    *         A vector of filenames of the plugins to sort, in their current
    *         load order.
@@ -126,6 +321,79 @@ public:
   virtual std::vector<std::string> SortPlugins(
       const std::vector<std::string>& plugins) = 0;
 
+  /**
+   *  @brief Calculates a new load order for the game's installed plugins,
+   *         reporting progress as each phase of sorting starts.
+   *  @details Behaves like the overload without a progress callback, except
+   *           that the callback is called before each phase starts. If the
+   *           callback returns false, sorting stops and a
+   *           SortingCancelledError is thrown. The callback is called on the
+   *           thread that called this function.
+   *  @param plugins
+   *         A vector of filenames of the plugins to sort, in their current
+   *         load order.
+   *  @param progressCallback
+   *         The function called before each phase of sorting.
+   *  @returns A vector of the given plugin filenames in their sorted load
+   *           order.
+   */
+  virtual std::vector<std::string> SortPlugins(
+      const std::vector<std::string>& plugins,
+      const SortingProgressCallback& progressCallback) = 0;
+
+  /**
+   *  @brief Get the number of plugins that the most recent sort moved.
+   *  @details This is the fewest plugins that would need to be moved to get
+   *           from the load order given to the most recent ``SortPlugins()``
+   *           call to the load order that it returned.
+   *  @returns The number of moved plugins, or 0 if plugins have not been
+   *           sorted.
+   */
+  virtual size_t GetLastSortMovedPluginCount() const = 0;
+
+  /**
+   *  @brief Check a load order against the constraints that sorting always
+   *         respects.
+   *  @details The constraints are the hardcoded positions of plugins, that
+   *           master-flagged plugins load before other plugins, that plugins
+   *           load after their masters, and the requirements and load after
+   *           metadata in the masterlist and userlist. Group membership and
+   *           overlaps are not checked, as sorting can override them.
+   *
+   *           Only plugins that have been loaded are checked, and loading
+   *           their headers is enough. Metadata conditions are evaluated
+   *           using the current load order state.
+   *  @param loadOrder
+   *         The filenames of the plugins to check, in their load order.
+   *  @returns The constraints that the given load order breaks. The vector is
+   *           empty if none are broken.
+   */
+  virtual std::vector<LoadOrderViolation> VerifyLoadOrder(
+      const std::vector<std::string>& loadOrder) = 0;
+
+  /**
+   *  @brief Find user metadata that has no effect on sorting because it is
+   *         already implied by other metadata or plugin data.
+   *  @details A user load after entry is redundant if the plugin must already
+   *           load after the file because of masters, the master flag, or
+   *           masterlist load after and requirement metadata, including
+   *           through other plugins. A user requirement is redundant if the
+   *           file is already one of the plugin's masters or masterlist
+   *           requirements. A user group is redundant if it is the same as the
+   *           plugin's masterlist group.
+   *
+   *           Only plugins that have been loaded are checked, and loading
+   *           their headers is enough. Metadata conditions are evaluated
+   *           using the current load order state.
+   *  @param loadOrder
+   *         The filenames of the plugins to check, for example as returned by
+   *         ``SortPlugins()``.
+   *  @returns The redundant user metadata. The vector is empty if there is
+   *           none.
+   */
+  virtual std::vector<RedundantUserRule> FindRedundantUserRules(
+      const std::vector<std::string>& loadOrder) = 0;
+
   /**
    *  @}
    *  @name Load Order Interaction
@@ -156,6 +424,25 @@ public:
    */
   virtual std::vector<std::string> GetLoadOrder() const = 0;
 
+  /**
+   * @brief Get how many of the game's load order slots are used by active
+   *        plugins.
+   * @details Active plugins are counted using the load order state that was
+   *          last loaded by ``LoadCurrentLoadOrderState()``. If an active
+   *          plugin has been loaded, its light flag is used to decide if it
+   *          is a light plugin, otherwise only plugins with a ``.esl`` file
+   *          extension are counted as light plugins. Light plugins are only
+   *          supported by Skyrim Special Edition, Skyrim VR, Fallout 4 and
+   *          Fallout 4 VR, and in those games one full plugin slot is
+   *          reserved for light plugins. If a game version has been set using
+   *          ``SetGameVersion()``, Skyrim Special Edition versions before
+   *          1.5.3 and Fallout 4 versions before 1.10.26 are treated as not
+   *          supporting light plugins.
+   * @returns The number of used and available slots for each class of
+   *          plugin.
+   */
+  virtual PluginSlotUsage GetPluginSlotUsage() const = 0;
+
   /**
    * @brief Set the game's load order.
    * @param loadOrder
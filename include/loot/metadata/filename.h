@@ -24,6 +24,8 @@
 #ifndef LOOT_METADATA_FILENAME
 #define LOOT_METADATA_FILENAME
 
+#include <functional>
+#include <ostream>
 #include <string>
 
 #include "loot/api_decorator.h"
@@ -93,6 +95,24 @@ LOOT_API bool operator<=(const Filename& lhs, const Filename& rhs);
  *          object, false otherwise.
  */
 LOOT_API bool operator>=(const Filename& lhs, const Filename& rhs);
+
+/**
+ * Write a Filename to an output stream.
+ * @returns The given output stream.
+ */
+LOOT_API std::ostream& operator<<(std::ostream& out, const Filename& filename);
+}
+
+namespace std {
+/**
+ * A hash function for Filename objects that is consistent with their
+ * case-insensitive equality, so that they can be used as keys in unordered
+ * containers.
+ */
+template<>
+struct hash<loot::Filename> {
+  LOOT_API size_t operator()(const loot::Filename& filename) const;
+};
 }
 
 #endif
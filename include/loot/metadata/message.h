@@ -47,34 +47,42 @@ public:
   LOOT_API explicit Message();
 
   /**
-   * Construct a Message object with the given type, English content and
-   * condition string.
+   * Construct a Message object with the given type, English content,
+   * condition string and ID.
    * @param  type
    *         The message type.
    * @param  content
    *         The English message content text.
    * @param  condition
    *         A condition string.
+   * @param  id
+   *         A stable identifier for the message, or an empty string if the
+   *         message has no ID.
    * @return A Message object.
    */
   LOOT_API explicit Message(const MessageType type,
                    const std::string& content,
-                   const std::string& condition = "");
+                   const std::string& condition = "",
+                   const std::string& id = "");
 
   /**
-   * Construct a Message object with the given type, content and condition
-   * string.
+   * Construct a Message object with the given type, content, condition string
+   * and ID.
    * @param  type
    *         The message type.
    * @param  content
    *         The message content. If multilingual, one language must be English.
    * @param  condition
    *         A condition string.
+   * @param  id
+   *         A stable identifier for the message, or an empty string if the
+   *         message has no ID.
    * @return A Message object.
    */
   LOOT_API explicit Message(const MessageType type,
                    const std::vector<MessageContent>& content,
-                   const std::string& condition = "");
+                   const std::string& condition = "",
+                   const std::string& id = "");
 
   /**
    * A less-than operator implemented with no semantics so that Message objects
@@ -102,6 +110,15 @@ public:
    */
   LOOT_API std::vector<MessageContent> GetContent() const;
 
+  /**
+   * Get the message's ID.
+   *
+   * IDs are optional, and identify a message independently of its content
+   * so that it can be suppressed even if its text is later changed.
+   * @return The message's ID, or an empty string if it has no ID.
+   */
+  LOOT_API std::string GetId() const;
+
   /**
    * Get the message content given a language.
    * @param  language
@@ -123,6 +140,7 @@ public:
 private:
   MessageType type_;
   std::vector<MessageContent> content_;
+  std::string id_;
 };
 
 /**
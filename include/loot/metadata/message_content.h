@@ -90,7 +90,10 @@ public:
    *         The MessageContent objects to choose between.
    * @param  language
    *         The locale or language code for the preferred language to select.
-   *         Locale codes are of the form <language code>_<country code>.
+   *         Locale codes are of the form <language code>_<country code>,
+   *         and may have further subtags (e.g. zh_Hant_TW). Subtags may be
+   *         separated using an underscore or a hyphen, and codes are compared
+   *         case-insensitively.
    * @return A MessageContent object.
    *
    *         If the vector only contains a single element, that element is
@@ -99,11 +102,13 @@ public:
    *         If content with a language that exactly matches the given locale
    *         or language code is present, that content is returned.
    *
-   *         If a locale code is given and there is no exact match but content
-   *         for that locale's language is present, that content is returned.
+   *         If there is no exact match, the last subtag is removed from the
+   *         given code and the result is matched, repeating until only the
+   *         language code is left. For example, zh_Hant_TW falls back to
+   *         zh_Hant and then to zh.
    *
-   *         If a language code is given and there is no exact match but content
-   *         for a locale in that langauge is present, that content is returned.
+   *         If there is still no match but content for a more specific locale
+   *         of the given code is present, that content is returned.
    *
    *         If no locale or language code matches are found and content in the
    *         default language is present, that content is returned.
@@ -114,6 +119,34 @@ public:
       const std::vector<MessageContent> content,
       const std::string& language);
 
+  /**
+   * Choose a MessageContent object from a vector given an ordered list of
+   * preferred languages.
+   * @param  content
+   *         The MessageContent objects to choose between.
+   * @param  languages
+   *         The locale or language codes for the preferred languages, in
+   *         descending order of preference. Locale codes may separate their
+   *         language and country codes using an underscore or a hyphen.
+   * @return A MessageContent object.
+   *
+   *         If the vector only contains a single element, that element is
+   *         returned.
+   *
+   *         Otherwise, each preferred language is tried in turn, and the
+   *         first match (using the same rules as the single language overload)
+   *         is returned. For example, given "pt-BR", content for "pt_BR" is
+   *         matched before content for "pt".
+   *
+   *         If none of the preferred languages match and content in the
+   *         default language is present, that content is returned.
+   *
+   *         Otherwise, a default-constructed MessageContent is returned.
+   */
+  LOOT_API static MessageContent Choose(
+      const std::vector<MessageContent> content,
+      const std::vector<std::string>& languages);
+
 private:
   std::string text_;
   std::string language_;
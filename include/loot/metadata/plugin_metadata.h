@@ -38,6 +38,7 @@
 #include "loot/metadata/message.h"
 #include "loot/metadata/plugin_cleaning_data.h"
 #include "loot/metadata/tag.h"
+#include "loot/struct/metadata_merge_modes.h"
 
 namespace loot {
 /**
@@ -54,6 +55,10 @@ public:
   /**
    * Construct a PluginMetadata object with no metadata for a plugin with the
    * given filename.
+   *
+   * If the filename is a regular expression, it is compiled once here so that
+   * NameMatches() can reuse it, and a std::regex_error is thrown if it is
+   * invalid.
    * @param  name
    *         The filename of the plugin that the object is constructed for.
    * @return A PluginMetadata object.
@@ -71,6 +76,22 @@ public:
    */
   LOOT_API void MergeMetadata(const PluginMetadata& plugin);
 
+  /**
+   * Merge metadata from the given PluginMetadata object into this object,
+   * using the given mode for each type of list metadata.
+   *
+   * This behaves like the overload without merge modes, except that if a
+   * type of metadata's merge mode is MergeMode::replace and this object
+   * already has metadata of that type, the given object's metadata of that
+   * type is not merged.
+   * @param plugin
+   *        The plugin metadata to merge.
+   * @param mergeModes
+   *        How each type of list metadata is merged.
+   */
+  LOOT_API void MergeMetadata(const PluginMetadata& plugin,
+                              const MetadataMergeModes& mergeModes);
+
   /**
    * Get metadata in this object that isn't present in the given PluginMetadata
    * object.
@@ -250,6 +271,7 @@ public:
 
 private:
   std::string name_;
+  std::optional<std::regex> nameRegex_;
   std::optional<std::string> group_;
   std::vector<File> loadAfter_;
   std::vector<File> requirements_;
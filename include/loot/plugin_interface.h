@@ -32,6 +32,7 @@
 
 #include "loot/metadata/message.h"
 #include "loot/metadata/tag.h"
+#include "loot/struct/plugin_integrity_issue.h"
 
 namespace loot {
 /**
@@ -84,6 +85,27 @@ public:
    */
   virtual std::optional<uint32_t> GetCRC() const = 0;
 
+  /**
+   * Get the structural problems in the plugin.
+   *
+   * The plugin is checked the first time this is called, rather than when it
+   * is loaded, so that loading and sorting plugins don't pay for the checks.
+   * The checks are cheap: each record and group is checked to make sure that
+   * it does not extend past the end of the data that contains it, that its
+   * FormID has a valid mod index, and that its subrecord sizes add up to its
+   * data size. The subrecords of compressed records are not checked. Plugins
+   * that are too badly damaged to be parsed cannot be loaded at all.
+   *
+   * Because the plugin file is read again when first checked, a
+   * FileAccessError is thrown if it can no longer be read, e.g. because it
+   * has been moved or ghosted since it was loaded.
+   * @return An optional containing the problems found if the plugin has been
+   *         fully loaded, otherwise an optional containing no value. The
+   *         vector is empty if no problems were found.
+   */
+  virtual std::optional<std::vector<PluginIntegrityIssue>> GetIntegrityIssues()
+      const = 0;
+
   /**
    * Check if the plugin's master flag is set.
    * @return True if the master flag is set, false otherwise.
@@ -114,6 +136,12 @@ public:
 
   /**
    * Check if the plugin is or would be valid as a light master.
+   * @details The range of object IDs that is valid may depend on the game
+   *          version given to GameInterface::SetGameVersion(). If the
+   *          extended range applies and the plugin was fully loaded, the
+   *          plugin file is read again to check its records the first time
+   *          this or GetIntegrityIssues() is called, and a FileAccessError is
+   *          thrown if it can no longer be read.
    * @return True if the plugin is a valid light master or would be a valid
    *         light master, false otherwise.
    */
@@ -141,6 +169,19 @@ public:
    *         data compared.
    */
   virtual bool DoFormIDsOverlap(const PluginInterface& plugin) const = 0;
+
+  /**
+   * Get the assets that are in archives loaded by both plugins.
+   * @param  plugin
+   *         The other plugin to check for overlapping assets with.
+   * @return The paths of the assets that both plugins load from archives,
+   *         using backslashes as separators. If neither plugin's archives
+   *         store an asset's folder or file name, its hash is given in
+   *         hexadecimal form instead. The vector is empty if either plugin was
+   *         loaded with only its header.
+   */
+  virtual std::vector<std::string> GetOverlappingAssets(
+      const PluginInterface& plugin) const = 0;
 };
 }
 
@@ -0,0 +1,57 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_BUILD_INFO
+#define LOOT_BUILD_INFO
+
+#include <string>
+
+namespace loot {
+/**
+ * @brief A structure that holds details of how the API was built.
+ */
+struct BuildInfo {
+  inline explicit BuildInfo() : isSharedLibrary(false) {}
+
+  /** @brief The ID and version of the C++ compiler, e.g. "MSVC 19.28". */
+  std::string compiler;
+
+  /** @brief The name of the operating system that the API was built for. */
+  std::string targetSystem;
+
+  /** @brief The processor architecture that the API was built for. */
+  std::string targetProcessor;
+
+  /**
+   * @brief The CMake build configuration, e.g. "Release". It is empty if no
+   *        build type was set for a single-configuration generator.
+   */
+  std::string buildType;
+
+  /** @brief True if the API was built as a shared library. */
+  bool isSharedLibrary;
+};
+}
+
+#endif
@@ -0,0 +1,63 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_CONDITION_CACHE_STATS
+#define LOOT_CONDITION_CACHE_STATS
+
+#include <cstddef>
+
+namespace loot {
+/**
+ * @brief A structure that holds statistics about libloot's cache of condition
+ *        string results since it was last cleared.
+ * @details libloot caches the results of whole condition strings in front of
+ *          loot-condition-interpreter's own cache of condition function
+ *          results. These statistics only describe libloot's cache:
+ *          loot-condition-interpreter does not provide statistics for its
+ *          cache, so a miss counted here may still be answered from it. Both
+ *          caches are cleared together by
+ *          DatabaseInterface::ClearConditionCache().
+ */
+struct ConditionCacheStats {
+  inline explicit ConditionCacheStats() : entries(0), hits(0), misses(0) {}
+
+  /**
+   * @brief The number of condition results that are cached.
+   */
+  size_t entries;
+
+  /**
+   * @brief The number of condition evaluations that used a cached result.
+   */
+  size_t hits;
+
+  /**
+   * @brief The number of condition evaluations that did not use a cached
+   *        result.
+   */
+  size_t misses;
+};
+}
+
+#endif
@@ -0,0 +1,53 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+
+#ifndef LOOT_EVALUATED_METADATA
+#define LOOT_EVALUATED_METADATA
+
+#include <map>
+#include <string>
+#include <vector>
+
+#include "loot/metadata/message.h"
+#include "loot/metadata/plugin_metadata.h"
+
+namespace loot {
+/**
+ * @brief A structure that holds general messages and plugin metadata with
+ *        their conditions all evaluated at the same time.
+ */
+struct EvaluatedMetadata {
+  /** @brief The general messages whose conditions evaluated to true. */
+  std::vector<Message> generalMessages;
+
+  /**
+   * @brief The evaluated metadata for each plugin that has any, keyed by the
+   *        plugin filenames that were given.
+   */
+  std::map<std::string, PluginMetadata> pluginMetadata;
+};
+}
+
+#endif
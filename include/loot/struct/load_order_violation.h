@@ -0,0 +1,55 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_LOAD_ORDER_VIOLATION
+#define LOOT_LOAD_ORDER_VIOLATION
+
+#include <string>
+
+#include "loot/enum/edge_type.h"
+
+namespace loot {
+/**
+ * @brief A structure that describes a pair of plugins that are loaded in an
+ *        order that breaks one of the constraints that sorting always
+ *        respects.
+ */
+struct LoadOrderViolation {
+  /** @brief The plugin that is loaded too early. */
+  std::string plugin;
+
+  /** @brief The plugin that must load before plugin, but loads after it. */
+  std::string mustLoadAfter;
+
+  /**
+   * @brief The type of the broken constraint. This is one of
+   *        EdgeType::hardcoded, EdgeType::masterFlag, EdgeType::master,
+   *        EdgeType::masterlistRequirement, EdgeType::userRequirement,
+   *        EdgeType::masterlistLoadAfter or EdgeType::userLoadAfter.
+   */
+  EdgeType type;
+};
+}
+
+#endif
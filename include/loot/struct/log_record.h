@@ -0,0 +1,53 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_LOG_RECORD
+#define LOOT_LOG_RECORD
+
+#include <chrono>
+#include <string>
+
+#include "loot/enum/log_level.h"
+
+namespace loot {
+/** @brief A structure that holds the details of a single logged message. */
+struct LogRecord {
+  /** @brief The level of the message. */
+  LogLevel level;
+
+  /**
+   * @brief The name of the logger that the message was logged to, which
+   *        identifies the library component that produced it.
+   */
+  std::string target;
+
+  /** @brief The time at which the message was logged. */
+  std::chrono::system_clock::time_point timestamp;
+
+  /** @brief The message string. */
+  std::string message;
+};
+}
+
+#endif
@@ -0,0 +1,64 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_MASTER_DEPENDENCY_NODE
+#define LOOT_MASTER_DEPENDENCY_NODE
+
+#include <string>
+#include <vector>
+
+namespace loot {
+/**
+ * @brief A structure that holds a node in the graph of a plugin's transitive
+ *        masters.
+ */
+struct MasterDependencyNode {
+  inline explicit MasterDependencyNode() :
+      isInstalled(false), isActive(false), isLoaded(false) {}
+
+  /** @brief The filename of the plugin. */
+  std::string name;
+
+  /** @brief True if the plugin is installed, including if it is ghosted. */
+  bool isInstalled;
+
+  /** @brief True if the plugin is active in the current load order state. */
+  bool isActive;
+
+  /**
+   * @brief True if the plugin has been loaded. Only loaded plugins' masters
+   *        are known.
+   */
+  bool isLoaded;
+
+  /**
+   * @brief The filenames of the plugin's masters, in the same order they are
+   *        listed in the plugin. Each master has its own node in the graph.
+   *        Empty if the plugin has no masters or has not been loaded.
+   */
+  std::vector<std::string> masters;
+};
+}
+
+#endif
@@ -0,0 +1,56 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_MASTER_INFO
+#define LOOT_MASTER_INFO
+
+#include <optional>
+#include <string>
+
+namespace loot {
+/**
+ * @brief A structure that holds information about one of a plugin's masters,
+ *        resolved against the game's installed and loaded plugins.
+ */
+struct MasterInfo {
+  inline explicit MasterInfo() : isInstalled(false), isActive(false) {}
+
+  /** @brief The filename of the master, as listed by the plugin. */
+  std::string name;
+
+  /** @brief True if the master is installed, including if it is ghosted. */
+  bool isInstalled;
+
+  /**
+   * @brief True if the master is a light plugin, false if it is a full
+   *        plugin. Has no value if the master has not been loaded.
+   */
+  std::optional<bool> isLightPlugin;
+
+  /** @brief True if the master is active in the current load order state. */
+  bool isActive;
+};
+}
+
+#endif
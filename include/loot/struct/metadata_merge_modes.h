@@ -0,0 +1,64 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_METADATA_MERGE_MODES
+#define LOOT_METADATA_MERGE_MODES
+
+#include "loot/enum/merge_mode.h"
+
+namespace loot {
+/**
+ * @brief A structure that holds how each type of list metadata is merged when
+ *        user metadata is combined with masterlist metadata.
+ * @details A plugin's group is not a list: if the user metadata sets a group,
+ *          it is always used instead of the masterlist's group.
+ */
+struct MetadataMergeModes {
+  /** @brief How load after metadata is merged. */
+  MergeMode loadAfter = MergeMode::append;
+
+  /** @brief How requirements are merged. */
+  MergeMode requirements = MergeMode::append;
+
+  /** @brief How incompatibilities are merged. */
+  MergeMode incompatibilities = MergeMode::append;
+
+  /** @brief How messages are merged. */
+  MergeMode messages = MergeMode::append;
+
+  /** @brief How Bash Tag suggestions are merged. */
+  MergeMode tags = MergeMode::append;
+
+  /** @brief How dirty plugin information is merged. */
+  MergeMode dirtyInfo = MergeMode::append;
+
+  /** @brief How clean plugin information is merged. */
+  MergeMode cleanInfo = MergeMode::append;
+
+  /** @brief How locations are merged. */
+  MergeMode locations = MergeMode::append;
+};
+}
+
+#endif
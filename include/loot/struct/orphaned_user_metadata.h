@@ -0,0 +1,59 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_ORPHANED_USER_METADATA
+#define LOOT_ORPHANED_USER_METADATA
+
+#include <string>
+#include <vector>
+
+namespace loot {
+/**
+ * @brief A structure that holds the user metadata that refers to plugins or
+ *        groups that no longer exist.
+ */
+struct OrphanedUserMetadata {
+  /**
+   * @brief The names of the plugins that have user metadata but are not
+   *        installed.
+   */
+  std::vector<std::string> plugins;
+
+  /**
+   * @brief The names of the groups that user groups load after or that user
+   *        metadata puts plugins in, but that are not defined in the
+   *        masterlist or userlist.
+   */
+  std::vector<std::string> groups;
+
+  /**
+   * @brief The names of the plugins that user metadata puts in groups that are
+   *        not defined in the masterlist or userlist. Sorting fails while any
+   *        such metadata exists.
+   */
+  std::vector<std::string> pluginsInUndefinedGroups;
+};
+}
+
+#endif
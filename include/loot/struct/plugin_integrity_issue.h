@@ -0,0 +1,55 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_PLUGIN_INTEGRITY_ISSUE
+#define LOOT_PLUGIN_INTEGRITY_ISSUE
+
+#include <cstdint>
+#include <string>
+
+#include "loot/enum/integrity_issue_type.h"
+
+namespace loot {
+/**
+ * @brief A structure that describes a structural problem found in a plugin.
+ */
+struct PluginIntegrityIssue {
+  /** @brief The type of the problem. */
+  IntegrityIssueType type;
+
+  /** @brief The offset in bytes of the record or group from the file start. */
+  uintmax_t offset;
+
+  /** @brief The type of the record, or ``GRUP`` for a group. */
+  std::string recordType;
+
+  /**
+   * @brief The FormID of the record. This is zero for groups and Morrowind
+   *        records.
+   */
+  uint32_t formId;
+};
+}
+
+#endif
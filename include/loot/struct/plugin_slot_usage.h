@@ -0,0 +1,56 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_PLUGIN_SLOT_USAGE
+#define LOOT_PLUGIN_SLOT_USAGE
+
+#include <cstddef>
+
+namespace loot {
+/**
+ * @brief A structure that holds how many of the game's load order slots are
+ *        used by active plugins, for each class of plugin.
+ */
+struct PluginSlotUsage {
+  inline explicit PluginSlotUsage() :
+      fullPlugins(0), fullPluginSlots(0), lightPlugins(0), lightPluginSlots(0) {}
+
+  /** @brief The number of active full plugins. */
+  size_t fullPlugins;
+
+  /** @brief The maximum number of full plugins that can be active. */
+  size_t fullPluginSlots;
+
+  /** @brief The number of active light plugins. */
+  size_t lightPlugins;
+
+  /**
+   * @brief The maximum number of light plugins that can be active. This is
+   *        zero if the game does not support light plugins.
+   */
+  size_t lightPluginSlots;
+};
+}
+
+#endif
@@ -0,0 +1,57 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_REDUNDANT_USER_RULE
+#define LOOT_REDUNDANT_USER_RULE
+
+#include <string>
+
+#include "loot/enum/edge_type.h"
+
+namespace loot {
+/**
+ * @brief A structure that describes a piece of user metadata that has no
+ *        effect on sorting because it is already implied by other metadata
+ *        or plugin data.
+ */
+struct RedundantUserRule {
+  /** @brief The plugin that the user metadata belongs to. */
+  std::string plugin;
+
+  /**
+   * @brief The name of the file that the plugin loads after or requires, or
+   *        the name of the group that the plugin is in.
+   */
+  std::string target;
+
+  /**
+   * @brief The type of the user metadata. This is one of
+   *        EdgeType::userLoadAfter, EdgeType::userRequirement or
+   *        EdgeType::group.
+   */
+  EdgeType type;
+};
+}
+
+#endif
@@ -45,6 +45,9 @@ struct SimpleMessage {
 
   /** @brief The message's condition string. */
   std::string condition;
+
+  /** @brief The message's ID, or an empty string if it has no ID. */
+  std::string id;
 };
 }
 
@@ -24,10 +24,20 @@
 
 #include "loot/api.h"
 
+#include <algorithm>
 #include <filesystem>
+#include <mutex>
+#include <regex>
 
+#include <boost/algorithm/string.hpp>
+#include <yaml-cpp/yaml.h>
+
+#include "api/archive/bsa.h"
 #include "api/game/game.h"
+#include "api/game/synchronized_game.h"
 #include "api/helpers/logging.h"
+#include "api/helpers/text.h"
+#include "api/plugin.h"
 
 namespace fs = std::filesystem;
 
@@ -53,14 +63,97 @@ std::filesystem::path ResolvePath(const std::filesystem::path& path) {
   return path;
 }
 
-LOOT_API void SetLoggingCallback(
-    std::function<void(LogLevel, const char*)> callback) {
-  auto sink = std::make_shared<SpdLoggingSink>(callback);
-  auto logger = std::make_shared<spdlog::logger>(LOGGER_NAME, sink);
-  logger->set_level(spdlog::level::level_enum::trace);
+static std::mutex loggingSinksMutex;
+static std::shared_ptr<SpdLoggingSink> callbackSink;
+static std::shared_ptr<LogRecordBufferSink> bufferSink;
+static bool collapseRepeatedLogRecords = false;
+static size_t maxLogRecordsPerSecond = 0;
+
+void RegisterLogger() {
+  std::vector<spdlog::sink_ptr> sinks;
+  if (callbackSink) {
+    sinks.push_back(callbackSink);
+  }
+  if (bufferSink) {
+    sinks.push_back(bufferSink);
+  }
 
   spdlog::drop(LOGGER_NAME);
-  spdlog::register_logger(logger);
+
+  if (!sinks.empty()) {
+    auto logger =
+        std::make_shared<spdlog::logger>(LOGGER_NAME, sinks.begin(), sinks.end());
+
+    // The logger's level is checked before a message is formatted, so set it
+    // as high as possible to avoid formatting messages no sink will use.
+    if (bufferSink) {
+      logger->set_level(spdlog::level::level_enum::trace);
+    } else {
+      logger->set_level(callbackSink->level());
+    }
+
+    spdlog::register_logger(logger);
+  }
+}
+
+LOOT_API void SetLoggingCallback(
+    std::function<void(LogLevel, const char*)> callback,
+    LogLevel minimumLevel) {
+  SetLoggingCallback(
+      [callback](const LogRecord& record) {
+        callback(record.level, record.message.c_str());
+      },
+      minimumLevel);
+}
+
+LOOT_API void SetLoggingCallback(
+    std::function<void(const LogRecord&)> callback,
+    LogLevel minimumLevel) {
+  std::lock_guard<std::mutex> guard(loggingSinksMutex);
+
+  callbackSink = std::make_shared<SpdLoggingSink>(callback);
+  callbackSink->set_level(mapToSpdlog(minimumLevel));
+  callbackSink->SetThrottling(collapseRepeatedLogRecords,
+                              maxLogRecordsPerSecond);
+
+  RegisterLogger();
+}
+
+LOOT_API void SetLogThrottling(bool collapseRepeats,
+                               size_t maxRecordsPerSecond) {
+  std::lock_guard<std::mutex> guard(loggingSinksMutex);
+
+  collapseRepeatedLogRecords = collapseRepeats;
+  maxLogRecordsPerSecond = maxRecordsPerSecond;
+
+  if (callbackSink) {
+    callbackSink->SetThrottling(collapseRepeats, maxRecordsPerSecond);
+  }
+}
+
+LOOT_API void SetLogBufferCapacity(size_t capacity) {
+  std::lock_guard<std::mutex> guard(loggingSinksMutex);
+
+  if (capacity == 0) {
+    bufferSink.reset();
+  } else if (bufferSink) {
+    bufferSink->SetCapacity(capacity);
+    return;
+  } else {
+    bufferSink = std::make_shared<LogRecordBufferSink>(capacity);
+  }
+
+  RegisterLogger();
+}
+
+LOOT_API std::vector<LogRecord> GetBufferedLogRecords() {
+  std::lock_guard<std::mutex> guard(loggingSinksMutex);
+
+  if (!bufferSink) {
+    return {};
+  }
+
+  return bufferSink->GetRecords();
 }
 
 LOOT_API bool IsCompatible(const unsigned int versionMajor,
@@ -75,7 +168,8 @@ LOOT_API bool IsCompatible(const unsigned int versionMajor,
 LOOT_API std::shared_ptr<GameInterface> CreateGameHandle(
     const GameType game,
     const std::filesystem::path& gamePath,
-    const std::filesystem::path& gameLocalPath) {
+    const std::filesystem::path& gameLocalPath,
+    const bool threadSafe) {
   auto logger = getLogger();
   if (logger) {
     logger->info(
@@ -95,6 +189,130 @@ LOOT_API std::shared_ptr<GameInterface> CreateGameHandle(
     throw std::invalid_argument("Given game local path \"" + gameLocalPath.u8string() +
                                 "\" does not resolve to a valid directory.");
 
-  return std::make_shared<Game>(game, resolvedGamePath, resolvedGameLocalPath);
+  auto handle =
+      std::make_shared<Game>(game, resolvedGamePath, resolvedGameLocalPath);
+  if (threadSafe) {
+    return std::make_shared<SynchronizedGame>(handle);
+  }
+
+  return handle;
+}
+
+LOOT_API std::vector<std::string> GetCapabilities() {
+  return {
+      "archive-assets",
+      "archive-cache",
+      "build-info",
+      "condition-cache-stats",
+      "diagnostics-report",
+      "effective-bash-tags",
+      "error-categories",
+      "evaluated-metadata",
+      "game-version",
+      "load-order-verification",
+      "log-buffer",
+      "log-throttling",
+      "master-dependency-graph",
+      "master-info",
+      "message-suppression",
+      "metadata-merge-modes",
+      "minimal-movement-sort",
+      "operation-timings",
+      "orphaned-user-metadata",
+      "plugin-filename-functions",
+      "plugin-integrity-issues",
+      "plugin-slot-usage",
+      "redundant-user-rules",
+      "sorting-progress",
+      "thread-safe-game-handles",
+  };
+}
+
+LOOT_API bool HasCapability(const std::string& capability) {
+  auto capabilities = GetCapabilities();
+  return std::find(capabilities.begin(), capabilities.end(), capability) !=
+         capabilities.end();
+}
+
+// These lists must be checked whenever LCI_VERSION is changed in
+// CMakeLists.txt, and condition_language_test.h will fail until they are.
+LOOT_API std::vector<std::string> GetSupportedConditionFunctions() {
+  return {
+      "file",
+      "active",
+      "many",
+      "many_active",
+      "is_master",
+      "checksum",
+      "version",
+      "product_version",
+  };
+}
+
+LOOT_API std::vector<std::string> GetSupportedConditionOperators() {
+  return {"and", "or", "not", "==", "!=", "<", ">", "<=", ">="};
+}
+
+LOOT_API bool HasPluginFileExtension(const GameType game,
+                                     const std::string& filename) {
+  return hasPluginFileExtension(filename, game);
+}
+
+LOOT_API bool IsGhostedFilename(const std::string& filename) {
+  return RemoveGhostExtension(filename).length() != filename.length();
+}
+
+LOOT_API std::string TrimGhostExtension(const std::string& filename) {
+  return RemoveGhostExtension(filename);
+}
+
+LOOT_API std::map<std::filesystem::path, std::vector<std::string>>
+GetArchiveAssetPaths(const std::vector<std::filesystem::path>& archivePaths) {
+  std::map<std::filesystem::path, std::vector<std::string>> assetPaths;
+
+  for (const auto& archivePath : archivePaths) {
+    std::vector<std::string> paths;
+    for (const auto& asset : GetAssetsInBethesdaArchive(archivePath)) {
+      paths.push_back(GetAssetPath(asset));
+    }
+
+    assetPaths.emplace(archivePath, paths);
+  }
+
+  return assetPaths;
+}
+
+LOOT_API std::optional<ErrorCategory> GetErrorCategory(
+    const std::exception& error) {
+  if (dynamic_cast<const SortingCancelledError*>(&error) != nullptr ||
+      dynamic_cast<const CyclicInteractionError*>(&error) != nullptr ||
+      dynamic_cast<const UndefinedGroupError*>(&error) != nullptr) {
+    return ErrorCategory::sorting;
+  }
+
+  if (dynamic_cast<const ConditionSyntaxError*>(&error) != nullptr) {
+    return ErrorCategory::condition;
+  }
+
+  if (dynamic_cast<const ArchiveParseError*>(&error) != nullptr ||
+      dynamic_cast<const YAML::Exception*>(&error) != nullptr) {
+    return ErrorCategory::parse;
+  }
+
+  if (dynamic_cast<const FileAccessError*>(&error) != nullptr ||
+      dynamic_cast<const std::system_error*>(&error) != nullptr) {
+    return ErrorCategory::io;
+  }
+
+  if (dynamic_cast<const GitStateError*>(&error) != nullptr) {
+    return ErrorCategory::state;
+  }
+
+  if (dynamic_cast<const std::invalid_argument*>(&error) != nullptr ||
+      dynamic_cast<const std::regex_error*>(&error) != nullptr) {
+    return ErrorCategory::invalidArgument;
+  }
+
+  return std::nullopt;
 }
 }
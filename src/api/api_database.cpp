@@ -24,7 +24,9 @@
 
 #include "api/api_database.h"
 
+#include <algorithm>
 #include <unordered_map>
+#include <unordered_set>
 #include <vector>
 
 #include "api/game/game.h"
@@ -37,8 +39,9 @@
 
 namespace loot {
 ApiDatabase::ApiDatabase(
-    std::shared_ptr<ConditionEvaluator> conditionEvaluator) :
-    conditionEvaluator_(conditionEvaluator) {}
+    std::shared_ptr<ConditionEvaluator> conditionEvaluator,
+    std::shared_ptr<OperationTimings> timings) :
+    conditionEvaluator_(conditionEvaluator), timings_(timings) {}
 
 ///////////////////////////////////
 // Database Loading Functions
@@ -51,34 +54,43 @@ void ApiDatabase::LoadLists(const std::filesystem::path& masterlistPath,
 
   if (!masterlistPath.empty()) {
     if (std::filesystem::exists(masterlistPath)) {
+      ScopedTimer timer(timings_, "LoadMasterlist");
       temp.Load(masterlistPath);
     } else {
       throw FileAccessError("The given masterlist path does not exist: " +
-                            masterlistPath.u8string());
+                                masterlistPath.u8string(),
+                            masterlistPath);
     }
   }
 
   if (!userlistPath.empty()) {
     if (std::filesystem::exists(userlistPath)) {
+      ScopedTimer timer(timings_, "LoadUserlist");
       userTemp.Load(userlistPath);
     } else {
       throw FileAccessError("The given userlist path does not exist: " +
-                            userlistPath.u8string());
+                                userlistPath.u8string(),
+                            userlistPath);
     }
   }
 
   masterlist_ = temp;
   userlist_ = userTemp;
+  masterlistPath_ = masterlistPath;
+  userlistPath_ = userlistPath;
+  ResetTransitiveAfterGroups();
 }
 
 void ApiDatabase::WriteUserMetadata(const std::filesystem::path& outputFile,
-                                    const bool overwrite) const {
+                                    const WriteMode mode) const {
   if (!std::filesystem::exists(outputFile.parent_path()))
     throw std::invalid_argument("Output directory does not exist.");
 
-  if (std::filesystem::exists(outputFile) && !overwrite)
+  if (std::filesystem::exists(outputFile) && mode == WriteMode::create)
     throw FileAccessError(
-        "Output file exists but overwrite is not set to true.");
+        "Output file exists but the write mode does not allow it to be "
+        "replaced.",
+        outputFile);
 
   userlist_.Save(outputFile);
 }
@@ -98,6 +110,8 @@ bool ApiDatabase::UpdateMasterlist(const std::filesystem::path& masterlistPath,
   Masterlist masterlist;
   if (masterlist.Update(masterlistPath, remoteURL, remoteBranch)) {
     masterlist_ = masterlist;
+    masterlistPath_ = masterlistPath;
+    ResetTransitiveAfterGroups();
     return true;
   }
 
@@ -156,7 +170,15 @@ std::vector<Message> ApiDatabase::GetGeneralMessages(
     }
   }
 
-  return masterlistMessages;
+  return RemoveSuppressedMessages(masterlistMessages);
+}
+
+std::vector<std::string> ApiDatabase::GetSuppressedMessageIds() const {
+  return userlist_.SuppressedMessageIds();
+}
+
+void ApiDatabase::SetSuppressedMessageIds(const std::vector<std::string>& ids) {
+  userlist_.SetSuppressedMessageIds(ids);
 }
 
 std::vector<Group> ApiDatabase::GetGroups(bool includeUserMetadata) const {
@@ -200,6 +222,7 @@ std::vector<Group> ApiDatabase::GetUserGroups() const {
 
 void ApiDatabase::SetUserGroups(const std::vector<Group>& groups) {
   userlist_.SetGroups(groups);
+  ResetTransitiveAfterGroups();
 }
 
 std::vector<Vertex> ApiDatabase::GetGroupsPath(
@@ -216,16 +239,41 @@ std::optional<PluginMetadata> ApiDatabase::GetPluginMetadata(
     const std::string& plugin,
     bool includeUserMetadata,
     bool evaluateConditions) const {
+  if (includeUserMetadata) {
+    return GetPluginMetadata(plugin, MetadataMergeModes(), evaluateConditions);
+  }
+
   auto metadata = masterlist_.FindPlugin(plugin);
 
-  if (includeUserMetadata) {
-    auto userMetadata = userlist_.FindPlugin(plugin);
-    if (userMetadata.has_value()) {
-      if (metadata.has_value()) {
-        userMetadata.value().MergeMetadata(metadata.value());
-      }
-      metadata = userMetadata;
+  if (metadata.has_value()) {
+    metadata.value().SetMessages(
+        RemoveSuppressedMessages(metadata.value().GetMessages()));
+  }
+
+  if (evaluateConditions && metadata.has_value()) {
+    return conditionEvaluator_->EvaluateAll(metadata.value());
+  }
+
+  return metadata;
+}
+
+std::optional<PluginMetadata> ApiDatabase::GetPluginMetadata(
+    const std::string& plugin,
+    const MetadataMergeModes& mergeModes,
+    bool evaluateConditions) const {
+  auto metadata = masterlist_.FindPlugin(plugin);
+
+  auto userMetadata = userlist_.FindPlugin(plugin);
+  if (userMetadata.has_value()) {
+    if (metadata.has_value()) {
+      userMetadata.value().MergeMetadata(metadata.value(), mergeModes);
     }
+    metadata = userMetadata;
+  }
+
+  if (metadata.has_value()) {
+    metadata.value().SetMessages(
+        RemoveSuppressedMessages(metadata.value().GetMessages()));
   }
 
   if (evaluateConditions && metadata.has_value()) {
@@ -235,6 +283,44 @@ std::optional<PluginMetadata> ApiDatabase::GetPluginMetadata(
   return metadata;
 }
 
+std::optional<PluginMetadata> ApiDatabase::GetOverriddenPluginMetadata(
+    const std::string& plugin,
+    const MetadataMergeModes& mergeModes) const {
+  auto masterlistMetadata = masterlist_.FindPlugin(plugin);
+  auto userMetadata = userlist_.FindPlugin(plugin);
+  if (!masterlistMetadata.has_value() || !userMetadata.has_value()) {
+    return std::nullopt;
+  }
+
+  userMetadata.value().MergeMetadata(masterlistMetadata.value(), mergeModes);
+
+  auto overriddenMetadata =
+      masterlistMetadata.value().NewMetadata(userMetadata.value());
+  if (overriddenMetadata.HasNameOnly()) {
+    return std::nullopt;
+  }
+
+  return overriddenMetadata;
+}
+
+EvaluatedMetadata ApiDatabase::GetEvaluatedMetadata(
+    const std::vector<std::string>& plugins,
+    bool includeUserMetadata) const {
+  EvaluatedMetadata evaluatedMetadata;
+
+  // Getting the general messages clears the condition cache first.
+  evaluatedMetadata.generalMessages = GetGeneralMessages(true);
+
+  for (const auto& plugin : plugins) {
+    auto metadata = GetPluginMetadata(plugin, includeUserMetadata, true);
+    if (metadata.has_value()) {
+      evaluatedMetadata.pluginMetadata.emplace(plugin, metadata.value());
+    }
+  }
+
+  return evaluatedMetadata;
+}
+
 std::optional<PluginMetadata> ApiDatabase::GetPluginUserMetadata(
     const std::string& plugin,
     bool evaluateConditions) const {
@@ -256,7 +342,33 @@ void ApiDatabase::DiscardPluginUserMetadata(const std::string& plugin) {
   userlist_.ErasePlugin(plugin);
 }
 
-void ApiDatabase::DiscardAllUserMetadata() { userlist_.Clear(); }
+void ApiDatabase::DiscardAllUserMetadata() {
+  userlist_.Clear();
+  ResetTransitiveAfterGroups();
+}
+
+void ApiDatabase::ValidateCondition(const std::string& condition) const {
+  ParseCondition(condition);
+}
+
+std::vector<bool> ApiDatabase::EvaluateConditions(
+    const std::vector<std::string>& conditions) const {
+  return conditionEvaluator_->Evaluate(conditions);
+}
+
+std::vector<bool> ApiDatabase::EvaluateConditionsWithActivePlugins(
+    const std::vector<std::string>& conditions,
+    const std::vector<std::string>& activePlugins) const {
+  return conditionEvaluator_->Evaluate(conditions, activePlugins);
+}
+
+void ApiDatabase::ClearConditionCache() {
+  conditionEvaluator_->ClearConditionCache();
+}
+
+ConditionCacheStats ApiDatabase::GetConditionCacheStats() const {
+  return conditionEvaluator_->GetConditionCacheStats();
+}
 
 // Writes a minimal masterlist that only contains mods that have Bash Tag
 // suggestions, and/or dirty messages, plus the Tag suggestions and/or messages
@@ -264,13 +376,15 @@ void ApiDatabase::DiscardAllUserMetadata() { userlist_.Clear(); }
 // outputFile is the path to use for output. If outputFile already exists, it
 // will only be overwritten if overwrite is true.
 void ApiDatabase::WriteMinimalList(const std::filesystem::path& outputFile,
-                                   const bool overwrite) const {
+                                   const WriteMode mode) const {
   if (!std::filesystem::exists(outputFile.parent_path()))
     throw std::invalid_argument("Output directory does not exist.");
 
-  if (std::filesystem::exists(outputFile) && !overwrite)
+  if (std::filesystem::exists(outputFile) && mode == WriteMode::create)
     throw FileAccessError(
-        "Output file exists but overwrite is not set to true.");
+        "Output file exists but the write mode does not allow it to be "
+        "replaced.",
+        outputFile);
 
   MetadataList minimalList;
   for (const auto& plugin : masterlist_.Plugins()) {
@@ -283,4 +397,55 @@ void ApiDatabase::WriteMinimalList(const std::filesystem::path& outputFile,
 
   minimalList.Save(outputFile);
 }
+
+const Masterlist& ApiDatabase::GetMasterlist() const { return masterlist_; }
+
+const MetadataList& ApiDatabase::GetUserlist() const { return userlist_; }
+
+std::filesystem::path ApiDatabase::GetMasterlistPath() const {
+  return masterlistPath_;
+}
+
+std::filesystem::path ApiDatabase::GetUserlistPath() const {
+  return userlistPath_;
+}
+
+std::shared_ptr<
+    const std::unordered_map<std::string, std::unordered_set<std::string>>>
+ApiDatabase::GetTransitiveAfterGroups() const {
+  std::lock_guard<std::mutex> lock(transitiveAfterGroupsMutex_);
+
+  if (!transitiveAfterGroups_) {
+    transitiveAfterGroups_ = std::make_shared<
+        const std::unordered_map<std::string, std::unordered_set<std::string>>>(
+        loot::GetTransitiveAfterGroups(GetGroups(false), GetUserGroups()));
+  }
+
+  return transitiveAfterGroups_;
+}
+
+void ApiDatabase::ResetTransitiveAfterGroups() {
+  std::lock_guard<std::mutex> lock(transitiveAfterGroupsMutex_);
+  transitiveAfterGroups_.reset();
+}
+
+std::vector<Message> ApiDatabase::RemoveSuppressedMessages(
+    std::vector<Message> messages) const {
+  const auto suppressedIds = userlist_.SuppressedMessageIds();
+  if (suppressedIds.empty()) {
+    return messages;
+  }
+
+  const std::unordered_set<std::string> ids(suppressedIds.begin(),
+                                            suppressedIds.end());
+  messages.erase(std::remove_if(messages.begin(),
+                                messages.end(),
+                                [&](const Message& message) {
+                                  return !message.GetId().empty() &&
+                                         ids.count(message.GetId()) != 0;
+                                }),
+                 messages.end());
+
+  return messages;
+}
 }
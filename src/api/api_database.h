@@ -26,11 +26,17 @@
 #define LOOT_API_LOOT_DB
 
 #include <list>
+#include <memory>
+#include <mutex>
+#include <optional>
 #include <string>
+#include <unordered_map>
+#include <unordered_set>
 #include <vector>
 
 #include "api/game/game_cache.h"
 #include "api/game/load_order_handler.h"
+#include "api/helpers/timing.h"
 #include "api/masterlist.h"
 #include "api/metadata/condition_evaluator.h"
 #include "api/metadata_list.h"
@@ -40,16 +46,20 @@
 
 namespace loot {
 struct ApiDatabase : public DatabaseInterface {
-  explicit ApiDatabase(std::shared_ptr<ConditionEvaluator> conditionEvaluator);
+  explicit ApiDatabase(std::shared_ptr<ConditionEvaluator> conditionEvaluator,
+                       std::shared_ptr<OperationTimings> timings);
 
   void LoadLists(const std::filesystem::path& masterlist_path,
                  const std::filesystem::path& userlist_path = "");
 
+  using DatabaseInterface::WriteMinimalList;
+  using DatabaseInterface::WriteUserMetadata;
+
   void WriteUserMetadata(const std::filesystem::path& outputFile,
-                         const bool overwrite) const;
+                         const WriteMode mode) const;
 
   void WriteMinimalList(const std::filesystem::path& outputFile,
-                        const bool overwrite) const;
+                        const WriteMode mode) const;
 
   bool UpdateMasterlist(const std::filesystem::path& masterlist_path,
                         const std::string& remote_url,
@@ -67,6 +77,9 @@ struct ApiDatabase : public DatabaseInterface {
   std::vector<Message> GetGeneralMessages(
       bool evaluateConditions = false) const;
 
+  std::vector<std::string> GetSuppressedMessageIds() const;
+  void SetSuppressedMessageIds(const std::vector<std::string>& ids);
+
   std::vector<Group> GetGroups(bool includeUserMetadata = true) const;
   std::vector<Group> GetUserGroups() const;
   void SetUserGroups(const std::vector<Group>& groups);
@@ -78,20 +91,74 @@ struct ApiDatabase : public DatabaseInterface {
       bool includeUserMetadata = true,
       bool evaluateConditions = false) const;
 
+  std::optional<PluginMetadata> GetPluginMetadata(
+      const std::string& plugin,
+      const MetadataMergeModes& mergeModes,
+      bool evaluateConditions = false) const;
+
+  std::optional<PluginMetadata> GetOverriddenPluginMetadata(
+      const std::string& plugin,
+      const MetadataMergeModes& mergeModes) const;
+
   std::optional<PluginMetadata> GetPluginUserMetadata(
       const std::string& plugin,
       bool evaluateConditions = false) const;
 
+  EvaluatedMetadata GetEvaluatedMetadata(
+      const std::vector<std::string>& plugins,
+      bool includeUserMetadata = true) const;
+
   void SetPluginUserMetadata(const PluginMetadata& pluginMetadata);
 
   void DiscardPluginUserMetadata(const std::string& plugin);
 
   void DiscardAllUserMetadata();
 
+  void ValidateCondition(const std::string& condition) const;
+
+  std::vector<bool> EvaluateConditions(
+      const std::vector<std::string>& conditions) const;
+
+  std::vector<bool> EvaluateConditionsWithActivePlugins(
+      const std::vector<std::string>& conditions,
+      const std::vector<std::string>& activePlugins) const;
+
+  void ClearConditionCache();
+
+  ConditionCacheStats GetConditionCacheStats() const;
+
+  const Masterlist& GetMasterlist() const;
+
+  const MetadataList& GetUserlist() const;
+
+  // The paths that the loaded masterlist and userlist were read from, which
+  // are empty if they were not loaded from a file.
+  std::filesystem::path GetMasterlistPath() const;
+  std::filesystem::path GetUserlistPath() const;
+
+  // The result is cached until the masterlist or user groups change. The
+  // returned map is not changed when the cache is reset, so it remains valid
+  // to use.
+  std::shared_ptr<
+      const std::unordered_map<std::string, std::unordered_set<std::string>>>
+  GetTransitiveAfterGroups() const;
+
 private:
+  std::vector<Message> RemoveSuppressedMessages(
+      std::vector<Message> messages) const;
+  void ResetTransitiveAfterGroups();
+
   std::shared_ptr<ConditionEvaluator> conditionEvaluator_;
+  std::shared_ptr<OperationTimings> timings_;
   Masterlist masterlist_;
   MetadataList userlist_;
+  std::filesystem::path masterlistPath_;
+  std::filesystem::path userlistPath_;
+
+  mutable std::shared_ptr<
+      const std::unordered_map<std::string, std::unordered_set<std::string>>>
+      transitiveAfterGroups_;
+  mutable std::mutex transitiveAfterGroupsMutex_;
 };
 }
 
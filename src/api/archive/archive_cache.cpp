@@ -0,0 +1,246 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#include "api/archive/archive_cache.h"
+
+#include <exception>
+#include <fstream>
+#include <stdexcept>
+
+#include "api/archive/binary_io.h"
+#include "api/helpers/logging.h"
+#include "loot/exception/file_access_error.h"
+#include "loot/loot_version.h"
+
+using std::lock_guard;
+using std::mutex;
+
+namespace loot {
+static constexpr char ARCHIVE_CACHE_MAGIC[] = "LOOTAC";
+static constexpr uint32_t ARCHIVE_CACHE_VERSION = 2;
+
+static constexpr uint8_t ASSET_HAS_FOLDER_NAME = 0x1;
+static constexpr uint8_t ASSET_HAS_FILE_NAME = 0x2;
+
+// Each cached asset has at least two hashes and a flags byte.
+static constexpr size_t MIN_ASSET_SIZE = 17;
+
+static int64_t GetLastWriteTime(const std::filesystem::path& path) {
+  return std::filesystem::last_write_time(path).time_since_epoch().count();
+}
+
+static void WriteCacheString(std::ostream& out, const std::string& value) {
+  WriteLittleEndian<uint32_t>(out, static_cast<uint32_t>(value.size()));
+  out.write(value.data(), value.size());
+}
+
+static std::string ReadCacheString(std::istream& in) {
+  const auto length = ReadLittleEndian<uint32_t>(in);
+  if (length > GetRemainingLength(in)) {
+    throw std::runtime_error("a string length is larger than the file");
+  }
+
+  std::string value(length, '\0');
+  in.read(&value[0], length);
+
+  return value;
+}
+
+// Last write times are stored as counts since the filesystem clock's epoch,
+// which is implementation-defined, so a cache is only valid for the build
+// that wrote it.
+static std::string GetBuildId() {
+  return LootVersion::GetVersionString() + '+' + LootVersion::revision;
+}
+
+std::vector<ArchiveAsset> ArchiveCache::GetAssets(
+    const std::filesystem::path& archivePath) {
+  uintmax_t size = 0;
+  int64_t lastWriteTime = 0;
+  try {
+    size = std::filesystem::file_size(archivePath);
+    lastWriteTime = GetLastWriteTime(archivePath);
+  } catch (std::filesystem::filesystem_error& e) {
+    std::throw_with_nested(FileAccessError("Unable to read the archive \"" +
+                                               archivePath.u8string() +
+                                               "\": " + e.what(),
+                                           archivePath));
+  }
+
+  {
+    lock_guard<mutex> lock(mutex_);
+
+    auto it = entries_.find(archivePath);
+    if (it != entries_.end() && it->second.size == size &&
+        it->second.lastWriteTime == lastWriteTime) {
+      return it->second.assets;
+    }
+  }
+
+  // Read the archive without holding the lock so that multiple archives can
+  // be read in parallel.
+  auto assets = GetAssetsInBethesdaArchive(archivePath);
+
+  lock_guard<mutex> lock(mutex_);
+  entries_.insert_or_assign(archivePath, Entry{size, lastWriteTime, assets});
+
+  return assets;
+}
+
+void ArchiveCache::Save(const std::filesystem::path& cachePath) const {
+  lock_guard<mutex> lock(mutex_);
+
+  auto logger = getLogger();
+  if (logger) {
+    logger->trace("Saving the archive cache to: {}", cachePath.u8string());
+  }
+
+  try {
+    std::ofstream out(cachePath, std::ios::binary);
+    out.exceptions(std::ios_base::badbit | std::ios_base::failbit);
+
+    out.write(ARCHIVE_CACHE_MAGIC, sizeof(ARCHIVE_CACHE_MAGIC) - 1);
+    WriteLittleEndian<uint32_t>(out, ARCHIVE_CACHE_VERSION);
+    WriteCacheString(out, GetBuildId());
+    WriteLittleEndian<uint32_t>(out, static_cast<uint32_t>(entries_.size()));
+
+    for (const auto& [archivePath, entry] : entries_) {
+      WriteCacheString(out, archivePath.u8string());
+      WriteLittleEndian<uint64_t>(out, entry.size);
+      WriteLittleEndian<uint64_t>(out,
+                                  static_cast<uint64_t>(entry.lastWriteTime));
+      WriteLittleEndian<uint32_t>(out,
+                                  static_cast<uint32_t>(entry.assets.size()));
+
+      for (const auto& asset : entry.assets) {
+        WriteLittleEndian<uint64_t>(out, asset.folderHash);
+        WriteLittleEndian<uint64_t>(out, asset.fileHash);
+
+        uint8_t flags = 0;
+        if (asset.folderName.has_value()) {
+          flags |= ASSET_HAS_FOLDER_NAME;
+        }
+        if (asset.fileName.has_value()) {
+          flags |= ASSET_HAS_FILE_NAME;
+        }
+        WriteLittleEndian<uint8_t>(out, flags);
+
+        if (asset.folderName.has_value()) {
+          WriteCacheString(out, asset.folderName.value());
+        }
+        if (asset.fileName.has_value()) {
+          WriteCacheString(out, asset.fileName.value());
+        }
+      }
+    }
+  } catch (std::exception& e) {
+    std::throw_with_nested(
+        FileAccessError("Unable to write the archive cache to \"" +
+                            cachePath.u8string() + "\": " + e.what(),
+                        cachePath));
+  }
+}
+
+void ArchiveCache::Load(const std::filesystem::path& cachePath) {
+  auto logger = getLogger();
+  if (logger) {
+    logger->trace("Loading the archive cache from: {}", cachePath.u8string());
+  }
+
+  std::map<std::filesystem::path, Entry> entries;
+  try {
+    std::ifstream in(cachePath, std::ios::binary);
+    in.exceptions(std::ios_base::badbit | std::ios_base::failbit);
+
+    std::string magic(sizeof(ARCHIVE_CACHE_MAGIC) - 1, '\0');
+    in.read(&magic[0], magic.size());
+    if (magic != ARCHIVE_CACHE_MAGIC) {
+      throw FileAccessError(
+          "\"" + cachePath.u8string() + "\" is not an archive cache file",
+          cachePath);
+    }
+
+    const auto version = ReadLittleEndian<uint32_t>(in);
+    if (version != ARCHIVE_CACHE_VERSION) {
+      throw FileAccessError(
+          "\"" + cachePath.u8string() +
+              "\" has an unsupported archive cache version: " +
+              std::to_string(version),
+          cachePath);
+    }
+
+    const auto buildId = ReadCacheString(in);
+    if (buildId != GetBuildId()) {
+      throw FileAccessError("\"" + cachePath.u8string() +
+                                "\" was written by a different build: " +
+                                buildId,
+                            cachePath);
+    }
+
+    const auto entryCount = ReadLittleEndian<uint32_t>(in);
+    for (uint32_t i = 0; i < entryCount; ++i) {
+      const auto archivePath =
+          std::filesystem::u8path(ReadCacheString(in));
+
+      Entry entry;
+      entry.size = ReadLittleEndian<uint64_t>(in);
+      entry.lastWriteTime =
+          static_cast<int64_t>(ReadLittleEndian<uint64_t>(in));
+
+      const auto assetCount = ReadLittleEndian<uint32_t>(in);
+      if (assetCount > GetRemainingLength(in) / MIN_ASSET_SIZE) {
+        throw std::runtime_error("an asset count is larger than the file");
+      }
+      entry.assets.reserve(assetCount);
+      for (uint32_t j = 0; j < assetCount; ++j) {
+        ArchiveAsset asset;
+        asset.folderHash = ReadLittleEndian<uint64_t>(in);
+        asset.fileHash = ReadLittleEndian<uint64_t>(in);
+
+        const auto flags = ReadLittleEndian<uint8_t>(in);
+        if (flags & ASSET_HAS_FOLDER_NAME) {
+          asset.folderName = ReadCacheString(in);
+        }
+        if (flags & ASSET_HAS_FILE_NAME) {
+          asset.fileName = ReadCacheString(in);
+        }
+
+        entry.assets.push_back(asset);
+      }
+
+      entries.insert_or_assign(archivePath, entry);
+    }
+  } catch (FileAccessError&) {
+    throw;
+  } catch (std::exception& e) {
+    std::throw_with_nested(
+        FileAccessError("Unable to read the archive cache from \"" +
+                            cachePath.u8string() + "\": " + e.what(),
+                        cachePath));
+  }
+
+  lock_guard<mutex> lock(mutex_);
+  entries_ = entries;
+}
+}
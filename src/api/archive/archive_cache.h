@@ -0,0 +1,59 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_API_ARCHIVE_ARCHIVE_CACHE
+#define LOOT_API_ARCHIVE_ARCHIVE_CACHE
+
+#include <cstdint>
+#include <filesystem>
+#include <map>
+#include <mutex>
+#include <vector>
+
+#include "api/archive/bsa.h"
+
+namespace loot {
+// Stores the assets read from archives so that they don't need to be read
+// again unless the archive's size or last modified time changes.
+class ArchiveCache {
+public:
+  std::vector<ArchiveAsset> GetAssets(const std::filesystem::path& archivePath);
+
+  void Save(const std::filesystem::path& cachePath) const;
+  void Load(const std::filesystem::path& cachePath);
+
+private:
+  struct Entry {
+    uintmax_t size;
+    int64_t lastWriteTime;
+    std::vector<ArchiveAsset> assets;
+  };
+
+  std::map<std::filesystem::path, Entry> entries_;
+
+  mutable std::mutex mutex_;
+};
+}
+
+#endif
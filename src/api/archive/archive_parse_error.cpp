@@ -0,0 +1,40 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+#include "loot/exception/archive_parse_error.h"
+
+namespace loot {
+ArchiveParseError::ArchiveParseError(const std::filesystem::path& archivePath,
+                                     const std::string& details) :
+    FileAccessError("Unable to parse the archive \"" + archivePath.u8string() +
+                        "\": " + details,
+                    archivePath),
+    archivePath_(archivePath),
+    details_(details) {}
+
+std::filesystem::path ArchiveParseError::GetArchivePath() const {
+  return archivePath_;
+}
+
+std::string ArchiveParseError::GetDetails() const { return details_; }
+}
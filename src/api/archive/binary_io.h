@@ -0,0 +1,69 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_API_ARCHIVE_BINARY_IO
+#define LOOT_API_ARCHIVE_BINARY_IO
+
+#include <cstdint>
+#include <istream>
+#include <ostream>
+#include <string>
+
+namespace loot {
+// Archives are little-endian, read and write them byte-by-byte so that the
+// host's endianness doesn't matter.
+template<typename T>
+T ReadLittleEndian(std::istream& in) {
+  unsigned char bytes[sizeof(T)];
+  in.read(reinterpret_cast<char*>(bytes), sizeof(T));
+
+  T value = 0;
+  for (size_t i = 0; i < sizeof(T); ++i) {
+    value |= static_cast<T>(bytes[i]) << (8 * i);
+  }
+
+  return value;
+}
+
+// Get the number of bytes between the stream's current position and its end,
+// so that lengths and counts read from a file can be checked before they're
+// used to allocate memory.
+inline uint64_t GetRemainingLength(std::istream& in) {
+  const auto position = in.tellg();
+  in.seekg(0, std::ios::end);
+  const auto end = in.tellg();
+  in.seekg(position);
+
+  return end > position ? static_cast<uint64_t>(end - position) : 0;
+}
+
+template<typename T>
+void WriteLittleEndian(std::ostream& out, T value) {
+  for (size_t i = 0; i < sizeof(T); ++i) {
+    out.put(static_cast<char>((value >> (8 * i)) & 0xFF));
+  }
+}
+}
+
+#endif
@@ -0,0 +1,313 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#include "api/archive/bsa.h"
+
+#include <fstream>
+#include <iomanip>
+#include <sstream>
+
+#include "api/archive/binary_io.h"
+#include "api/helpers/logging.h"
+#include "loot/exception/archive_parse_error.h"
+
+namespace loot {
+static constexpr uint32_t BSA_FLAG_INCLUDE_DIRECTORY_NAMES = 0x1;
+static constexpr uint32_t BSA_FLAG_INCLUDE_FILE_NAMES = 0x2;
+static constexpr uint32_t BSA_FLAG_XBOX_ARCHIVE = 0x40;
+static constexpr uint32_t BSA_FLAG_EMBED_FILE_NAMES = 0x100;
+
+static constexpr size_t BA2_GENERAL_RECORD_REMAINDER_SIZE = 24;
+static constexpr size_t BA2_TEXTURE_RECORD_REMAINDER_SIZE = 10;
+static constexpr size_t BA2_TEXTURE_CHUNK_SIZE = 24;
+
+static constexpr size_t BSA_FOLDER_RECORD_SIZE = 16;
+static constexpr size_t BSA_V105_FOLDER_RECORD_SIZE = 24;
+static constexpr size_t BSA_FILE_RECORD_SIZE = 16;
+static constexpr size_t BA2_RECORD_PREFIX_SIZE = 12;
+
+static std::string ReadBString(std::istream& in) {
+  const auto length = ReadLittleEndian<uint8_t>(in);
+
+  std::string value(length, '\0');
+  in.read(&value[0], length);
+
+  // The length may include a null terminator.
+  if (!value.empty() && value.back() == '\0') {
+    value.pop_back();
+  }
+
+  return value;
+}
+
+// Counts are read from the archive, so check that there's enough data left
+// to hold the records they describe before allocating space for them.
+static void CheckRecordCount(std::istream& in,
+                             const std::filesystem::path& path,
+                             uint64_t count,
+                             size_t recordSize,
+                             const std::string& recordType) {
+  if (count > GetRemainingLength(in) / recordSize) {
+    throw ArchiveParseError(path,
+                            "the header lists " + std::to_string(count) +
+                                " " + recordType +
+                                " but the archive is too short to hold them");
+  }
+}
+
+static void SetNamesFromPath(ArchiveAsset& asset,
+                             const std::string& assetPath) {
+  const auto separatorPos = assetPath.find_last_of("\\/");
+  if (separatorPos == std::string::npos) {
+    asset.folderName = "";
+    asset.fileName = assetPath;
+  } else {
+    asset.folderName = assetPath.substr(0, separatorPos);
+    asset.fileName = assetPath.substr(separatorPos + 1);
+  }
+}
+
+static std::vector<ArchiveAsset> GetAssetsInBsa(
+    std::istream& in,
+    const std::filesystem::path& path) {
+  const auto version = ReadLittleEndian<uint32_t>(in);
+  if (version != 103 && version != 104 && version != 105) {
+    throw ArchiveParseError(
+        path, "unsupported BSA version: " + std::to_string(version));
+  }
+
+  const auto folderRecordsOffset = ReadLittleEndian<uint32_t>(in);
+  const auto archiveFlags = ReadLittleEndian<uint32_t>(in);
+  const auto folderCount = ReadLittleEndian<uint32_t>(in);
+  const auto fileCount = ReadLittleEndian<uint32_t>(in);
+
+  if (archiveFlags & BSA_FLAG_XBOX_ARCHIVE) {
+    throw ArchiveParseError(path, "Xbox archives are not supported");
+  }
+
+  in.seekg(folderRecordsOffset);
+
+  CheckRecordCount(in,
+                   path,
+                   folderCount,
+                   version == 105 ? BSA_V105_FOLDER_RECORD_SIZE
+                                  : BSA_FOLDER_RECORD_SIZE,
+                   "folders");
+
+  std::vector<std::pair<uint64_t, uint32_t>> folderRecords;
+  folderRecords.reserve(folderCount);
+  uint64_t totalFileCount = 0;
+  for (uint32_t i = 0; i < folderCount; ++i) {
+    const auto folderHash = ReadLittleEndian<uint64_t>(in);
+    const auto folderFileCount = ReadLittleEndian<uint32_t>(in);
+
+    // Skip the file records offset. Skyrim SE's v105 format pads it and
+    // widens it to 64 bits.
+    if (version == 105) {
+      in.ignore(12);
+    } else {
+      in.ignore(4);
+    }
+
+    folderRecords.push_back(std::make_pair(folderHash, folderFileCount));
+    totalFileCount += folderFileCount;
+  }
+
+  if (totalFileCount != fileCount) {
+    throw ArchiveParseError(path,
+                            "the folder records list " +
+                                std::to_string(totalFileCount) +
+                                " files but the header lists " +
+                                std::to_string(fileCount));
+  }
+
+  CheckRecordCount(in, path, fileCount, BSA_FILE_RECORD_SIZE, "files");
+
+  std::vector<ArchiveAsset> assets;
+  std::vector<uint32_t> dataOffsets;
+  assets.reserve(fileCount);
+  dataOffsets.reserve(fileCount);
+  for (const auto& folderRecord : folderRecords) {
+    std::optional<std::string> folderName;
+    if (archiveFlags & BSA_FLAG_INCLUDE_DIRECTORY_NAMES) {
+      folderName = ReadBString(in);
+    }
+
+    for (uint32_t i = 0; i < folderRecord.second; ++i) {
+      const auto fileHash = ReadLittleEndian<uint64_t>(in);
+
+      // Skip the file size, which includes a compression toggle bit.
+      in.ignore(4);
+      dataOffsets.push_back(ReadLittleEndian<uint32_t>(in));
+
+      assets.push_back(
+          ArchiveAsset{folderRecord.first, fileHash, folderName, std::nullopt});
+    }
+  }
+
+  if (archiveFlags & BSA_FLAG_INCLUDE_FILE_NAMES) {
+    for (auto& asset : assets) {
+      std::string fileName;
+      std::getline(in, fileName, '\0');
+      asset.fileName = fileName;
+    }
+  } else if (version != 103 && (archiveFlags & BSA_FLAG_EMBED_FILE_NAMES)) {
+    // Fallout 3 onwards can store each file's full path at the start of its
+    // data instead of in a file names block. Oblivion uses the same flag for
+    // something else.
+    for (size_t i = 0; i < assets.size(); ++i) {
+      in.seekg(dataOffsets[i]);
+
+      const auto length = ReadLittleEndian<uint8_t>(in);
+      std::string assetPath(length, '\0');
+      in.read(&assetPath[0], length);
+
+      SetNamesFromPath(assets[i], assetPath);
+    }
+  }
+
+  return assets;
+}
+
+static std::vector<ArchiveAsset> GetAssetsInBa2(
+    std::istream& in,
+    const std::filesystem::path& path) {
+  const auto version = ReadLittleEndian<uint32_t>(in);
+
+  char archiveType[4];
+  in.read(archiveType, 4);
+  const std::string type(archiveType, 4);
+
+  const auto fileCount = ReadLittleEndian<uint32_t>(in);
+  const auto nameTableOffset = ReadLittleEndian<uint64_t>(in);
+
+  // Versions 2 and 3 extend the header with extra fields.
+  if (version == 2) {
+    in.ignore(8);
+  } else if (version == 3) {
+    in.ignore(12);
+  }
+
+  if (type != "GNRL" && type != "DX10") {
+    throw ArchiveParseError(path, "unsupported BA2 type: " + type);
+  }
+
+  const auto recordSize =
+      BA2_RECORD_PREFIX_SIZE + (type == "GNRL"
+                                    ? BA2_GENERAL_RECORD_REMAINDER_SIZE
+                                    : 2 + BA2_TEXTURE_RECORD_REMAINDER_SIZE);
+  CheckRecordCount(in, path, fileCount, recordSize, "files");
+
+  std::vector<ArchiveAsset> assets;
+  assets.reserve(fileCount);
+  for (uint32_t i = 0; i < fileCount; ++i) {
+    const auto nameHash = ReadLittleEndian<uint32_t>(in);
+    const auto extension = ReadLittleEndian<uint32_t>(in);
+    const auto directoryHash = ReadLittleEndian<uint32_t>(in);
+
+    if (type == "GNRL") {
+      in.ignore(BA2_GENERAL_RECORD_REMAINDER_SIZE);
+    } else {
+      in.ignore(1);
+      const auto chunkCount = ReadLittleEndian<uint8_t>(in);
+      in.ignore(BA2_TEXTURE_RECORD_REMAINDER_SIZE);
+      in.ignore(chunkCount * BA2_TEXTURE_CHUNK_SIZE);
+    }
+
+    // BA2 files identify files by their name and extension separately, so
+    // combine the two into a single hash value.
+    const uint64_t fileHash = (static_cast<uint64_t>(nameHash) << 32) | extension;
+
+    assets.push_back(
+        ArchiveAsset{directoryHash, fileHash, std::nullopt, std::nullopt});
+  }
+
+  if (nameTableOffset != 0) {
+    in.seekg(nameTableOffset);
+
+    for (auto& asset : assets) {
+      const auto length = ReadLittleEndian<uint16_t>(in);
+      std::string name(length, '\0');
+      in.read(&name[0], length);
+
+      SetNamesFromPath(asset, name);
+    }
+  }
+
+  return assets;
+}
+
+std::vector<ArchiveAsset> GetAssetsInBethesdaArchive(
+    const std::filesystem::path& archivePath) {
+  auto logger = getLogger();
+  if (logger) {
+    logger->trace("Reading assets in archive: {}", archivePath.u8string());
+  }
+
+  std::ifstream in(archivePath, std::ios::binary);
+  if (!in.is_open()) {
+    throw FileAccessError(
+        "Unable to open the archive \"" + archivePath.u8string() + "\"",
+        archivePath);
+  }
+
+  try {
+    in.exceptions(std::ios_base::badbit | std::ios_base::failbit);
+
+    char magic[4];
+    in.read(magic, 4);
+    const std::string fileId(magic, 4);
+
+    if (fileId == std::string("BSA\0", 4)) {
+      return GetAssetsInBsa(in, archivePath);
+    } else if (fileId == "BTDX") {
+      return GetAssetsInBa2(in, archivePath);
+    }
+  } catch (std::ios_base::failure&) {
+    // Reads fail if they go past the end of the file, which is only
+    // possible if the archive's offsets or counts are wrong.
+    throw ArchiveParseError(archivePath, "unexpected end of file");
+  }
+
+  throw ArchiveParseError(archivePath, "not a recognised archive format");
+}
+
+static std::string HashToHexString(uint64_t hash) {
+  std::stringstream stream;
+  stream << "0x" << std::hex << std::setw(16) << std::setfill('0') << hash;
+  return stream.str();
+}
+
+std::string GetAssetPath(const ArchiveAsset& asset) {
+  const auto fileName =
+      asset.fileName.value_or(HashToHexString(asset.fileHash));
+
+  if (asset.folderName.has_value() && asset.folderName.value().empty()) {
+    return fileName;
+  }
+
+  return asset.folderName.value_or(HashToHexString(asset.folderHash)) + "\\" +
+         fileName;
+}
+}
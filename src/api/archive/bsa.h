@@ -0,0 +1,55 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_API_ARCHIVE_BSA
+#define LOOT_API_ARCHIVE_BSA
+
+#include <cstdint>
+#include <filesystem>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace loot {
+struct ArchiveAsset {
+  uint64_t folderHash;
+  uint64_t fileHash;
+  // Archives don't necessarily store folder and file names, so these are
+  // only set if the archive records them.
+  std::optional<std::string> folderName;
+  std::optional<std::string> fileName;
+};
+
+// Read the asset records from a BSA or BA2 archive. Morrowind's BSA format is
+// not supported. Throws a FileAccessError if the archive cannot be opened,
+// or an ArchiveParseError if its contents are invalid or unsupported.
+std::vector<ArchiveAsset> GetAssetsInBethesdaArchive(
+    const std::filesystem::path& archivePath);
+
+// Get the asset's path, using its folder and/or file hash as a hexadecimal
+// string in place of any name that is not known.
+std::string GetAssetPath(const ArchiveAsset& asset);
+}
+
+#endif
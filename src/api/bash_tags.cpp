@@ -0,0 +1,113 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#include "api/bash_tags.h"
+
+#include <fstream>
+#include <set>
+
+#include <boost/algorithm/string.hpp>
+
+#include "api/helpers/logging.h"
+#include "loot/exception/file_access_error.h"
+
+namespace loot {
+std::vector<Tag> ReadBashTagsFile(const std::filesystem::path& dataPath,
+                                  const std::string& pluginName) {
+  const auto filePath =
+      dataPath / "BashTags" /
+      (std::filesystem::u8path(pluginName).stem().u8string() + ".txt");
+
+  if (!std::filesystem::exists(filePath)) {
+    return {};
+  }
+
+  auto logger = getLogger();
+  if (logger) {
+    logger->trace("Reading Bash Tags from: {}", filePath.u8string());
+  }
+
+  std::ifstream in(filePath);
+  if (!in.is_open()) {
+    throw FileAccessError(
+        "Couldn't open \"" + filePath.u8string() + "\" to read Bash Tags.",
+        filePath);
+  }
+
+  std::vector<Tag> tags;
+  std::string line;
+  while (std::getline(in, line)) {
+    const auto commentStart = line.find('#');
+    if (commentStart != std::string::npos) {
+      line.erase(commentStart);
+    }
+
+    std::vector<std::string> names;
+    boost::split(names, line, [](char c) { return c == ','; });
+
+    for (auto& name : names) {
+      boost::trim(name);
+      if (name.empty()) {
+        continue;
+      }
+
+      if (name[0] == '-') {
+        name.erase(0, 1);
+        boost::trim(name);
+        if (!name.empty()) {
+          tags.push_back(Tag(name, false));
+        }
+      } else {
+        tags.push_back(Tag(name));
+      }
+    }
+  }
+
+  return tags;
+}
+
+EffectiveBashTags ResolveBashTags(
+    const std::vector<std::vector<Tag>>& tagSources) {
+  std::set<std::string> added;
+  std::set<std::string> removed;
+
+  for (const auto& tags : tagSources) {
+    for (const auto& tag : tags) {
+      if (tag.IsAddition()) {
+        removed.erase(tag.GetName());
+        added.insert(tag.GetName());
+      } else {
+        added.erase(tag.GetName());
+        removed.insert(tag.GetName());
+      }
+    }
+  }
+
+  EffectiveBashTags effectiveTags;
+  effectiveTags.added.assign(added.begin(), added.end());
+  effectiveTags.removed.assign(removed.begin(), removed.end());
+
+  return effectiveTags;
+}
+}
@@ -0,0 +1,50 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_API_BASH_TAGS
+#define LOOT_API_BASH_TAGS
+
+#include <filesystem>
+#include <string>
+#include <vector>
+
+#include "loot/metadata/tag.h"
+#include "loot/struct/effective_bash_tags.h"
+
+namespace loot {
+// Read the tags in the BashTags/<plugin basename>.txt file in the given data
+// path. The file holds comma-separated tag names, which may be split across
+// lines, and names prefixed with a minus sign are removals. Anything after a
+// # on a line is a comment. If the file doesn't exist, no tags are returned.
+std::vector<Tag> ReadBashTagsFile(const std::filesystem::path& dataPath,
+                                  const std::string& pluginName);
+
+// Combine the given sources of tags, with later sources taking precedence
+// over earlier sources. Tag conditions are ignored, so any conditions should
+// be evaluated first.
+EffectiveBashTags ResolveBashTags(
+    const std::vector<std::vector<Tag>>& tagSources);
+}
+
+#endif
@@ -0,0 +1,923 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#include "loot/capi.h"
+
+#include <cstdlib>
+#include <cstring>
+#include <filesystem>
+#include <ios>
+#include <regex>
+
+#include <yaml-cpp/yaml.h>
+
+#include "loot/api.h"
+
+using loot::DatabaseInterface;
+using loot::File;
+using loot::GameInterface;
+using loot::PluginMetadata;
+using loot::Tag;
+
+struct loot_game {
+  std::shared_ptr<GameInterface> game;
+};
+
+struct loot_plugin_metadata {
+  PluginMetadata metadata;
+};
+
+namespace {
+thread_local std::string lastErrorMessage;
+
+// These are more specific than the std::invalid_argument that the C++ API
+// throws, so that the C API can give them their own error codes.
+class GameNotFoundError : public std::invalid_argument {
+public:
+  using std::invalid_argument::invalid_argument;
+};
+
+class PluginNotLoadedError : public std::invalid_argument {
+public:
+  using std::invalid_argument::invalid_argument;
+};
+
+template<typename T>
+void RequireNonNull(T* pointer, const char* name) {
+  if (pointer == nullptr) {
+    throw std::invalid_argument(std::string("The argument \"") + name +
+                                "\" must not be null.");
+  }
+}
+
+template<typename F>
+loot_error_code Call(F function) {
+  try {
+    function();
+    lastErrorMessage.clear();
+    return LOOT_OK;
+  } catch (const loot::ArchiveParseError& e) {
+    lastErrorMessage = e.what();
+    return LOOT_ERROR_ARCHIVE_PARSE;
+  } catch (const loot::FileAccessError& e) {
+    lastErrorMessage = e.what();
+    return LOOT_ERROR_FILE_ACCESS;
+  } catch (const loot::ConditionSyntaxError& e) {
+    lastErrorMessage = e.what();
+    return LOOT_ERROR_CONDITION_SYNTAX;
+  } catch (const loot::CyclicInteractionError& e) {
+    lastErrorMessage = e.what();
+    return LOOT_ERROR_CYCLIC_INTERACTION;
+  } catch (const loot::UndefinedGroupError& e) {
+    lastErrorMessage = e.what();
+    return LOOT_ERROR_UNDEFINED_GROUP;
+  } catch (const loot::SortingCancelledError& e) {
+    lastErrorMessage = e.what();
+    return LOOT_ERROR_SORTING_CANCELLED;
+  } catch (const loot::GitStateError& e) {
+    lastErrorMessage = e.what();
+    return LOOT_ERROR_GIT_STATE;
+  } catch (const YAML::Exception& e) {
+    lastErrorMessage = e.what();
+    return LOOT_ERROR_METADATA_PARSE;
+  } catch (const GameNotFoundError& e) {
+    lastErrorMessage = e.what();
+    return LOOT_ERROR_GAME_NOT_FOUND;
+  } catch (const PluginNotLoadedError& e) {
+    lastErrorMessage = e.what();
+    return LOOT_ERROR_PLUGIN_NOT_LOADED;
+  } catch (const std::invalid_argument& e) {
+    lastErrorMessage = e.what();
+    return LOOT_ERROR_INVALID_ARGUMENT;
+  } catch (const std::regex_error& e) {
+    lastErrorMessage = e.what();
+    return LOOT_ERROR_INVALID_ARGUMENT;
+  } catch (const std::filesystem::filesystem_error& e) {
+    lastErrorMessage = e.what();
+    return LOOT_ERROR_IO;
+  } catch (const std::ios_base::failure& e) {
+    lastErrorMessage = e.what();
+    return LOOT_ERROR_IO;
+  } catch (const std::system_error& e) {
+    lastErrorMessage = e.what();
+    return LOOT_ERROR_SYSTEM;
+  } catch (const std::exception& e) {
+    lastErrorMessage = e.what();
+    return LOOT_ERROR_UNKNOWN;
+  } catch (...) {
+    lastErrorMessage = "An unknown error occurred.";
+    return LOOT_ERROR_UNKNOWN;
+  }
+}
+
+char* CopyString(const std::string& string) {
+  auto copy = static_cast<char*>(std::malloc(string.size() + 1));
+  if (copy == nullptr) {
+    throw std::bad_alloc();
+  }
+
+  std::memcpy(copy, string.c_str(), string.size() + 1);
+  return copy;
+}
+
+void SetStringArray(loot_string_array* array,
+                    const std::vector<std::string>& strings) {
+  array->items = nullptr;
+  array->count = 0;
+
+  if (strings.empty()) {
+    return;
+  }
+
+  array->items =
+      static_cast<char**>(std::calloc(strings.size(), sizeof(char*)));
+  if (array->items == nullptr) {
+    throw std::bad_alloc();
+  }
+
+  try {
+    for (const auto& string : strings) {
+      array->items[array->count] = CopyString(string);
+      array->count += 1;
+    }
+  } catch (...) {
+    loot_free_string_array(array);
+    throw;
+  }
+}
+
+void SetMessageArray(loot_message_array* array,
+                     const std::vector<loot::Message>& messages,
+                     const std::string& language) {
+  array->items = nullptr;
+  array->count = 0;
+
+  if (messages.empty()) {
+    return;
+  }
+
+  array->items = static_cast<loot_message*>(
+      std::calloc(messages.size(), sizeof(loot_message)));
+  if (array->items == nullptr) {
+    throw std::bad_alloc();
+  }
+
+  try {
+    for (const auto& message : messages) {
+      const auto simpleMessage = message.ToSimpleMessage(language);
+
+      array->items[array->count].type =
+          static_cast<unsigned int>(simpleMessage.type);
+      array->items[array->count].text = CopyString(simpleMessage.text);
+      array->count += 1;
+    }
+  } catch (...) {
+    loot_free_message_array(array);
+    throw;
+  }
+}
+
+loot::WriteMode ToWriteMode(loot_write_mode mode) {
+  switch (mode) {
+    case LOOT_WRITE_MODE_CREATE:
+      return loot::WriteMode::create;
+    case LOOT_WRITE_MODE_CREATE_OR_TRUNCATE:
+      return loot::WriteMode::createOrTruncate;
+    default:
+      throw std::invalid_argument("Invalid write mode: " +
+                                  std::to_string(static_cast<int>(mode)));
+  }
+}
+
+std::vector<std::string> ToVector(const char* const* strings, size_t count) {
+  if (count > 0) {
+    RequireNonNull(strings, "strings");
+  }
+
+  std::vector<std::string> vector;
+  for (size_t i = 0; i < count; ++i) {
+    RequireNonNull(strings[i], "strings[i]");
+    vector.push_back(strings[i]);
+  }
+
+  return vector;
+}
+
+std::vector<File> ToFiles(const char* const* names, size_t count) {
+  std::vector<File> files;
+  for (const auto& name : ToVector(names, count)) {
+    files.push_back(File(name));
+  }
+
+  return files;
+}
+
+std::vector<std::string> GetFileNames(const std::vector<File>& files) {
+  std::vector<std::string> names;
+  for (const auto& file : files) {
+    names.push_back(std::string(file.GetName()));
+  }
+
+  return names;
+}
+
+std::shared_ptr<DatabaseInterface> GetDatabase(loot_game* game) {
+  RequireNonNull(game, "game");
+  return game->game->GetDatabase();
+}
+}
+
+LOOT_API const char* loot_get_last_error_message(void) {
+  return lastErrorMessage.c_str();
+}
+
+LOOT_API void loot_free_string(char* string) { std::free(string); }
+
+LOOT_API void loot_free_string_array(loot_string_array* array) {
+  if (array == nullptr) {
+    return;
+  }
+
+  for (size_t i = 0; i < array->count; ++i) {
+    std::free(array->items[i]);
+  }
+  std::free(array->items);
+
+  array->items = nullptr;
+  array->count = 0;
+}
+
+LOOT_API void loot_free_message_array(loot_message_array* array) {
+  if (array == nullptr) {
+    return;
+  }
+
+  for (size_t i = 0; i < array->count; ++i) {
+    std::free(array->items[i].text);
+  }
+  std::free(array->items);
+
+  array->items = nullptr;
+  array->count = 0;
+}
+
+LOOT_API loot_error_code loot_get_version(char** version) {
+  return Call([&]() {
+    RequireNonNull(version, "version");
+    *version = CopyString(loot::LootVersion::GetVersionString());
+  });
+}
+
+LOOT_API bool loot_is_compatible(unsigned int major,
+                                 unsigned int minor,
+                                 unsigned int patch) {
+  return loot::IsCompatible(major, minor, patch);
+}
+
+LOOT_API bool loot_has_capability(const char* capability) {
+  if (capability == nullptr) {
+    return false;
+  }
+
+  return loot::HasCapability(capability);
+}
+
+LOOT_API loot_error_code loot_set_logging_callback(
+    loot_logging_callback callback,
+    void* context) {
+  return Call([&]() {
+    if (callback == nullptr) {
+      loot::SetLoggingCallback([](loot::LogLevel, const char*) {});
+      return;
+    }
+
+    loot::SetLoggingCallback(
+        [callback, context](loot::LogLevel level, const char* message) {
+          callback(static_cast<unsigned int>(level), message, context);
+        });
+  });
+}
+
+LOOT_API loot_error_code loot_create_game(unsigned int game_type,
+                                          const char* game_path,
+                                          const char* game_local_path,
+                                          loot_game** game) {
+  return loot_create_game_ex(
+      game_type, game_path, game_local_path, false, game);
+}
+
+LOOT_API loot_error_code loot_create_game_ex(unsigned int game_type,
+                                             const char* game_path,
+                                             const char* game_local_path,
+                                             bool thread_safe,
+                                             loot_game** game) {
+  return Call([&]() {
+    RequireNonNull(game_path, "game_path");
+    RequireNonNull(game, "game");
+
+    const auto gamePath = std::filesystem::u8path(game_path);
+    if (!std::filesystem::is_directory(gamePath)) {
+      throw GameNotFoundError(std::string("The game path \"") + game_path +
+                              "\" is not a directory.");
+    }
+
+    const auto localPath = game_local_path == nullptr
+                               ? std::filesystem::path()
+                               : std::filesystem::u8path(game_local_path);
+
+    auto handle =
+        loot::CreateGameHandle(static_cast<loot::GameType>(game_type),
+                               gamePath,
+                               localPath,
+                               thread_safe);
+
+    *game = new loot_game{handle};
+  });
+}
+
+LOOT_API void loot_destroy_game(loot_game* game) { delete game; }
+
+LOOT_API loot_error_code loot_game_is_valid_plugin(loot_game* game,
+                                                   const char* plugin,
+                                                   bool* is_valid) {
+  return Call([&]() {
+    RequireNonNull(game, "game");
+    RequireNonNull(plugin, "plugin");
+    RequireNonNull(is_valid, "is_valid");
+
+    *is_valid = game->game->IsValidPlugin(plugin);
+  });
+}
+
+LOOT_API loot_error_code loot_game_load_plugins(loot_game* game,
+                                                const char* const* plugins,
+                                                size_t plugin_count,
+                                                bool load_headers_only) {
+  return Call([&]() {
+    RequireNonNull(game, "game");
+
+    game->game->LoadPlugins(ToVector(plugins, plugin_count),
+                            load_headers_only);
+  });
+}
+
+LOOT_API loot_error_code loot_game_get_plugin_info(loot_game* game,
+                                                   const char* plugin,
+                                                   char** version,
+                                                   loot_string_array* masters,
+                                                   bool* is_master,
+                                                   bool* is_light_plugin,
+                                                   bool* is_empty) {
+  return Call([&]() {
+    RequireNonNull(game, "game");
+    RequireNonNull(plugin, "plugin");
+
+    auto loadedPlugin = game->game->GetPlugin(plugin);
+    if (!loadedPlugin) {
+      throw PluginNotLoadedError(std::string("The plugin \"") + plugin +
+                                 "\" has not been loaded.");
+    }
+
+    // Read everything before allocating anything, so that nothing needs to
+    // be freed if reading fails.
+    const auto pluginVersion = loadedPlugin->GetVersion();
+    const auto pluginMasters = loadedPlugin->GetMasters();
+    const auto isMaster = loadedPlugin->IsMaster();
+    const auto isLightPlugin = loadedPlugin->IsLightPlugin();
+    const auto isEmpty = loadedPlugin->IsEmpty();
+
+    char* versionCopy = nullptr;
+    if (version != nullptr && pluginVersion.has_value()) {
+      versionCopy = CopyString(pluginVersion.value());
+    }
+    if (masters != nullptr) {
+      try {
+        SetStringArray(masters, pluginMasters);
+      } catch (...) {
+        std::free(versionCopy);
+        throw;
+      }
+    }
+
+    if (version != nullptr) {
+      *version = versionCopy;
+    }
+    if (is_master != nullptr) {
+      *is_master = isMaster;
+    }
+    if (is_light_plugin != nullptr) {
+      *is_light_plugin = isLightPlugin;
+    }
+    if (is_empty != nullptr) {
+      *is_empty = isEmpty;
+    }
+  });
+}
+
+LOOT_API loot_error_code loot_game_identify_main_master_file(
+    loot_game* game,
+    const char* master_file) {
+  return Call([&]() {
+    RequireNonNull(game, "game");
+    RequireNonNull(master_file, "master_file");
+
+    game->game->IdentifyMainMasterFile(master_file);
+  });
+}
+
+LOOT_API loot_error_code loot_game_sort_plugins(
+    loot_game* game,
+    const char* const* plugins,
+    size_t plugin_count,
+    loot_string_array* sorted_plugins) {
+  return Call([&]() {
+    RequireNonNull(game, "game");
+    RequireNonNull(sorted_plugins, "sorted_plugins");
+
+    SetStringArray(sorted_plugins,
+                   game->game->SortPlugins(ToVector(plugins, plugin_count)));
+  });
+}
+
+LOOT_API loot_error_code loot_game_sort_plugins_with_progress(
+    loot_game* game,
+    const char* const* plugins,
+    size_t plugin_count,
+    loot_sorting_progress_callback callback,
+    void* context,
+    loot_string_array* sorted_plugins) {
+  return Call([&]() {
+    RequireNonNull(game, "game");
+    RequireNonNull(sorted_plugins, "sorted_plugins");
+
+    loot::SortingProgressCallback progressCallback;
+    if (callback != nullptr) {
+      progressCallback = [callback, context](const std::string& phase,
+                                             size_t phaseIndex,
+                                             size_t phaseCount) {
+        return callback(phase.c_str(), phaseIndex, phaseCount, context);
+      };
+    }
+
+    SetStringArray(sorted_plugins,
+                   game->game->SortPlugins(ToVector(plugins, plugin_count),
+                                           progressCallback));
+  });
+}
+
+LOOT_API loot_error_code loot_game_load_current_load_order_state(
+    loot_game* game) {
+  return Call([&]() {
+    RequireNonNull(game, "game");
+
+    game->game->LoadCurrentLoadOrderState();
+  });
+}
+
+LOOT_API loot_error_code loot_game_is_plugin_active(loot_game* game,
+                                                    const char* plugin,
+                                                    bool* is_active) {
+  return Call([&]() {
+    RequireNonNull(game, "game");
+    RequireNonNull(plugin, "plugin");
+    RequireNonNull(is_active, "is_active");
+
+    *is_active = game->game->IsPluginActive(plugin);
+  });
+}
+
+LOOT_API loot_error_code loot_game_get_load_order(
+    loot_game* game,
+    loot_string_array* load_order) {
+  return Call([&]() {
+    RequireNonNull(game, "game");
+    RequireNonNull(load_order, "load_order");
+
+    SetStringArray(load_order, game->game->GetLoadOrder());
+  });
+}
+
+LOOT_API loot_error_code loot_game_set_load_order(loot_game* game,
+                                                  const char* const* plugins,
+                                                  size_t plugin_count) {
+  return Call([&]() {
+    RequireNonNull(game, "game");
+
+    game->game->SetLoadOrder(ToVector(plugins, plugin_count));
+  });
+}
+
+LOOT_API loot_error_code loot_db_load_lists(loot_game* game,
+                                            const char* masterlist_path,
+                                            const char* userlist_path) {
+  return Call([&]() {
+    RequireNonNull(masterlist_path, "masterlist_path");
+
+    const auto userlistPath = userlist_path == nullptr
+                                  ? std::filesystem::path()
+                                  : std::filesystem::u8path(userlist_path);
+
+    GetDatabase(game)->LoadLists(std::filesystem::u8path(masterlist_path),
+                                 userlistPath);
+  });
+}
+
+LOOT_API loot_error_code loot_db_write_user_metadata(loot_game* game,
+                                                     const char* output_path,
+                                                     loot_write_mode mode) {
+  return Call([&]() {
+    RequireNonNull(output_path, "output_path");
+
+    GetDatabase(game)->WriteUserMetadata(std::filesystem::u8path(output_path),
+                                         ToWriteMode(mode));
+  });
+}
+
+LOOT_API loot_error_code loot_db_write_minimal_list(loot_game* game,
+                                                    const char* output_path,
+                                                    loot_write_mode mode) {
+  return Call([&]() {
+    RequireNonNull(output_path, "output_path");
+
+    GetDatabase(game)->WriteMinimalList(std::filesystem::u8path(output_path),
+                                        ToWriteMode(mode));
+  });
+}
+
+LOOT_API loot_error_code loot_db_update_masterlist(loot_game* game,
+                                                   const char* masterlist_path,
+                                                   const char* remote_url,
+                                                   const char* remote_branch,
+                                                   bool* was_updated) {
+  return Call([&]() {
+    RequireNonNull(masterlist_path, "masterlist_path");
+    RequireNonNull(remote_url, "remote_url");
+    RequireNonNull(remote_branch, "remote_branch");
+    RequireNonNull(was_updated, "was_updated");
+
+    *was_updated = GetDatabase(game)->UpdateMasterlist(
+        std::filesystem::u8path(masterlist_path), remote_url, remote_branch);
+  });
+}
+
+LOOT_API loot_error_code loot_db_get_masterlist_revision(
+    loot_game* game,
+    const char* masterlist_path,
+    bool get_short_id,
+    char** revision_id,
+    char** revision_date,
+    bool* is_modified) {
+  return Call([&]() {
+    RequireNonNull(masterlist_path, "masterlist_path");
+    RequireNonNull(revision_id, "revision_id");
+    RequireNonNull(revision_date, "revision_date");
+    RequireNonNull(is_modified, "is_modified");
+
+    const auto info = GetDatabase(game)->GetMasterlistRevision(
+        std::filesystem::u8path(masterlist_path), get_short_id);
+
+    auto revisionId = CopyString(info.revision_id);
+    try {
+      *revision_date = CopyString(info.revision_date);
+    } catch (...) {
+      std::free(revisionId);
+      throw;
+    }
+
+    *revision_id = revisionId;
+    *is_modified = info.is_modified;
+  });
+}
+
+LOOT_API loot_error_code loot_db_is_latest_masterlist(
+    loot_game* game,
+    const char* masterlist_path,
+    const char* branch,
+    bool* is_latest) {
+  return Call([&]() {
+    RequireNonNull(masterlist_path, "masterlist_path");
+    RequireNonNull(branch, "branch");
+    RequireNonNull(is_latest, "is_latest");
+
+    *is_latest = GetDatabase(game)->IsLatestMasterlist(
+        std::filesystem::u8path(masterlist_path), branch);
+  });
+}
+
+LOOT_API loot_error_code loot_db_get_known_bash_tags(loot_game* game,
+                                                     loot_string_array* tags) {
+  return Call([&]() {
+    RequireNonNull(tags, "tags");
+
+    SetStringArray(tags, GetDatabase(game)->GetKnownBashTags());
+  });
+}
+
+LOOT_API loot_error_code loot_db_get_general_messages(
+    loot_game* game,
+    const char* language,
+    bool evaluate_conditions,
+    loot_message_array* messages) {
+  return Call([&]() {
+    RequireNonNull(language, "language");
+    RequireNonNull(messages, "messages");
+
+    SetMessageArray(messages,
+                    GetDatabase(game)->GetGeneralMessages(evaluate_conditions),
+                    language);
+  });
+}
+
+LOOT_API loot_error_code loot_db_get_group_names(loot_game* game,
+                                                 bool include_user_metadata,
+                                                 loot_string_array* groups) {
+  return Call([&]() {
+    RequireNonNull(groups, "groups");
+
+    std::vector<std::string> names;
+    for (const auto& group :
+         GetDatabase(game)->GetGroups(include_user_metadata)) {
+      names.push_back(group.GetName());
+    }
+
+    SetStringArray(groups, names);
+  });
+}
+
+LOOT_API loot_error_code loot_db_validate_condition(loot_game* game,
+                                                   const char* condition) {
+  return Call([&]() {
+    RequireNonNull(condition, "condition");
+
+    GetDatabase(game)->ValidateCondition(condition);
+  });
+}
+
+LOOT_API loot_error_code loot_db_evaluate_condition(loot_game* game,
+                                                   const char* condition,
+                                                   bool* result) {
+  return Call([&]() {
+    RequireNonNull(condition, "condition");
+    RequireNonNull(result, "result");
+
+    *result = GetDatabase(game)->EvaluateConditions({condition}).front();
+  });
+}
+
+LOOT_API loot_error_code loot_db_clear_condition_cache(loot_game* game) {
+  return Call([&]() { GetDatabase(game)->ClearConditionCache(); });
+}
+
+LOOT_API loot_error_code loot_db_get_plugin_metadata(
+    loot_game* game,
+    const char* plugin,
+    bool include_user_metadata,
+    bool evaluate_conditions,
+    loot_plugin_metadata** metadata) {
+  return Call([&]() {
+    RequireNonNull(plugin, "plugin");
+    RequireNonNull(metadata, "metadata");
+
+    const auto pluginMetadata = GetDatabase(game)->GetPluginMetadata(
+        plugin, include_user_metadata, evaluate_conditions);
+
+    *metadata = pluginMetadata.has_value()
+                    ? new loot_plugin_metadata{pluginMetadata.value()}
+                    : nullptr;
+  });
+}
+
+LOOT_API loot_error_code loot_db_get_plugin_user_metadata(
+    loot_game* game,
+    const char* plugin,
+    bool evaluate_conditions,
+    loot_plugin_metadata** metadata) {
+  return Call([&]() {
+    RequireNonNull(plugin, "plugin");
+    RequireNonNull(metadata, "metadata");
+
+    const auto pluginMetadata =
+        GetDatabase(game)->GetPluginUserMetadata(plugin, evaluate_conditions);
+
+    *metadata = pluginMetadata.has_value()
+                    ? new loot_plugin_metadata{pluginMetadata.value()}
+                    : nullptr;
+  });
+}
+
+LOOT_API loot_error_code loot_db_set_plugin_user_metadata(
+    loot_game* game,
+    const loot_plugin_metadata* metadata) {
+  return Call([&]() {
+    RequireNonNull(metadata, "metadata");
+
+    GetDatabase(game)->SetPluginUserMetadata(metadata->metadata);
+  });
+}
+
+LOOT_API loot_error_code loot_db_discard_plugin_user_metadata(
+    loot_game* game,
+    const char* plugin) {
+  return Call([&]() {
+    RequireNonNull(plugin, "plugin");
+
+    GetDatabase(game)->DiscardPluginUserMetadata(plugin);
+  });
+}
+
+LOOT_API loot_error_code loot_db_discard_all_user_metadata(loot_game* game) {
+  return Call([&]() { GetDatabase(game)->DiscardAllUserMetadata(); });
+}
+
+LOOT_API loot_error_code loot_create_plugin_metadata(
+    const char* plugin,
+    loot_plugin_metadata** metadata) {
+  return Call([&]() {
+    RequireNonNull(plugin, "plugin");
+    RequireNonNull(metadata, "metadata");
+
+    *metadata = new loot_plugin_metadata{PluginMetadata(plugin)};
+  });
+}
+
+LOOT_API void loot_destroy_plugin_metadata(loot_plugin_metadata* metadata) {
+  delete metadata;
+}
+
+LOOT_API loot_error_code loot_plugin_metadata_get_group(
+    const loot_plugin_metadata* metadata,
+    char** group) {
+  return Call([&]() {
+    RequireNonNull(metadata, "metadata");
+    RequireNonNull(group, "group");
+
+    const auto pluginGroup = metadata->metadata.GetGroup();
+    *group =
+        pluginGroup.has_value() ? CopyString(pluginGroup.value()) : nullptr;
+  });
+}
+
+LOOT_API loot_error_code loot_plugin_metadata_set_group(
+    loot_plugin_metadata* metadata,
+    const char* group) {
+  return Call([&]() {
+    RequireNonNull(metadata, "metadata");
+
+    if (group == nullptr) {
+      metadata->metadata.UnsetGroup();
+    } else {
+      metadata->metadata.SetGroup(group);
+    }
+  });
+}
+
+LOOT_API loot_error_code loot_plugin_metadata_get_load_after(
+    const loot_plugin_metadata* metadata,
+    loot_string_array* files) {
+  return Call([&]() {
+    RequireNonNull(metadata, "metadata");
+    RequireNonNull(files, "files");
+
+    SetStringArray(files, GetFileNames(metadata->metadata.GetLoadAfterFiles()));
+  });
+}
+
+LOOT_API loot_error_code loot_plugin_metadata_set_load_after(
+    loot_plugin_metadata* metadata,
+    const char* const* files,
+    size_t file_count) {
+  return Call([&]() {
+    RequireNonNull(metadata, "metadata");
+
+    metadata->metadata.SetLoadAfterFiles(ToFiles(files, file_count));
+  });
+}
+
+LOOT_API loot_error_code loot_plugin_metadata_get_requirements(
+    const loot_plugin_metadata* metadata,
+    loot_string_array* files) {
+  return Call([&]() {
+    RequireNonNull(metadata, "metadata");
+    RequireNonNull(files, "files");
+
+    SetStringArray(files, GetFileNames(metadata->metadata.GetRequirements()));
+  });
+}
+
+LOOT_API loot_error_code loot_plugin_metadata_set_requirements(
+    loot_plugin_metadata* metadata,
+    const char* const* files,
+    size_t file_count) {
+  return Call([&]() {
+    RequireNonNull(metadata, "metadata");
+
+    metadata->metadata.SetRequirements(ToFiles(files, file_count));
+  });
+}
+
+LOOT_API loot_error_code loot_plugin_metadata_get_incompatibilities(
+    const loot_plugin_metadata* metadata,
+    loot_string_array* files) {
+  return Call([&]() {
+    RequireNonNull(metadata, "metadata");
+    RequireNonNull(files, "files");
+
+    SetStringArray(files,
+                   GetFileNames(metadata->metadata.GetIncompatibilities()));
+  });
+}
+
+LOOT_API loot_error_code loot_plugin_metadata_set_incompatibilities(
+    loot_plugin_metadata* metadata,
+    const char* const* files,
+    size_t file_count) {
+  return Call([&]() {
+    RequireNonNull(metadata, "metadata");
+
+    metadata->metadata.SetIncompatibilities(ToFiles(files, file_count));
+  });
+}
+
+LOOT_API loot_error_code loot_plugin_metadata_get_tags(
+    const loot_plugin_metadata* metadata,
+    loot_string_array* added_tags,
+    loot_string_array* removed_tags) {
+  return Call([&]() {
+    RequireNonNull(metadata, "metadata");
+    RequireNonNull(added_tags, "added_tags");
+    RequireNonNull(removed_tags, "removed_tags");
+
+    std::vector<std::string> added;
+    std::vector<std::string> removed;
+    for (const auto& tag : metadata->metadata.GetTags()) {
+      if (tag.IsAddition()) {
+        added.push_back(tag.GetName());
+      } else {
+        removed.push_back(tag.GetName());
+      }
+    }
+
+    SetStringArray(added_tags, added);
+    try {
+      SetStringArray(removed_tags, removed);
+    } catch (...) {
+      loot_free_string_array(added_tags);
+      throw;
+    }
+  });
+}
+
+LOOT_API loot_error_code loot_plugin_metadata_set_tags(
+    loot_plugin_metadata* metadata,
+    const char* const* added_tags,
+    size_t added_tag_count,
+    const char* const* removed_tags,
+    size_t removed_tag_count) {
+  return Call([&]() {
+    RequireNonNull(metadata, "metadata");
+
+    std::vector<Tag> tags;
+    for (const auto& name : ToVector(added_tags, added_tag_count)) {
+      tags.push_back(Tag(name, true));
+    }
+    for (const auto& name : ToVector(removed_tags, removed_tag_count)) {
+      tags.push_back(Tag(name, false));
+    }
+
+    metadata->metadata.SetTags(tags);
+  });
+}
+
+LOOT_API loot_error_code loot_plugin_metadata_get_messages(
+    const loot_plugin_metadata* metadata,
+    const char* language,
+    loot_message_array* messages) {
+  return Call([&]() {
+    RequireNonNull(metadata, "metadata");
+    RequireNonNull(language, "language");
+    RequireNonNull(messages, "messages");
+
+    SetMessageArray(messages, metadata->metadata.GetMessages(), language);
+  });
+}
@@ -0,0 +1,247 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#include "api/diagnostics.h"
+
+#include <iomanip>
+#include <sstream>
+
+#include "loot/api.h"
+
+namespace loot {
+std::string EscapeJsonString(const std::string& text) {
+  std::stringstream stream;
+  stream << '"';
+
+  for (const char character : text) {
+    switch (character) {
+      case '"':
+        stream << "\\\"";
+        break;
+      case '\\':
+        stream << "\\\\";
+        break;
+      case '\b':
+        stream << "\\b";
+        break;
+      case '\f':
+        stream << "\\f";
+        break;
+      case '\n':
+        stream << "\\n";
+        break;
+      case '\r':
+        stream << "\\r";
+        break;
+      case '\t':
+        stream << "\\t";
+        break;
+      default:
+        if (static_cast<unsigned char>(character) < 0x20) {
+          stream << "\\u" << std::hex << std::setw(4) << std::setfill('0')
+                 << static_cast<int>(character) << std::dec;
+        } else {
+          // Non-ASCII UTF-8 bytes can be written as they are.
+          stream << character;
+        }
+    }
+  }
+
+  stream << '"';
+  return stream.str();
+}
+
+static std::string GetGameTypeName(GameType gameType) {
+  switch (gameType) {
+    case GameType::tes3:
+      return "tes3";
+    case GameType::tes4:
+      return "tes4";
+    case GameType::tes5:
+      return "tes5";
+    case GameType::tes5se:
+      return "tes5se";
+    case GameType::tes5vr:
+      return "tes5vr";
+    case GameType::fo3:
+      return "fo3";
+    case GameType::fonv:
+      return "fonv";
+    case GameType::fo4:
+      return "fo4";
+    case GameType::fo4vr:
+      return "fo4vr";
+    default:
+      return "unknown";
+  }
+}
+
+static std::string GetLogLevelName(LogLevel level) {
+  switch (level) {
+    case LogLevel::trace:
+      return "trace";
+    case LogLevel::debug:
+      return "debug";
+    case LogLevel::info:
+      return "info";
+    case LogLevel::warning:
+      return "warning";
+    case LogLevel::error:
+      return "error";
+    case LogLevel::fatal:
+      return "fatal";
+    default:
+      return "unknown";
+  }
+}
+
+static std::string ToJsonPath(const std::filesystem::path& path) {
+  if (path.empty()) {
+    return "null";
+  }
+
+  return EscapeJsonString(path.u8string());
+}
+
+static std::string ToJsonArray(const std::vector<std::string>& values) {
+  std::string json = "[";
+  for (size_t i = 0; i < values.size(); ++i) {
+    if (i > 0) {
+      json += ",";
+    }
+    json += EscapeJsonString(values[i]);
+  }
+  return json + "]";
+}
+
+static std::string ToJson(const PluginInterface& plugin) {
+  std::stringstream stream;
+  stream << "{\"name\":" << EscapeJsonString(plugin.GetName());
+
+  stream << ",\"version\":";
+  const auto version = plugin.GetVersion();
+  if (version.has_value()) {
+    stream << EscapeJsonString(version.value());
+  } else {
+    stream << "null";
+  }
+
+  stream << ",\"crc\":";
+  const auto crc = plugin.GetCRC();
+  if (crc.has_value()) {
+    stream << "\"" << std::hex << std::uppercase << std::setw(8)
+           << std::setfill('0') << crc.value() << std::dec << "\"";
+  } else {
+    stream << "null";
+  }
+
+  stream << std::boolalpha << ",\"isMaster\":" << plugin.IsMaster()
+         << ",\"isLightPlugin\":" << plugin.IsLightPlugin()
+         << ",\"isEmpty\":" << plugin.IsEmpty()
+         << ",\"loadsArchive\":" << plugin.LoadsArchive()
+         << ",\"masters\":" << ToJsonArray(plugin.GetMasters()) << "}";
+
+  return stream.str();
+}
+
+static std::string ToJson(const MetadataList& metadataList) {
+  std::stringstream stream;
+  stream << "{\"plugins\":" << metadataList.Plugins().size()
+         << ",\"messages\":" << metadataList.Messages().size()
+         << ",\"groups\":" << metadataList.Groups().size()
+         << ",\"bashTags\":" << metadataList.BashTags().size() << "}";
+
+  return stream.str();
+}
+
+static std::string ToJson(const LogRecord& record) {
+  const auto milliseconds =
+      std::chrono::duration_cast<std::chrono::milliseconds>(
+          record.timestamp.time_since_epoch())
+          .count();
+
+  std::stringstream stream;
+  stream << "{\"level\":" << EscapeJsonString(GetLogLevelName(record.level))
+         << ",\"target\":" << EscapeJsonString(record.target)
+         << ",\"timestamp\":" << milliseconds
+         << ",\"message\":" << EscapeJsonString(record.message) << "}";
+
+  return stream.str();
+}
+
+std::string GenerateDiagnosticsReport(const Game& game,
+                                      const ApiDatabase& database) {
+  std::stringstream stream;
+
+  stream << "{\"libloot\":{\"version\":"
+         << EscapeJsonString(LootVersion::GetVersionString())
+         << ",\"revision\":" << EscapeJsonString(LootVersion::revision) << "}";
+
+  stream << ",\"game\":{\"type\":"
+         << EscapeJsonString(GetGameTypeName(game.Type()))
+         << ",\"gamePath\":" << ToJsonPath(game.GamePath())
+         << ",\"localPath\":" << ToJsonPath(game.LocalDataPath())
+         << ",\"dataPath\":" << ToJsonPath(game.DataPath())
+         << ",\"masterlistPath\":" << ToJsonPath(database.GetMasterlistPath())
+         << ",\"userlistPath\":" << ToJsonPath(database.GetUserlistPath())
+         << "}";
+
+  stream << ",\"loadOrder\":[";
+  const auto loadOrder = game.GetLoadOrder();
+  for (size_t i = 0; i < loadOrder.size(); ++i) {
+    if (i > 0) {
+      stream << ",";
+    }
+    stream << "{\"name\":" << EscapeJsonString(loadOrder[i])
+           << ",\"active\":" << std::boolalpha
+           << game.IsPluginActive(loadOrder[i]) << "}";
+  }
+  stream << "]";
+
+  stream << ",\"plugins\":[";
+  const auto plugins = game.GetLoadedPlugins();
+  for (size_t i = 0; i < plugins.size(); ++i) {
+    if (i > 0) {
+      stream << ",";
+    }
+    stream << ToJson(*plugins[i]);
+  }
+  stream << "]";
+
+  stream << ",\"masterlist\":" << ToJson(database.GetMasterlist())
+         << ",\"userlist\":" << ToJson(database.GetUserlist());
+
+  stream << ",\"logs\":[";
+  const auto records = GetBufferedLogRecords();
+  for (size_t i = 0; i < records.size(); ++i) {
+    if (i > 0) {
+      stream << ",";
+    }
+    stream << ToJson(records[i]);
+  }
+  stream << "]}";
+
+  return stream.str();
+}
+}
@@ -27,13 +27,19 @@
 #include <algorithm>
 #include <cmath>
 #include <map>
+#include <queue>
 #include <thread>
+#include <unordered_set>
 
 #include <boost/algorithm/string.hpp>
 
 #include "api/api_database.h"
+#include "api/bash_tags.h"
+#include "api/diagnostics.h"
 #include "api/helpers/logging.h"
+#include "api/helpers/text.h"
 #include "api/sorting/plugin_sort.h"
+#include "api/sorting/redundant_rules.h"
 #include "loot/exception/file_access_error.h"
 
 #ifdef _WIN32
@@ -55,13 +61,111 @@ using std::vector;
 using std::filesystem::u8path;
 
 namespace loot {
+// Skyrim Special Edition 1.6.1130 widened the range of object IDs that light
+// plugins can use.
+static const std::vector<unsigned long> EXTENDED_LIGHT_PLUGIN_RANGE_VERSION = {
+    1, 6, 1130};
+
+// Light plugins were introduced by Skyrim Special Edition 1.5.3 and Fallout 4
+// 1.10.26.
+static const std::vector<unsigned long> TES5SE_LIGHT_PLUGINS_VERSION = {
+    1, 5, 3};
+static const std::vector<unsigned long> FO4_LIGHT_PLUGINS_VERSION = {1, 10, 26};
+
+static std::vector<unsigned long> ParseGameVersion(const std::string& version) {
+  std::vector<std::string> parts;
+  boost::split(parts, version, boost::is_any_of("."));
+
+  std::vector<unsigned long> numbers;
+  for (const auto& part : parts) {
+    if (part.empty() || !std::all_of(part.begin(), part.end(), [](char c) {
+          return c >= '0' && c <= '9';
+        })) {
+      throw std::invalid_argument("\"" + version +
+                                  "\" is not a valid game version");
+    }
+
+    try {
+      numbers.push_back(std::stoul(part));
+    } catch (const std::out_of_range&) {
+      throw std::invalid_argument("\"" + version +
+                                  "\" is not a valid game version");
+    }
+  }
+
+  return numbers;
+}
+
+// Maps directories to the paths of their entries, keyed by normalized
+// filename, so that each directory only needs to be read once.
+typedef std::map<std::filesystem::path,
+                 std::map<std::string, std::filesystem::path>>
+    DirectoryListings;
+
+static std::optional<std::filesystem::path> FindDirectoryEntry(
+    const std::filesystem::path& directory,
+    const std::string& filename,
+    DirectoryListings& listings) {
+  auto exactPath = directory / u8path(filename);
+  if (std::filesystem::exists(exactPath)) {
+    return exactPath;
+  }
+
+  // The filesystem may be case-sensitive, so fall back to comparing the
+  // directory's entries case-insensitively.
+  auto it = listings.find(directory);
+  if (it == listings.end()) {
+    std::map<std::string, std::filesystem::path> entries;
+    std::error_code ec;
+    for (auto entryIt = std::filesystem::directory_iterator(directory, ec);
+         !ec && entryIt != std::filesystem::directory_iterator();
+         entryIt.increment(ec)) {
+      entries.emplace(NormalizeFilename(entryIt->path().filename().u8string()),
+                      entryIt->path());
+    }
+    it = listings.emplace(directory, entries).first;
+  }
+
+  auto entryIt = it->second.find(NormalizeFilename(filename));
+  if (entryIt == it->second.end()) {
+    return std::nullopt;
+  }
+
+  return entryIt->second;
+}
+
+static bool IsLooseFile(const std::filesystem::path& dataPath,
+                        const std::string& assetPath,
+                        DirectoryListings& listings) {
+  std::vector<std::string> components;
+  boost::split(components, assetPath, boost::is_any_of("\\/"));
+
+  auto path = dataPath;
+  for (const auto& component : components) {
+    if (component.empty()) {
+      continue;
+    }
+
+    auto entryPath = FindDirectoryEntry(path, component, listings);
+    if (!entryPath.has_value()) {
+      return false;
+    }
+    path = entryPath.value();
+  }
+
+  return std::filesystem::is_regular_file(path);
+}
+
 Game::Game(const GameType gameType,
            const std::filesystem::path& gamePath,
            const std::filesystem::path& localDataPath) :
     type_(gameType),
     gamePath_(gamePath),
+    localDataPath_(localDataPath),
     cache_(std::make_shared<GameCache>()),
-    loadOrderHandler_(std::make_shared<LoadOrderHandler>()) {
+    loadOrderHandler_(std::make_shared<LoadOrderHandler>()),
+    timings_(std::make_shared<OperationTimings>()),
+    lastSortMovedPluginCount_(0) {
   auto logger = getLogger();
   if (logger) {
     logger->info("Initialising load order data for game of type {} at: {}",
@@ -74,7 +178,7 @@ Game::Game(const GameType gameType,
   conditionEvaluator_ =
       std::make_shared<ConditionEvaluator>(Type(), DataPath());
 
-  database_ = std::make_shared<ApiDatabase>(conditionEvaluator_);
+  database_ = std::make_shared<ApiDatabase>(conditionEvaluator_, timings_);
 }
 
 GameType Game::Type() const { return type_; }
@@ -87,20 +191,87 @@ std::filesystem::path Game::DataPath() const {
   }
 }
 
+std::filesystem::path Game::GamePath() const { return gamePath_; }
+
+std::filesystem::path Game::LocalDataPath() const { return localDataPath_; }
+
 std::shared_ptr<GameCache> Game::GetCache() { return cache_; }
 
 std::shared_ptr<LoadOrderHandler> Game::GetLoadOrderHandler() {
   return loadOrderHandler_;
 }
 
+std::shared_ptr<OperationTimings> Game::GetTimings() { return timings_; }
+
+std::shared_ptr<ApiDatabase> Game::GetApiDatabase() { return database_; }
+
 std::shared_ptr<DatabaseInterface> Game::GetDatabase() { return database_; }
 
+OrphanedUserMetadata Game::GetOrphanedUserMetadata() const {
+  OrphanedUserMetadata orphanedMetadata;
+
+  for (const auto& plugin : database_->GetUserlist().Plugins()) {
+    if (!plugin.IsRegexPlugin() && !IsValidPlugin(plugin.GetName())) {
+      orphanedMetadata.plugins.push_back(plugin.GetName());
+    }
+  }
+
+  std::set<std::string> groupNames;
+  for (const auto& group : database_->GetGroups()) {
+    groupNames.insert(group.GetName());
+  }
+
+  const auto addUndefinedGroup = [&](const std::string& groupName) {
+    if (groupNames.count(groupName) == 0 &&
+        std::find(orphanedMetadata.groups.begin(),
+                  orphanedMetadata.groups.end(),
+                  groupName) == orphanedMetadata.groups.end()) {
+      orphanedMetadata.groups.push_back(groupName);
+    }
+  };
+
+  for (const auto& group : database_->GetUserGroups()) {
+    for (const auto& afterGroup : group.GetAfterGroups()) {
+      addUndefinedGroup(afterGroup);
+    }
+  }
+
+  // Plugins in undefined groups cause sorting to fail.
+  for (const auto& plugin : database_->GetUserlist().Plugins()) {
+    const auto group = plugin.GetGroup();
+    if (group.has_value() && groupNames.count(group.value()) == 0) {
+      orphanedMetadata.pluginsInUndefinedGroups.push_back(plugin.GetName());
+      addUndefinedGroup(group.value());
+    }
+  }
+
+  return orphanedMetadata;
+}
+
 bool Game::IsValidPlugin(const std::string& plugin) const {
   return Plugin::IsValid(Type(), DataPath() / u8path(plugin));
 }
 
+void Game::SetGameVersion(const std::string& version) {
+  if (version.empty()) {
+    gameVersion_ = std::nullopt;
+    return;
+  }
+
+  // Check that the version is valid.
+  ParseGameVersion(version);
+
+  gameVersion_ = version;
+}
+
+std::optional<std::string> Game::GetGameVersion() const {
+  return gameVersion_;
+}
+
 void Game::LoadPlugins(const std::vector<std::string>& plugins,
                        bool loadHeadersOnly) {
+  ScopedTimer timer(timings_, "LoadPlugins");
+
   auto logger = getLogger();
   uintmax_t meanFileSize = 0;
   std::multimap<uintmax_t, string> sizeMap;
@@ -113,11 +284,7 @@ void Game::LoadPlugins(const std::vector<std::string>& plugins,
     uintmax_t fileSize = Plugin::GetFileSize(DataPath() / u8path(plugin));
     meanFileSize += fileSize;
 
-    // Trim .ghost extension if present.
-    if (boost::iends_with(plugin, ".ghost"))
-      sizeMap.emplace(fileSize, plugin.substr(0, plugin.length() - 6));
-    else
-      sizeMap.emplace(fileSize, plugin);
+    sizeMap.emplace(fileSize, RemoveGhostExtension(plugin));
   }
   meanFileSize /= sizeMap.size();  // Rounding error, but not important.
 
@@ -151,6 +318,11 @@ void Game::LoadPlugins(const std::vector<std::string>& plugins,
     ++currentGroup;
   }
 
+  const bool extendedLightPluginRange =
+      type_ == GameType::tes5se && gameVersion_.has_value() &&
+      ParseGameVersion(gameVersion_.value()) >=
+          EXTENDED_LIGHT_PLUGIN_RANGE_VERSION;
+
   // Clear the existing plugin and archive caches.
   cache_->ClearCachedPlugins();
 
@@ -172,7 +344,11 @@ void Game::LoadPlugins(const std::vector<std::string>& plugins,
           const bool loadHeader =
               loadHeadersOnly || loot::equivalent(pluginPath, masterPath);
 
-          cache_->AddPlugin(Plugin(Type(), cache_, pluginPath, loadHeader));
+          cache_->AddPlugin(Plugin(Type(),
+                                   cache_,
+                                   pluginPath,
+                                   loadHeader,
+                                   extendedLightPluginRange));
         } catch (std::exception& e) {
           if (logger) {
             logger->error(
@@ -192,6 +368,11 @@ void Game::LoadPlugins(const std::vector<std::string>& plugins,
   }
 
   conditionEvaluator_->RefreshState(cache_);
+
+  // Deliver any pending throttling summaries to the logging callback.
+  if (logger) {
+    logger->flush();
+  }
 }
 
 std::shared_ptr<const PluginInterface> Game::GetPlugin(
@@ -210,16 +391,177 @@ std::vector<std::shared_ptr<const PluginInterface>> Game::GetLoadedPlugins()
   return interfacePointers;
 }
 
+std::vector<MasterInfo> Game::GetMasterInfo(
+    const std::string& pluginName) const {
+  auto plugin = cache_->GetPlugin(pluginName);
+  if (!plugin) {
+    throw std::invalid_argument("\"" + pluginName + "\" has not been loaded");
+  }
+
+  std::vector<MasterInfo> masters;
+  for (const auto& masterName : plugin->GetMasters()) {
+    MasterInfo master;
+    master.name = masterName;
+    master.isInstalled = IsValidPlugin(masterName);
+    master.isActive = IsPluginActive(masterName);
+
+    auto loadedMaster = cache_->GetPlugin(masterName);
+    if (loadedMaster) {
+      master.isLightPlugin = loadedMaster->IsLightPlugin();
+    }
+
+    masters.push_back(master);
+  }
+
+  return masters;
+}
+
+std::vector<MasterDependencyNode> Game::GetMasterDependencyGraph(
+    const std::string& pluginName) const {
+  if (!cache_->GetPlugin(pluginName)) {
+    throw std::invalid_argument("\"" + pluginName + "\" has not been loaded");
+  }
+
+  // Visit each plugin once, so that masters shared by several plugins don't
+  // get duplicated and cycles can't cause infinite loops.
+  std::vector<MasterDependencyNode> nodes;
+  std::unordered_set<std::string> visited{NormalizeFilename(pluginName)};
+  std::queue<std::string> pluginsToVisit;
+  pluginsToVisit.push(pluginName);
+
+  while (!pluginsToVisit.empty()) {
+    MasterDependencyNode node;
+    node.name = pluginsToVisit.front();
+    pluginsToVisit.pop();
+
+    node.isInstalled = IsValidPlugin(node.name);
+    node.isActive = IsPluginActive(node.name);
+
+    auto plugin = cache_->GetPlugin(node.name);
+    node.isLoaded = plugin != nullptr;
+    if (plugin) {
+      node.masters = plugin->GetMasters();
+
+      for (const auto& master : node.masters) {
+        if (visited.insert(NormalizeFilename(master)).second) {
+          pluginsToVisit.push(master);
+        }
+      }
+    }
+
+    nodes.push_back(node);
+  }
+
+  return nodes;
+}
+
+EffectiveBashTags Game::GetEffectiveBashTags(
+    const std::string& pluginName) const {
+  auto plugin = cache_->GetPlugin(pluginName);
+  if (!plugin) {
+    throw std::invalid_argument("\"" + pluginName + "\" has not been loaded");
+  }
+
+  std::vector<std::vector<Tag>> tagSources{plugin->GetBashTags()};
+
+  auto masterlistMetadata =
+      database_->GetPluginMetadata(pluginName, false, true);
+  if (masterlistMetadata.has_value()) {
+    tagSources.push_back(masterlistMetadata.value().GetTags());
+  }
+
+  auto userMetadata = database_->GetPluginUserMetadata(pluginName, true);
+  if (userMetadata.has_value()) {
+    tagSources.push_back(userMetadata.value().GetTags());
+  }
+
+  tagSources.push_back(ReadBashTagsFile(DataPath(), pluginName));
+
+  return ResolveBashTags(tagSources);
+}
+
+std::vector<std::string> Game::GetAssetsOverriddenByLooseFiles(
+    const std::string& pluginName) const {
+  auto plugin = cache_->GetPlugin(pluginName);
+  if (!plugin) {
+    throw std::invalid_argument("\"" + pluginName + "\" has not been loaded");
+  }
+
+  std::set<std::string> overriddenPaths;
+  DirectoryListings listings;
+  for (const auto& asset : plugin->GetArchiveAssets()) {
+    // Assets can only be matched to loose files if their names are known.
+    if (!asset.folderName.has_value() || !asset.fileName.has_value()) {
+      continue;
+    }
+
+    const auto assetPath = GetAssetPath(asset);
+
+    if (IsLooseFile(DataPath(), assetPath, listings)) {
+      overriddenPaths.insert(assetPath);
+    }
+  }
+
+  return std::vector<std::string>(overriddenPaths.begin(),
+                                  overriddenPaths.end());
+}
+
+std::vector<std::filesystem::path> Game::GetAssociatedArchives(
+    const std::string& pluginName) {
+  if (!IsValidPlugin(pluginName))
+    throw std::invalid_argument("\"" + pluginName + "\" is not a valid plugin");
+
+  // Refresh the cached archive paths in case archives have been added or
+  // removed since plugins were last loaded.
+  CacheArchives();
+
+  return Plugin::FindAssociatedArchives(
+      Type(), cache_, DataPath() / u8path(pluginName));
+}
+
 void Game::IdentifyMainMasterFile(const std::string& masterFile) {
   masterFilename_ = masterFile;
 }
 
 std::vector<std::string> Game::SortPlugins(
     const std::vector<std::string>& plugins) {
+  return SortPlugins(plugins, SortingProgressCallback());
+}
+
+std::vector<std::string> Game::SortPlugins(
+    const std::vector<std::string>& plugins,
+    const SortingProgressCallback& progressCallback) {
+  ReportSortingProgress(progressCallback, "LoadPlugins", 0);
   LoadPlugins(plugins, false);
 
   // Sort plugins into their load order.
-  return loot::SortPlugins(*this, plugins);
+  auto sortedPlugins = loot::SortPlugins(*this, plugins, progressCallback);
+
+  lastSortMovedPluginCount_ = CountMovedPlugins(plugins, sortedPlugins);
+
+  auto logger = getLogger();
+  if (logger) {
+    logger->info("Sorting moved {} plugins.", lastSortMovedPluginCount_);
+
+    // Deliver any pending throttling summaries to the logging callback.
+    logger->flush();
+  }
+
+  return sortedPlugins;
+}
+
+size_t Game::GetLastSortMovedPluginCount() const {
+  return lastSortMovedPluginCount_;
+}
+
+std::vector<LoadOrderViolation> Game::VerifyLoadOrder(
+    const std::vector<std::string>& loadOrder) {
+  return loot::VerifyLoadOrder(*this, loadOrder);
+}
+
+std::vector<RedundantUserRule> Game::FindRedundantUserRules(
+    const std::vector<std::string>& loadOrder) {
+  return loot::FindRedundantUserRules(*this, loadOrder);
 }
 
 void Game::LoadCurrentLoadOrderState() {
@@ -235,10 +577,68 @@ std::vector<std::string> Game::GetLoadOrder() const {
   return loadOrderHandler_->GetLoadOrder();
 }
 
+PluginSlotUsage Game::GetPluginSlotUsage() const {
+  static constexpr size_t MAX_FULL_PLUGINS = 255;
+  static constexpr size_t MAX_LIGHT_PLUGINS = 4096;
+
+  bool supportsLightPlugins =
+      type_ == GameType::tes5se || type_ == GameType::tes5vr ||
+      type_ == GameType::fo4 || type_ == GameType::fo4vr;
+  if (gameVersion_.has_value()) {
+    const auto version = ParseGameVersion(gameVersion_.value());
+    if (type_ == GameType::tes5se) {
+      supportsLightPlugins = version >= TES5SE_LIGHT_PLUGINS_VERSION;
+    } else if (type_ == GameType::fo4) {
+      supportsLightPlugins = version >= FO4_LIGHT_PLUGINS_VERSION;
+    }
+  }
+
+  PluginSlotUsage usage;
+  usage.fullPluginSlots = MAX_FULL_PLUGINS;
+  if (supportsLightPlugins) {
+    // The last full plugin slot is used for all light plugins.
+    usage.fullPluginSlots = MAX_FULL_PLUGINS - 1;
+    usage.lightPluginSlots = MAX_LIGHT_PLUGINS;
+  }
+
+  for (const auto& pluginName : loadOrderHandler_->GetActivePlugins()) {
+    bool isLightPlugin = false;
+    if (supportsLightPlugins) {
+      auto plugin = cache_->GetPlugin(pluginName);
+      isLightPlugin = plugin ? plugin->IsLightPlugin()
+                             : boost::iends_with(pluginName, ".esl");
+    }
+
+    if (isLightPlugin) {
+      usage.lightPlugins += 1;
+    } else {
+      usage.fullPlugins += 1;
+    }
+  }
+
+  return usage;
+}
+
 void Game::SetLoadOrder(const std::vector<std::string>& loadOrder) {
   loadOrderHandler_->SetLoadOrder(loadOrder);
 }
 
+void Game::SaveArchiveCache(const std::filesystem::path& cachePath) const {
+  cache_->GetArchiveCache()->Save(cachePath);
+}
+
+void Game::LoadArchiveCache(const std::filesystem::path& cachePath) {
+  cache_->GetArchiveCache()->Load(cachePath);
+}
+
+std::string Game::GetDiagnosticsReport() const {
+  return GenerateDiagnosticsReport(*this, *database_);
+}
+
+std::vector<OperationTiming> Game::GetOperationTimings() const {
+  return timings_->GetTimings();
+}
+
 void Game::CacheArchives() {
   const auto archiveFileExtension = GetArchiveFileExtension(Type());
 
@@ -28,8 +28,10 @@
 #include <filesystem>
 #include <string>
 
+#include "api/api_database.h"
 #include "api/game/game_cache.h"
 #include "api/game/load_order_handler.h"
+#include "api/helpers/timing.h"
 #include "api/metadata/condition_evaluator.h"
 #include "loot/game_interface.h"
 
@@ -45,17 +47,28 @@ public:
 
   GameType Type() const;
   std::filesystem::path DataPath() const;
+  std::filesystem::path GamePath() const;
+  // Empty if libloadorder looks up the local path itself.
+  std::filesystem::path LocalDataPath() const;
 
   std::shared_ptr<GameCache> GetCache();
   std::shared_ptr<LoadOrderHandler> GetLoadOrderHandler();
+  std::shared_ptr<OperationTimings> GetTimings();
+  std::shared_ptr<ApiDatabase> GetApiDatabase();
 
   // Game Interface Methods //
   ////////////////////////////
 
   std::shared_ptr<DatabaseInterface> GetDatabase();
 
+  OrphanedUserMetadata GetOrphanedUserMetadata() const;
+
   bool IsValidPlugin(const std::string& plugin) const;
 
+  void SetGameVersion(const std::string& version);
+
+  std::optional<std::string> GetGameVersion() const;
+
   void LoadPlugins(const std::vector<std::string>& plugins,
                    bool loadHeadersOnly);
 
@@ -64,30 +77,69 @@ public:
 
   std::vector<std::shared_ptr<const PluginInterface>> GetLoadedPlugins() const;
 
+  std::vector<MasterInfo> GetMasterInfo(const std::string& pluginName) const;
+
+  std::vector<MasterDependencyNode> GetMasterDependencyGraph(
+      const std::string& pluginName) const;
+
+  EffectiveBashTags GetEffectiveBashTags(const std::string& pluginName) const;
+
+  std::vector<std::string> GetAssetsOverriddenByLooseFiles(
+      const std::string& pluginName) const;
+
+  std::vector<std::filesystem::path> GetAssociatedArchives(
+      const std::string& pluginName);
+
   void IdentifyMainMasterFile(const std::string& masterFile);
 
   std::vector<std::string> SortPlugins(const std::vector<std::string>& plugins);
 
+  std::vector<std::string> SortPlugins(
+      const std::vector<std::string>& plugins,
+      const SortingProgressCallback& progressCallback);
+
+  size_t GetLastSortMovedPluginCount() const;
+
+  std::vector<LoadOrderViolation> VerifyLoadOrder(
+      const std::vector<std::string>& loadOrder);
+
+  std::vector<RedundantUserRule> FindRedundantUserRules(
+      const std::vector<std::string>& loadOrder);
+
   void LoadCurrentLoadOrderState();
 
   bool IsPluginActive(const std::string& pluginName) const;
 
   std::vector<std::string> GetLoadOrder() const;
 
+  PluginSlotUsage GetPluginSlotUsage() const;
+
   void SetLoadOrder(const std::vector<std::string>& loadOrder);
 
+  void SaveArchiveCache(const std::filesystem::path& cachePath) const;
+
+  void LoadArchiveCache(const std::filesystem::path& cachePath);
+
+  std::string GetDiagnosticsReport() const;
+
+  std::vector<OperationTiming> GetOperationTimings() const;
+
 private:
   void CacheArchives();
 
   std::shared_ptr<GameCache> cache_;
   std::shared_ptr<LoadOrderHandler> loadOrderHandler_;
   std::shared_ptr<ConditionEvaluator> conditionEvaluator_;
-  std::shared_ptr<DatabaseInterface> database_;
+  std::shared_ptr<OperationTimings> timings_;
+  std::shared_ptr<ApiDatabase> database_;
 
   const GameType type_;
   const std::filesystem::path gamePath_;
+  const std::filesystem::path localDataPath_;
 
   std::string masterFilename_;
+  std::optional<std::string> gameVersion_;
+  size_t lastSortMovedPluginCount_;
 };
 }
 #endif
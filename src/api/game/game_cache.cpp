@@ -34,7 +34,7 @@ using std::pair;
 using std::string;
 
 namespace loot {
-GameCache::GameCache() {}
+GameCache::GameCache() : archiveCache_(std::make_shared<ArchiveCache>()) {}
 
 GameCache::GameCache(const GameCache& cache) {
   lock_guard<mutex> lock(mutex_);
@@ -42,6 +42,7 @@ GameCache::GameCache(const GameCache& cache) {
 
   plugins_ = cache.plugins_;
   archivePaths_ = cache.archivePaths_;
+  archiveCache_ = cache.archiveCache_;
 }
 
 GameCache& GameCache::operator=(const GameCache& cache) {
@@ -51,6 +52,7 @@ GameCache& GameCache::operator=(const GameCache& cache) {
   if (&cache != this) {
     plugins_ = cache.plugins_;
     archivePaths_ = cache.archivePaths_;
+    archiveCache_ = cache.archiveCache_;
   }
 
   return *this;
@@ -102,6 +104,12 @@ void GameCache::CacheArchivePaths(std::set<std::filesystem::path>&& paths) {
   archivePaths_ = paths;
 }
 
+std::shared_ptr<ArchiveCache> GameCache::GetArchiveCache() const {
+  lock_guard<mutex> lock(mutex_);
+
+  return archiveCache_;
+}
+
 void GameCache::ClearCachedPlugins() {
   lock_guard<mutex> guard(mutex_);
 
@@ -29,6 +29,7 @@
 #include <string>
 #include <unordered_map>
 
+#include "api/archive/archive_cache.h"
 #include "api/plugin.h"
 
 namespace loot {
@@ -46,11 +47,16 @@ public:
   std::set<std::filesystem::path> GetArchivePaths() const;
   void CacheArchivePaths(std::set<std::filesystem::path>&& paths);
 
+  std::shared_ptr<ArchiveCache> GetArchiveCache() const;
+
   void ClearCachedPlugins();
 
 private:
   std::unordered_map<std::string, std::shared_ptr<const Plugin>> plugins_;
   std::set<std::filesystem::path> archivePaths_;
+  // The archive cache is not cleared along with plugins, as its entries are
+  // invalidated individually when an archive changes.
+  std::shared_ptr<ArchiveCache> archiveCache_;
 
   mutable std::mutex mutex_;
 };
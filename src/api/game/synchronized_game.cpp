@@ -0,0 +1,366 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#include "api/game/synchronized_game.h"
+
+namespace loot {
+SynchronizedDatabase::SynchronizedDatabase(
+    std::shared_ptr<DatabaseInterface> database,
+    std::shared_ptr<std::mutex> mutex) :
+    database_(database), mutex_(mutex) {}
+
+void SynchronizedDatabase::LoadLists(
+    const std::filesystem::path& masterlist_path,
+    const std::filesystem::path& userlist_path) {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  database_->LoadLists(masterlist_path, userlist_path);
+}
+
+void SynchronizedDatabase::WriteUserMetadata(
+    const std::filesystem::path& outputFile,
+    const WriteMode mode) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  database_->WriteUserMetadata(outputFile, mode);
+}
+
+void SynchronizedDatabase::WriteMinimalList(
+    const std::filesystem::path& outputFile,
+    const WriteMode mode) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  database_->WriteMinimalList(outputFile, mode);
+}
+
+bool SynchronizedDatabase::UpdateMasterlist(
+    const std::filesystem::path& masterlist_path,
+    const std::string& remote_url,
+    const std::string& remote_branch) {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->UpdateMasterlist(
+      masterlist_path, remote_url, remote_branch);
+}
+
+MasterlistInfo SynchronizedDatabase::GetMasterlistRevision(
+    const std::filesystem::path& masterlist_path,
+    const bool get_short_id) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->GetMasterlistRevision(masterlist_path, get_short_id);
+}
+
+bool SynchronizedDatabase::IsLatestMasterlist(
+    const std::filesystem::path& masterlist_path,
+    const std::string& branch) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->IsLatestMasterlist(masterlist_path, branch);
+}
+
+std::vector<std::string> SynchronizedDatabase::GetKnownBashTags() const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->GetKnownBashTags();
+}
+
+std::vector<Message> SynchronizedDatabase::GetGeneralMessages(
+    bool evaluateConditions) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->GetGeneralMessages(evaluateConditions);
+}
+
+std::vector<std::string> SynchronizedDatabase::GetSuppressedMessageIds() const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->GetSuppressedMessageIds();
+}
+
+void SynchronizedDatabase::SetSuppressedMessageIds(
+    const std::vector<std::string>& ids) {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  database_->SetSuppressedMessageIds(ids);
+}
+
+std::vector<Group> SynchronizedDatabase::GetGroups(
+    bool includeUserMetadata) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->GetGroups(includeUserMetadata);
+}
+
+std::vector<Group> SynchronizedDatabase::GetUserGroups() const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->GetUserGroups();
+}
+
+void SynchronizedDatabase::SetUserGroups(const std::vector<Group>& groups) {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  database_->SetUserGroups(groups);
+}
+
+std::vector<Vertex> SynchronizedDatabase::GetGroupsPath(
+    const std::string& fromGroupName,
+    const std::string& toGroupName) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->GetGroupsPath(fromGroupName, toGroupName);
+}
+
+std::optional<PluginMetadata> SynchronizedDatabase::GetPluginMetadata(
+    const std::string& plugin,
+    bool includeUserMetadata,
+    bool evaluateConditions) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->GetPluginMetadata(
+      plugin, includeUserMetadata, evaluateConditions);
+}
+
+std::optional<PluginMetadata> SynchronizedDatabase::GetPluginMetadata(
+    const std::string& plugin,
+    const MetadataMergeModes& mergeModes,
+    bool evaluateConditions) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->GetPluginMetadata(plugin, mergeModes, evaluateConditions);
+}
+
+std::optional<PluginMetadata> SynchronizedDatabase::GetOverriddenPluginMetadata(
+    const std::string& plugin,
+    const MetadataMergeModes& mergeModes) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->GetOverriddenPluginMetadata(plugin, mergeModes);
+}
+
+std::optional<PluginMetadata> SynchronizedDatabase::GetPluginUserMetadata(
+    const std::string& plugin,
+    bool evaluateConditions) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->GetPluginUserMetadata(plugin, evaluateConditions);
+}
+
+EvaluatedMetadata SynchronizedDatabase::GetEvaluatedMetadata(
+    const std::vector<std::string>& plugins,
+    bool includeUserMetadata) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->GetEvaluatedMetadata(plugins, includeUserMetadata);
+}
+
+void SynchronizedDatabase::SetPluginUserMetadata(
+    const PluginMetadata& pluginMetadata) {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  database_->SetPluginUserMetadata(pluginMetadata);
+}
+
+void SynchronizedDatabase::DiscardPluginUserMetadata(
+    const std::string& plugin) {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  database_->DiscardPluginUserMetadata(plugin);
+}
+
+void SynchronizedDatabase::DiscardAllUserMetadata() {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  database_->DiscardAllUserMetadata();
+}
+
+void SynchronizedDatabase::ValidateCondition(
+    const std::string& condition) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  database_->ValidateCondition(condition);
+}
+
+std::vector<bool> SynchronizedDatabase::EvaluateConditions(
+    const std::vector<std::string>& conditions) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->EvaluateConditions(conditions);
+}
+
+std::vector<bool> SynchronizedDatabase::EvaluateConditionsWithActivePlugins(
+    const std::vector<std::string>& conditions,
+    const std::vector<std::string>& activePlugins) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->EvaluateConditionsWithActivePlugins(conditions,
+                                                        activePlugins);
+}
+
+void SynchronizedDatabase::ClearConditionCache() {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  database_->ClearConditionCache();
+}
+
+ConditionCacheStats SynchronizedDatabase::GetConditionCacheStats() const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return database_->GetConditionCacheStats();
+}
+
+SynchronizedGame::SynchronizedGame(std::shared_ptr<GameInterface> game) :
+    game_(game),
+    mutex_(std::make_shared<std::mutex>()),
+    database_(
+        std::make_shared<SynchronizedDatabase>(game->GetDatabase(), mutex_)) {}
+
+std::shared_ptr<DatabaseInterface> SynchronizedGame::GetDatabase() {
+  return database_;
+}
+
+OrphanedUserMetadata SynchronizedGame::GetOrphanedUserMetadata() const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->GetOrphanedUserMetadata();
+}
+
+bool SynchronizedGame::IsValidPlugin(const std::string& plugin) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->IsValidPlugin(plugin);
+}
+
+void SynchronizedGame::SetGameVersion(const std::string& version) {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  game_->SetGameVersion(version);
+}
+
+std::optional<std::string> SynchronizedGame::GetGameVersion() const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->GetGameVersion();
+}
+
+void SynchronizedGame::LoadPlugins(const std::vector<std::string>& plugins,
+                                   bool loadHeadersOnly) {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  game_->LoadPlugins(plugins, loadHeadersOnly);
+}
+
+std::shared_ptr<const PluginInterface> SynchronizedGame::GetPlugin(
+    const std::string& pluginName) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->GetPlugin(pluginName);
+}
+
+std::vector<std::shared_ptr<const PluginInterface>>
+SynchronizedGame::GetLoadedPlugins() const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->GetLoadedPlugins();
+}
+
+std::vector<MasterInfo> SynchronizedGame::GetMasterInfo(
+    const std::string& pluginName) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->GetMasterInfo(pluginName);
+}
+
+std::vector<MasterDependencyNode> SynchronizedGame::GetMasterDependencyGraph(
+    const std::string& pluginName) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->GetMasterDependencyGraph(pluginName);
+}
+
+EffectiveBashTags SynchronizedGame::GetEffectiveBashTags(
+    const std::string& pluginName) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->GetEffectiveBashTags(pluginName);
+}
+
+std::vector<std::string> SynchronizedGame::GetAssetsOverriddenByLooseFiles(
+    const std::string& pluginName) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->GetAssetsOverriddenByLooseFiles(pluginName);
+}
+
+std::vector<std::filesystem::path> SynchronizedGame::GetAssociatedArchives(
+    const std::string& pluginName) {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->GetAssociatedArchives(pluginName);
+}
+
+void SynchronizedGame::SaveArchiveCache(
+    const std::filesystem::path& cachePath) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  game_->SaveArchiveCache(cachePath);
+}
+
+void SynchronizedGame::LoadArchiveCache(
+    const std::filesystem::path& cachePath) {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  game_->LoadArchiveCache(cachePath);
+}
+
+std::string SynchronizedGame::GetDiagnosticsReport() const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->GetDiagnosticsReport();
+}
+
+std::vector<OperationTiming> SynchronizedGame::GetOperationTimings() const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->GetOperationTimings();
+}
+
+void SynchronizedGame::IdentifyMainMasterFile(const std::string& masterFile) {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  game_->IdentifyMainMasterFile(masterFile);
+}
+
+std::vector<std::string> SynchronizedGame::SortPlugins(
+    const std::vector<std::string>& plugins) {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->SortPlugins(plugins);
+}
+
+std::vector<std::string> SynchronizedGame::SortPlugins(
+    const std::vector<std::string>& plugins,
+    const SortingProgressCallback& progressCallback) {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->SortPlugins(plugins, progressCallback);
+}
+
+size_t SynchronizedGame::GetLastSortMovedPluginCount() const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->GetLastSortMovedPluginCount();
+}
+
+std::vector<LoadOrderViolation> SynchronizedGame::VerifyLoadOrder(
+    const std::vector<std::string>& loadOrder) {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->VerifyLoadOrder(loadOrder);
+}
+
+std::vector<RedundantUserRule> SynchronizedGame::FindRedundantUserRules(
+    const std::vector<std::string>& loadOrder) {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->FindRedundantUserRules(loadOrder);
+}
+
+void SynchronizedGame::LoadCurrentLoadOrderState() {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  game_->LoadCurrentLoadOrderState();
+}
+
+bool SynchronizedGame::IsPluginActive(const std::string& plugin) const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->IsPluginActive(plugin);
+}
+
+std::vector<std::string> SynchronizedGame::GetLoadOrder() const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->GetLoadOrder();
+}
+
+PluginSlotUsage SynchronizedGame::GetPluginSlotUsage() const {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  return game_->GetPluginSlotUsage();
+}
+
+void SynchronizedGame::SetLoadOrder(const std::vector<std::string>& loadOrder) {
+  std::lock_guard<std::mutex> guard(*mutex_);
+  game_->SetLoadOrder(loadOrder);
+}
+}
@@ -0,0 +1,204 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_API_GAME_SYNCHRONIZED_GAME
+#define LOOT_API_GAME_SYNCHRONIZED_GAME
+
+#include <memory>
+#include <mutex>
+
+#include "loot/game_interface.h"
+
+namespace loot {
+// Wraps a DatabaseInterface so that each of its functions holds the given
+// mutex for the duration of the call.
+class SynchronizedDatabase : public DatabaseInterface {
+public:
+  SynchronizedDatabase(std::shared_ptr<DatabaseInterface> database,
+                       std::shared_ptr<std::mutex> mutex);
+
+  void LoadLists(const std::filesystem::path& masterlist_path,
+                 const std::filesystem::path& userlist_path = "");
+
+  using DatabaseInterface::WriteMinimalList;
+  using DatabaseInterface::WriteUserMetadata;
+
+  void WriteUserMetadata(const std::filesystem::path& outputFile,
+                         const WriteMode mode) const;
+
+  void WriteMinimalList(const std::filesystem::path& outputFile,
+                        const WriteMode mode) const;
+
+  bool UpdateMasterlist(const std::filesystem::path& masterlist_path,
+                        const std::string& remote_url,
+                        const std::string& remote_branch);
+
+  MasterlistInfo GetMasterlistRevision(
+      const std::filesystem::path& masterlist_path,
+      const bool get_short_id) const;
+
+  bool IsLatestMasterlist(const std::filesystem::path& masterlist_path,
+                          const std::string& branch) const;
+
+  std::vector<std::string> GetKnownBashTags() const;
+
+  std::vector<Message> GetGeneralMessages(
+      bool evaluateConditions = false) const;
+
+  std::vector<std::string> GetSuppressedMessageIds() const;
+
+  void SetSuppressedMessageIds(const std::vector<std::string>& ids);
+
+  std::vector<Group> GetGroups(bool includeUserMetadata = true) const;
+  std::vector<Group> GetUserGroups() const;
+  void SetUserGroups(const std::vector<Group>& groups);
+  std::vector<Vertex> GetGroupsPath(const std::string& fromGroupName,
+                                    const std::string& toGroupName) const;
+
+  std::optional<PluginMetadata> GetPluginMetadata(
+      const std::string& plugin,
+      bool includeUserMetadata = true,
+      bool evaluateConditions = false) const;
+
+  std::optional<PluginMetadata> GetPluginMetadata(
+      const std::string& plugin,
+      const MetadataMergeModes& mergeModes,
+      bool evaluateConditions = false) const;
+
+  std::optional<PluginMetadata> GetOverriddenPluginMetadata(
+      const std::string& plugin,
+      const MetadataMergeModes& mergeModes) const;
+
+  std::optional<PluginMetadata> GetPluginUserMetadata(
+      const std::string& plugin,
+      bool evaluateConditions = false) const;
+
+  EvaluatedMetadata GetEvaluatedMetadata(
+      const std::vector<std::string>& plugins,
+      bool includeUserMetadata = true) const;
+
+  void SetPluginUserMetadata(const PluginMetadata& pluginMetadata);
+
+  void DiscardPluginUserMetadata(const std::string& plugin);
+
+  void DiscardAllUserMetadata();
+
+  void ValidateCondition(const std::string& condition) const;
+
+  std::vector<bool> EvaluateConditions(
+      const std::vector<std::string>& conditions) const;
+
+  std::vector<bool> EvaluateConditionsWithActivePlugins(
+      const std::vector<std::string>& conditions,
+      const std::vector<std::string>& activePlugins) const;
+
+  void ClearConditionCache();
+
+  ConditionCacheStats GetConditionCacheStats() const;
+
+private:
+  std::shared_ptr<DatabaseInterface> database_;
+  std::shared_ptr<std::mutex> mutex_;
+};
+
+// Wraps a GameInterface so that each of its functions, and each function of
+// its database, holds a mutex shared between them for the duration of the
+// call.
+class SynchronizedGame : public GameInterface {
+public:
+  explicit SynchronizedGame(std::shared_ptr<GameInterface> game);
+
+  std::shared_ptr<DatabaseInterface> GetDatabase();
+
+  OrphanedUserMetadata GetOrphanedUserMetadata() const;
+
+  bool IsValidPlugin(const std::string& plugin) const;
+
+  void SetGameVersion(const std::string& version);
+
+  std::optional<std::string> GetGameVersion() const;
+
+  void LoadPlugins(const std::vector<std::string>& plugins,
+                   bool loadHeadersOnly);
+
+  std::shared_ptr<const PluginInterface> GetPlugin(
+      const std::string& pluginName) const;
+
+  std::vector<std::shared_ptr<const PluginInterface>> GetLoadedPlugins() const;
+
+  std::vector<MasterInfo> GetMasterInfo(const std::string& pluginName) const;
+
+  std::vector<MasterDependencyNode> GetMasterDependencyGraph(
+      const std::string& pluginName) const;
+
+  EffectiveBashTags GetEffectiveBashTags(const std::string& pluginName) const;
+
+  std::vector<std::string> GetAssetsOverriddenByLooseFiles(
+      const std::string& pluginName) const;
+
+  std::vector<std::filesystem::path> GetAssociatedArchives(
+      const std::string& pluginName);
+
+  void SaveArchiveCache(const std::filesystem::path& cachePath) const;
+
+  void LoadArchiveCache(const std::filesystem::path& cachePath);
+
+  std::string GetDiagnosticsReport() const;
+
+  std::vector<OperationTiming> GetOperationTimings() const;
+
+  void IdentifyMainMasterFile(const std::string& masterFile);
+
+  std::vector<std::string> SortPlugins(const std::vector<std::string>& plugins);
+
+  std::vector<std::string> SortPlugins(
+      const std::vector<std::string>& plugins,
+      const SortingProgressCallback& progressCallback);
+
+  size_t GetLastSortMovedPluginCount() const;
+
+  std::vector<LoadOrderViolation> VerifyLoadOrder(
+      const std::vector<std::string>& loadOrder);
+
+  std::vector<RedundantUserRule> FindRedundantUserRules(
+      const std::vector<std::string>& loadOrder);
+
+  void LoadCurrentLoadOrderState();
+
+  bool IsPluginActive(const std::string& plugin) const;
+
+  std::vector<std::string> GetLoadOrder() const;
+
+  PluginSlotUsage GetPluginSlotUsage() const;
+
+  void SetLoadOrder(const std::vector<std::string>& loadOrder);
+
+private:
+  std::shared_ptr<GameInterface> game_;
+  std::shared_ptr<std::mutex> mutex_;
+  std::shared_ptr<SynchronizedDatabase> database_;
+};
+}
+
+#endif
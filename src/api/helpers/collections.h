@@ -28,6 +28,8 @@
 #include <algorithm>
 #include <vector>
 
+#include "loot/enum/merge_mode.h"
+
 namespace loot {
 // Append second to first, skipping any elements that are already present in
 // first. Although this is O(U * M), both input vectors are expected to be
@@ -47,6 +49,19 @@ std::vector<T> mergeVectors(std::vector<T> first,
   return first;
 }
 
+// Merge second into first as above if the merge mode is append or first is
+// empty, otherwise return first unchanged.
+template<typename T>
+std::vector<T> mergeVectors(std::vector<T> first,
+                            const std::vector<T>& second,
+                            MergeMode mergeMode) {
+  if (mergeMode == MergeMode::replace && !first.empty()) {
+    return first;
+  }
+
+  return mergeVectors(first, second);
+}
+
 // Returns the elements in first that are not in second. Although this is
 // O(F * S), both input vectors are expected to be small (with tens of elements
 // being an unusually large number).
@@ -24,6 +24,7 @@
 
 #include "api/helpers/crc.h"
 
+#include <exception>
 #include <fstream>
 
 #include <boost/crc.hpp>
@@ -79,8 +80,10 @@ uint32_t GetCrc32(const std::filesystem::path& filename) {
     return checksum;
 
   } catch (std::exception& e) {
-    throw FileAccessError("Unable to open \"" + filename.u8string() +
-                          "\" for CRC calulation: " + e.what());
+    std::throw_with_nested(
+        FileAccessError("Unable to open \"" + filename.u8string() +
+                            "\" for CRC calulation: " + e.what(),
+                        filename));
   }
 }
 }
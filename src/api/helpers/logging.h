@@ -30,10 +30,16 @@
 #define NOMINMAX
 #endif
 
+#include <chrono>
+#include <deque>
+#include <optional>
+#include <vector>
+
 #include <spdlog/spdlog.h>
 #include <spdlog/sinks/base_sink.h>
 
 #include "loot/enum/log_level.h"
+#include "loot/struct/log_record.h"
 
 namespace loot {
 static const char* LOGGER_NAME = "loot_api_logger";
@@ -42,44 +48,194 @@ inline std::shared_ptr<spdlog::logger> getLogger() {
   return spdlog::get(LOGGER_NAME);
 }
 
+inline LogLevel mapFromSpdlog(spdlog::level::level_enum severity) {
+  using spdlog::level::level_enum;
+  switch (severity) {
+    case level_enum::trace:
+      return LogLevel::trace;
+    case level_enum::debug:
+      return LogLevel::debug;
+    case level_enum::info:
+      return LogLevel::info;
+    case level_enum::warn:
+      return LogLevel::warning;
+    case level_enum::err:
+      return LogLevel::error;
+    case level_enum::critical:
+      return LogLevel::fatal;
+    default:
+      return LogLevel::trace;
+  }
+}
+
+inline spdlog::level::level_enum mapToSpdlog(LogLevel level) {
+  using spdlog::level::level_enum;
+  switch (level) {
+    case LogLevel::trace:
+      return level_enum::trace;
+    case LogLevel::debug:
+      return level_enum::debug;
+    case LogLevel::info:
+      return level_enum::info;
+    case LogLevel::warning:
+      return level_enum::warn;
+    case LogLevel::error:
+      return level_enum::err;
+    case LogLevel::fatal:
+      return level_enum::critical;
+    default:
+      return level_enum::trace;
+  }
+}
+
+inline LogRecord toLogRecord(const spdlog::details::log_msg& msg) {
+  // string_view isn't necessarily null-terminated, so copy using the
+  // data and size together.
+  LogRecord record;
+  record.level = mapFromSpdlog(msg.level);
+  record.target = std::string(msg.logger_name.data(), msg.logger_name.size());
+  record.timestamp = msg.time;
+  record.message = std::string(msg.payload.data(), msg.payload.size());
+
+  return record;
+}
+
 class SpdLoggingSink : public spdlog::sinks::base_sink<std::mutex> {
 public:
-  explicit SpdLoggingSink(std::function<void(LogLevel, const char*)> callback) {
+  explicit SpdLoggingSink(std::function<void(const LogRecord&)> callback) :
+      collapseRepeats_(false),
+      maxRecordsPerSecond_(0),
+      repeatCount_(0),
+      windowCount_(0),
+      suppressedCount_(0) {
     this->callback = callback;
   }
 
+  void SetThrottling(bool collapseRepeats, size_t maxRecordsPerSecond) {
+    std::lock_guard<std::mutex> guard(mutex_);
+    flushSummaries();
+
+    collapseRepeats_ = collapseRepeats;
+    maxRecordsPerSecond_ = maxRecordsPerSecond;
+  }
+
 protected:
   void sink_it_(const spdlog::details::log_msg& msg) override {
-    // string_view isn't necessarily null-terminated, so using
-    // msg.payload.data() directly isn't a good idea.
-    std::string payload = std::string(msg.payload.data(), msg.payload.size());
-    callback(mapFromSpdlog(msg.level), payload.c_str());
+    auto record = toLogRecord(msg);
+
+    if (collapseRepeats_ && lastRecord_.has_value() &&
+        lastRecord_.value().level == record.level &&
+        lastRecord_.value().message == record.message) {
+      repeatCount_ += 1;
+      return;
+    }
+
+    flushRepeatSummary();
+
+    if (maxRecordsPerSecond_ > 0) {
+      if (record.timestamp - windowStart_ >= std::chrono::seconds(1)) {
+        flushSuppressionSummary();
+        windowStart_ = record.timestamp;
+        windowCount_ = 0;
+      }
+
+      if (windowCount_ >= maxRecordsPerSecond_) {
+        suppressedCount_ += 1;
+        return;
+      }
+
+      windowCount_ += 1;
+    }
+
+    lastRecord_ = record;
+    callback(record);
   }
 
-  void flush_() override {}
+  void flush_() override { flushSummaries(); }
 
 private:
-  std::function<void(LogLevel, const char*)> callback;
-
-  static LogLevel mapFromSpdlog(spdlog::level::level_enum severity) {
-    using spdlog::level::level_enum;
-    switch (severity) {
-      case level_enum::trace:
-        return LogLevel::trace;
-      case level_enum::debug:
-        return LogLevel::debug;
-      case level_enum::info:
-        return LogLevel::info;
-      case level_enum::warn:
-        return LogLevel::warning;
-      case level_enum::err:
-        return LogLevel::error;
-      case level_enum::critical:
-        return LogLevel::fatal;
-      default:
-        return LogLevel::trace;
+  std::function<void(const LogRecord&)> callback;
+
+  bool collapseRepeats_;
+  size_t maxRecordsPerSecond_;
+
+  std::optional<LogRecord> lastRecord_;
+  size_t repeatCount_;
+
+  std::chrono::system_clock::time_point windowStart_;
+  size_t windowCount_;
+  size_t suppressedCount_;
+
+  void flushSummaries() {
+    flushRepeatSummary();
+    flushSuppressionSummary();
+  }
+
+  void flushRepeatSummary() {
+    if (repeatCount_ == 0) {
+      return;
+    }
+
+    LogRecord summary = lastRecord_.value();
+    summary.timestamp = std::chrono::system_clock::now();
+    summary.message = "The previous message was repeated " +
+                      std::to_string(repeatCount_) + " more times.";
+    repeatCount_ = 0;
+
+    callback(summary);
+  }
+
+  void flushSuppressionSummary() {
+    if (suppressedCount_ == 0) {
+      return;
+    }
+
+    LogRecord summary;
+    summary.level = LogLevel::warning;
+    summary.target = LOGGER_NAME;
+    summary.timestamp = std::chrono::system_clock::now();
+    summary.message = std::to_string(suppressedCount_) +
+                      " messages were suppressed by the log rate limit.";
+    suppressedCount_ = 0;
+
+    callback(summary);
+  }
+};
+
+class LogRecordBufferSink : public spdlog::sinks::base_sink<std::mutex> {
+public:
+  explicit LogRecordBufferSink(size_t capacity) : capacity_(capacity) {}
+
+  void SetCapacity(size_t capacity) {
+    std::lock_guard<std::mutex> guard(mutex_);
+    capacity_ = capacity;
+    while (records_.size() > capacity_) {
+      records_.pop_front();
     }
   }
+
+  std::vector<LogRecord> GetRecords() {
+    std::lock_guard<std::mutex> guard(mutex_);
+    return std::vector<LogRecord>(records_.begin(), records_.end());
+  }
+
+protected:
+  void sink_it_(const spdlog::details::log_msg& msg) override {
+    if (capacity_ == 0) {
+      return;
+    }
+
+    if (records_.size() == capacity_) {
+      records_.pop_front();
+    }
+    records_.push_back(toLogRecord(msg));
+  }
+
+  void flush_() override {}
+
+private:
+  size_t capacity_;
+  std::deque<LogRecord> records_;
 };
 }
 
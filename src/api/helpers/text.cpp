@@ -180,4 +180,14 @@ std::string NormalizeFilename(const std::string& filename) {
   return normalizedFilename;
 #endif
 }
+
+std::string RemoveGhostExtension(const std::string& filename) {
+  static constexpr size_t GHOST_EXTENSION_LENGTH = 6;
+
+  if (boost::iends_with(filename, ".ghost")) {
+    return filename.substr(0, filename.length() - GHOST_EXTENSION_LENGTH);
+  }
+
+  return filename;
+}
 }
@@ -51,6 +51,11 @@ int CompareFilenames(const std::string& lhs, const std::string& rhs);
 // that the normalized filenames distinguish characters in a similar way to the
 // Windows filesystem.
 std::string NormalizeFilename(const std::string& filename);
+
+// Remove a ".ghost" file extension from the end of the given filename, if
+// present. The extension is matched case-insensitively, and only one is
+// removed.
+std::string RemoveGhostExtension(const std::string& filename);
 }
 
 #endif
@@ -0,0 +1,74 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#include "api/helpers/timing.h"
+
+#include <algorithm>
+
+#include "api/helpers/logging.h"
+
+namespace loot {
+void OperationTimings::Record(const std::string& operation,
+                              std::chrono::microseconds duration) {
+  auto logger = getLogger();
+  if (logger) {
+    logger->debug("{} took {} ms", operation, duration.count() / 1000.0);
+  }
+
+  std::lock_guard<std::mutex> guard(mutex_);
+
+  auto it = std::find_if(
+      timings_.begin(), timings_.end(), [&](const OperationTiming& timing) {
+        return timing.operation == operation;
+      });
+
+  if (it == timings_.end()) {
+    timings_.push_back(OperationTiming{operation, duration});
+  } else {
+    it->duration = duration;
+  }
+}
+
+std::vector<OperationTiming> OperationTimings::GetTimings() const {
+  std::lock_guard<std::mutex> guard(mutex_);
+  return timings_;
+}
+
+ScopedTimer::ScopedTimer(std::shared_ptr<OperationTimings> timings,
+                         const std::string& operation) :
+    timings_(timings),
+    operation_(operation),
+    start_(std::chrono::steady_clock::now()) {}
+
+ScopedTimer::~ScopedTimer() {
+  if (!timings_) {
+    return;
+  }
+
+  const auto duration = std::chrono::duration_cast<std::chrono::microseconds>(
+      std::chrono::steady_clock::now() - start_);
+
+  timings_->Record(operation_, duration);
+}
+}
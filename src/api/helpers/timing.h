@@ -0,0 +1,63 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_API_HELPERS_TIMING
+#define LOOT_API_HELPERS_TIMING
+
+#include <chrono>
+#include <memory>
+#include <mutex>
+#include <string>
+#include <vector>
+
+#include "loot/struct/operation_timing.h"
+
+namespace loot {
+class OperationTimings {
+public:
+  void Record(const std::string& operation,
+              std::chrono::microseconds duration);
+
+  std::vector<OperationTiming> GetTimings() const;
+
+private:
+  std::vector<OperationTiming> timings_;
+  mutable std::mutex mutex_;
+};
+
+// Records the time between its construction and destruction.
+class ScopedTimer {
+public:
+  explicit ScopedTimer(std::shared_ptr<OperationTimings> timings,
+                       const std::string& operation);
+  ~ScopedTimer();
+
+private:
+  std::shared_ptr<OperationTimings> timings_;
+  std::string operation_;
+  std::chrono::steady_clock::time_point start_;
+};
+}
+
+#endif
@@ -47,8 +47,8 @@ MasterlistInfo Masterlist::GetInfo(const std::filesystem::path& path,
     if (logger) {
       logger->info("Unknown masterlist revision: No masterlist present.");
     }
-    throw FileAccessError(string("N/A: No masterlist present at ") +
-                          path.u8string());
+    throw FileAccessError(
+        string("N/A: No masterlist present at ") + path.u8string(), path);
   } else if (!git.IsRepository(path.parent_path())) {
     if (logger) {
       logger->info("Unknown masterlist revision: Git repository missing.");
@@ -87,25 +87,58 @@ std::string IntToHexString(const uint32_t value) {
   return stream.str();
 }
 
-ConditionEvaluator::ConditionEvaluator(
-    const GameType gameType,
-    const std::filesystem::path& dataPath) {
-    lci_state * state = nullptr;
+std::shared_ptr<lci_state> CreateState(const GameType gameType,
+  const std::filesystem::path& dataPath) {
+  lci_state * state = nullptr;
+
+  // This probably isn't correct for API users other than LOOT.
+  // But that probably doesn't matter, as the only things conditional
+  // on LOOT's version are LOOT-specific messages.
+  auto lootPath = std::filesystem::absolute("LOOT.exe");
+  int result = lci_state_create(&state, mapGameType(gameType), dataPath.u8string().c_str(), lootPath.u8string().c_str());
+  HandleError("create state object for condition evaluation", result);
+
+  return std::shared_ptr<lci_state>(state, lci_state_destroy);
+}
 
-    // This probably isn't correct for API users other than LOOT.
-    // But that probably doesn't matter, as the only things conditional
-    // on LOOT's version are LOOT-specific messages.
-    auto lootPath = std::filesystem::absolute("LOOT.exe");
-    int result = lci_state_create(&state, mapGameType(gameType), dataPath.u8string().c_str(), lootPath.u8string().c_str());
-    HandleError("create state object for condition evaluation", result);
+void SetActivePlugins(lci_state * state,
+  const std::vector<std::string>& activePlugins) {
+  std::vector<const char *> activePluginNames;
+  for (auto& pluginName : activePlugins) {
+    activePluginNames.push_back(pluginName.c_str());
+  }
 
-    lciState_ = std::shared_ptr<lci_state>(state, lci_state_destroy);
+  int result = lci_state_set_active_plugins(state,
+    activePluginNames.data(),
+    activePluginNames.size());
+  HandleError("cache active plugins for condition evaluation", result);
 }
 
+ConditionEvaluator::ConditionEvaluator(
+    const GameType gameType,
+    const std::filesystem::path& dataPath) :
+    gameType_(gameType),
+    dataPath_(dataPath),
+    lciState_(CreateState(gameType, dataPath)),
+    cacheHits_(0),
+    cacheMisses_(0) {}
+
 bool ConditionEvaluator::Evaluate(const std::string& condition) {
   if (condition.empty())
     return true;
 
+  {
+    std::lock_guard<std::mutex> lock(cacheMutex_);
+
+    auto it = conditionCache_.find(condition);
+    if (it != conditionCache_.end()) {
+      ++cacheHits_;
+      return it->second;
+    }
+
+    ++cacheMisses_;
+  }
+
   auto logger = getLogger();
   if (logger) {
     logger->trace("Evaluating condition: {}", condition);
@@ -116,9 +149,70 @@ bool ConditionEvaluator::Evaluate(const std::string& condition) {
     HandleError("evaluate condition \"" + condition + "\"", result);
   }
 
+  std::lock_guard<std::mutex> lock(cacheMutex_);
+  conditionCache_.emplace(condition, result == LCI_RESULT_TRUE);
+
   return result == LCI_RESULT_TRUE;
 }
 
+std::vector<bool> ConditionEvaluator::Evaluate(
+    const std::vector<std::string>& conditions) {
+  auto logger = getLogger();
+  if (logger) {
+    logger->trace("Evaluating {} conditions", conditions.size());
+  }
+
+  std::vector<bool> results;
+  results.reserve(conditions.size());
+  for (const auto& condition : conditions) {
+    results.push_back(Evaluate(condition));
+  }
+
+  return results;
+}
+
+std::vector<bool> ConditionEvaluator::Evaluate(
+    const std::vector<std::string>& conditions,
+    const std::vector<std::string>& activePlugins) {
+  auto logger = getLogger();
+  if (logger) {
+    logger->trace("Evaluating {} conditions with {} hypothetically active "
+                  "plugins",
+                  conditions.size(),
+                  activePlugins.size());
+  }
+
+  for (const auto& condition : conditions) {
+    if (!condition.empty()) {
+      ParseCondition(condition);
+    }
+  }
+
+  // Use a separate state so that the hypothetical active plugins don't
+  // affect the results cached for the current load order state.
+  auto state = CreateState(gameType_, dataPath_);
+  SetActivePlugins(state.get(), activePlugins);
+  SetPluginVersionsAndCrcs(state.get());
+
+  std::vector<bool> results;
+  results.reserve(conditions.size());
+  for (const auto& condition : conditions) {
+    if (condition.empty()) {
+      results.push_back(true);
+      continue;
+    }
+
+    int result = lci_condition_eval(condition.c_str(), state.get());
+    if (result != LCI_RESULT_FALSE && result != LCI_RESULT_TRUE) {
+      HandleError("evaluate condition \"" + condition + "\"", result);
+    }
+
+    results.push_back(result == LCI_RESULT_TRUE);
+  }
+
+  return results;
+}
+
 PluginMetadata ConditionEvaluator::EvaluateAll(const PluginMetadata& pluginMetadata) {
   PluginMetadata evaluatedMetadata(pluginMetadata.GetName());
   evaluatedMetadata.SetLocations(pluginMetadata.GetLocations());
@@ -184,60 +278,71 @@ PluginMetadata ConditionEvaluator::EvaluateAll(const PluginMetadata& pluginMetad
 void ConditionEvaluator::ClearConditionCache() {
   int result = lci_state_clear_condition_cache(lciState_.get());
   HandleError("clear the condition cache", result);
+
+  std::lock_guard<std::mutex> lock(cacheMutex_);
+  conditionCache_.clear();
+  cacheHits_ = 0;
+  cacheMisses_ = 0;
+}
+
+ConditionCacheStats ConditionEvaluator::GetConditionCacheStats() const {
+  std::lock_guard<std::mutex> lock(cacheMutex_);
+
+  ConditionCacheStats stats;
+  stats.entries = conditionCache_.size();
+  stats.hits = cacheHits_;
+  stats.misses = cacheMisses_;
+
+  return stats;
 }
 
 void ConditionEvaluator::RefreshState(std::shared_ptr<LoadOrderHandler> loadOrderHandler) {
   ClearConditionCache();
 
-  std::vector<std::string> activePluginNameStrings = loadOrderHandler->GetActivePlugins();
-  std::vector<const char *> activePluginNames;
-  for (auto& pluginName : activePluginNameStrings) {
-    activePluginNames.push_back(pluginName.c_str());
-  }
-
-  int result = lci_state_set_active_plugins(lciState_.get(),
-    &activePluginNames[0],
-    activePluginNames.size());
-  HandleError("cache active plugins for condition evaluation", result);
+  SetActivePlugins(lciState_.get(), loadOrderHandler->GetActivePlugins());
 }
 
 void ConditionEvaluator::RefreshState(std::shared_ptr<GameCache> gameCache) {
   ClearConditionCache();
 
-  std::vector<std::string> pluginNames;
-  std::vector<std::string> pluginVersionStrings;
-  std::vector<uint32_t> crcs;
+  pluginNames_.clear();
+  pluginVersionStrings_.clear();
+  pluginCrcs_.clear();
   for (auto plugin : gameCache->GetPlugins()) {
-    pluginNames.push_back(plugin->GetName());
-    pluginVersionStrings.push_back(plugin->GetVersion().value_or(""));
-    crcs.push_back(plugin->GetCRC().value_or(0));
+    pluginNames_.push_back(plugin->GetName());
+    pluginVersionStrings_.push_back(plugin->GetVersion().value_or(""));
+    pluginCrcs_.push_back(plugin->GetCRC().value_or(0));
   }
 
+  SetPluginVersionsAndCrcs(lciState_.get());
+}
+
+void ConditionEvaluator::SetPluginVersionsAndCrcs(lci_state* state) const {
   std::vector<plugin_version> pluginVersions;
   std::vector<plugin_crc> pluginCrcs;
-  for (size_t i = 0; i < pluginNames.size(); ++i) {
-    if (!pluginVersionStrings[i].empty()) {
+  for (size_t i = 0; i < pluginNames_.size(); ++i) {
+    if (!pluginVersionStrings_[i].empty()) {
       plugin_version pluginVersion;
-      pluginVersion.plugin_name = pluginNames[i].c_str();
-      pluginVersion.version = pluginVersionStrings[i].c_str();
+      pluginVersion.plugin_name = pluginNames_[i].c_str();
+      pluginVersion.version = pluginVersionStrings_[i].c_str();
       pluginVersions.push_back(pluginVersion);
     }
 
-    if (crcs[i] != 0) {
+    if (pluginCrcs_[i] != 0) {
       plugin_crc pluginCrc;
-      pluginCrc.plugin_name = pluginNames[i].c_str();
-      pluginCrc.crc = crcs[i];
+      pluginCrc.plugin_name = pluginNames_[i].c_str();
+      pluginCrc.crc = pluginCrcs_[i];
       pluginCrcs.push_back(pluginCrc);
     }
   }
 
-  int result = lci_state_set_plugin_versions(lciState_.get(),
-    &pluginVersions[0],
+  int result = lci_state_set_plugin_versions(state,
+    pluginVersions.data(),
     pluginVersions.size());
   HandleError("cache plugin versions for condition evaluation", result);
 
-  result = lci_state_set_crc_cache(lciState_.get(),
-    &pluginCrcs[0],
+  result = lci_state_set_crc_cache(state,
+    pluginCrcs.data(),
     pluginCrcs.size());
   HandleError("fill CRC cache for condition evaluation", result);
 }
@@ -26,7 +26,9 @@
 #define LOOT_API_METADATA_CONDITION_EVALUATOR
 
 #include <filesystem>
+#include <mutex>
 #include <string>
+#include <unordered_map>
 
 #include <loot_condition_interpreter.h>
 
@@ -34,6 +36,7 @@
 #include "api/game/load_order_handler.h"
 #include "loot/metadata/plugin_cleaning_data.h"
 #include "loot/metadata/plugin_metadata.h"
+#include "loot/struct/condition_cache_stats.h"
 
 namespace loot {
 class ConditionEvaluator {
@@ -42,16 +45,36 @@ public:
     const std::filesystem::path& dataPath);
 
   bool Evaluate(const std::string& condition);
+  std::vector<bool> Evaluate(const std::vector<std::string>& conditions);
+  std::vector<bool> Evaluate(const std::vector<std::string>& conditions,
+                             const std::vector<std::string>& activePlugins);
   PluginMetadata EvaluateAll(const PluginMetadata& pluginMetadata);
 
   void ClearConditionCache();
+  ConditionCacheStats GetConditionCacheStats() const;
   void RefreshState(std::shared_ptr<LoadOrderHandler> loadOrderHandler);
   void RefreshState(std::shared_ptr<GameCache> gameCache);
 private:
   bool Evaluate(const PluginCleaningData& cleaningData,
     const std::string& pluginName);
+  void SetPluginVersionsAndCrcs(lci_state* state) const;
 
+  const GameType gameType_;
+  const std::filesystem::path dataPath_;
   std::shared_ptr<lci_state> lciState_;
+
+  // Kept so that they can also be set in hypothetical states.
+  std::vector<std::string> pluginNames_;
+  std::vector<std::string> pluginVersionStrings_;
+  std::vector<uint32_t> pluginCrcs_;
+
+  // Caches whole condition results in front of loot-condition-interpreter's
+  // own cache of function results, which provides no statistics. Both are
+  // only ever cleared together, in ClearConditionCache().
+  std::unordered_map<std::string, bool> conditionCache_;
+  size_t cacheHits_;
+  size_t cacheMisses_;
+  mutable std::mutex cacheMutex_;
 };
 
 void ParseCondition(const std::string& condition);
@@ -49,4 +49,14 @@ bool operator>(const Filename& lhs, const Filename& rhs) { return rhs < lhs; }
 bool operator<=(const Filename& lhs, const Filename& rhs) { return !(lhs > rhs); }
 
 bool operator>=(const Filename& lhs, const Filename& rhs) { return !(lhs < rhs); }
+
+std::ostream& operator<<(std::ostream& out, const Filename& filename) {
+  return out << std::string(filename);
+}
+}
+
+namespace std {
+size_t hash<loot::Filename>::operator()(const loot::Filename& filename) const {
+  return hash<string>()(loot::NormalizeFilename(std::string(filename)));
+}
 }
@@ -33,17 +33,21 @@ Message::Message() : type_(MessageType::say) {}
 
 Message::Message(const MessageType type,
                  const std::string& content,
-                 const std::string& condition) :
+                 const std::string& condition,
+                 const std::string& id) :
     type_(type),
+    id_(id),
     ConditionalMetadata(condition) {
   content_.push_back(MessageContent(content));
 }
 
 Message::Message(const MessageType type,
                  const std::vector<MessageContent>& content,
-                 const std::string& condition) :
+                 const std::string& condition,
+                 const std::string& id) :
     type_(type),
     content_(content),
+    id_(id),
     ConditionalMetadata(condition) {
   if (content.size() > 1) {
     bool englishStringExists = false;
@@ -75,17 +79,28 @@ bool Message::operator<(const Message& rhs) const {
     return false;
   }
 
-  return content_ < rhs.GetContent();
+  if (content_ < rhs.GetContent()) {
+    return true;
+  }
+
+  if (rhs.GetContent() < content_) {
+    return false;
+  }
+
+  return id_ < rhs.id_;
 }
 
 bool Message::operator==(const Message& rhs) const {
   return type_ == rhs.type_ && GetCondition() == rhs.GetCondition() &&
-         content_ == rhs.GetContent();
+         content_ == rhs.GetContent() && id_ == rhs.id_;
 }
 
 MessageType Message::GetType() const { return type_; }
 
 std::vector<MessageContent> Message::GetContent() const { return content_; }
+
+std::string Message::GetId() const { return id_; }
+
 MessageContent Message::GetContent(const std::string& language) const {
   return MessageContent::Choose(content_, language);
 }
@@ -97,6 +112,7 @@ SimpleMessage Message::ToSimpleMessage(const std::string& language) const {
   simpleMessage.language = content.GetLanguage();
   simpleMessage.text = content.GetText();
   simpleMessage.condition = GetCondition();
+  simpleMessage.id = id_;
 
   return simpleMessage;
 }
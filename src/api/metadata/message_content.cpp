@@ -24,6 +24,7 @@
 
 #include "loot/metadata/message_content.h"
 
+#include <algorithm>
 #include <optional>
 
 #include <boost/algorithm/string.hpp>
@@ -57,48 +58,91 @@ bool MessageContent::operator==(const MessageContent& rhs) const {
   return text_ == rhs.text_ && language_ == rhs.language_;
 }
 
+static std::string NormalizeLocaleCode(const std::string& code) {
+  auto normalized = boost::to_lower_copy(code);
+  std::replace(normalized.begin(), normalized.end(), '-', '_');
+
+  return normalized;
+}
+
+static std::optional<MessageContent> FindExactLanguageMatch(
+    const std::vector<MessageContent>& content,
+    const std::string& normalizedLanguage) {
+  auto it = std::find_if(
+      content.begin(), content.end(), [&](const MessageContent& mc) {
+        return NormalizeLocaleCode(mc.GetLanguage()) == normalizedLanguage;
+      });
+  if (it != content.end()) {
+    return *it;
+  }
+
+  return std::nullopt;
+}
+
+static std::optional<MessageContent> FindLanguageMatch(
+    const std::vector<MessageContent>& content,
+    const std::string& language) {
+  auto normalizedLanguage = NormalizeLocaleCode(language);
+
+  // Try the given code, then fall back by removing one subtag at a time, so
+  // that e.g. zh_Hant_TW is tried as zh_Hant and then zh.
+  auto fallback = normalizedLanguage;
+  while (true) {
+    auto match = FindExactLanguageMatch(content, fallback);
+    if (match.has_value()) {
+      return match;
+    }
+
+    auto underscorePos = fallback.rfind('_');
+    if (underscorePos == std::string::npos) {
+      break;
+    }
+    fallback.erase(underscorePos);
+  }
+
+  // Otherwise accept content that is more specific than the given code.
+  auto prefix = normalizedLanguage + '_';
+  auto it = std::find_if(
+      content.begin(), content.end(), [&](const MessageContent& mc) {
+        return boost::starts_with(NormalizeLocaleCode(mc.GetLanguage()),
+                                  prefix);
+      });
+  if (it != content.end()) {
+    return *it;
+  }
+
+  return std::nullopt;
+}
+
 MessageContent MessageContent::Choose(const std::vector<MessageContent> content,
                                       const std::string& language) {
+  return Choose(content, std::vector<std::string>({language}));
+}
+
+MessageContent MessageContent::Choose(
+    const std::vector<MessageContent> content,
+    const std::vector<std::string>& languages) {
   if (content.empty())
     return MessageContent();
   else if (content.size() == 1)
     return content[0];
-  else {
-    auto languageCode = language.substr(0, language.find("_"));
-    auto isCountryCodeGiven = languageCode.length() != language.length();
-
-    std::optional<MessageContent> matchedLanguage;
-    MessageContent english;
-    for (const auto& mc : content) {
-      auto contentLanguage = mc.GetLanguage();
-
-      if (contentLanguage == language) {
-        return mc;
-      } else if (!matchedLanguage.has_value()) {
-        if (isCountryCodeGiven && contentLanguage == languageCode) {
-          matchedLanguage = mc;
-        } else if (!isCountryCodeGiven) {
-          auto underscorePos = contentLanguage.find("_");
-          if (underscorePos != std::string::npos) {
-            auto contentLanguageCode = contentLanguage.substr(0, underscorePos);
-            if (contentLanguageCode == language) {
-              matchedLanguage = mc;
-            }
-          }
-        }
-
-        if (contentLanguage == MessageContent::defaultLanguage) {
-          english = mc;
-        }
-      }
-    }
 
-    if (matchedLanguage.has_value()) {
-      return matchedLanguage.value();
+  for (const auto& language : languages) {
+    auto match = FindLanguageMatch(content, language);
+    if (match.has_value()) {
+      return match.value();
     }
+  }
 
-    return english;
+  auto english = std::find_if(
+      content.begin(), content.end(), [](const MessageContent& mc) {
+        return mc.GetLanguage() == MessageContent::defaultLanguage;
+      });
+  if (english != content.end()) {
+    return *english;
   }
+
+  return MessageContent();
 }
 
 bool operator!=(const MessageContent& lhs, const MessageContent& rhs) {
@@ -43,13 +43,19 @@ using std::vector;
 namespace loot {
 PluginMetadata::PluginMetadata() {}
 
-PluginMetadata::PluginMetadata(const std::string& n) : name_(n) {
-  // If the name passed ends in '.ghost', that should be trimmed.
-  if (boost::iends_with(name_, ".ghost"))
-    name_ = name_.substr(0, name_.length() - 6);
+PluginMetadata::PluginMetadata(const std::string& n) :
+    name_(RemoveGhostExtension(n)) {
+
+  if (IsRegexPlugin())
+    nameRegex_ = regex(name_, regex::ECMAScript | regex::icase);
 }
 
 void PluginMetadata::MergeMetadata(const PluginMetadata& plugin) {
+  MergeMetadata(plugin, MetadataMergeModes());
+}
+
+void PluginMetadata::MergeMetadata(const PluginMetadata& plugin,
+                                   const MetadataMergeModes& mergeModes) {
   if (plugin.HasNameOnly())
     return;
 
@@ -57,19 +63,28 @@ void PluginMetadata::MergeMetadata(const PluginMetadata& plugin) {
     group_ = plugin.GetGroup();
   }
 
-  loadAfter_ = mergeVectors(loadAfter_, plugin.loadAfter_);
-  requirements_ = mergeVectors(requirements_, plugin.requirements_);
-  incompatibilities_ = mergeVectors(incompatibilities_, plugin.incompatibilities_);
+  loadAfter_ =
+      mergeVectors(loadAfter_, plugin.loadAfter_, mergeModes.loadAfter);
+  requirements_ = mergeVectors(
+      requirements_, plugin.requirements_, mergeModes.requirements);
+  incompatibilities_ = mergeVectors(incompatibilities_,
+                                    plugin.incompatibilities_,
+                                    mergeModes.incompatibilities);
 
-  tags_ = mergeVectors(tags_, plugin.tags_);
+  tags_ = mergeVectors(tags_, plugin.tags_, mergeModes.tags);
 
   // Messages are in an ordered list, and should be fully merged.
-  messages_.insert(
-      end(messages_), begin(plugin.messages_), end(plugin.messages_));
+  if (mergeModes.messages == MergeMode::append || messages_.empty()) {
+    messages_.insert(
+        end(messages_), begin(plugin.messages_), end(plugin.messages_));
+  }
 
-  dirtyInfo_ = mergeVectors(dirtyInfo_, plugin.dirtyInfo_);
-  cleanInfo_ = mergeVectors(cleanInfo_, plugin.cleanInfo_);
-  locations_ = mergeVectors(locations_, plugin.locations_);
+  dirtyInfo_ =
+      mergeVectors(dirtyInfo_, plugin.dirtyInfo_, mergeModes.dirtyInfo);
+  cleanInfo_ =
+      mergeVectors(cleanInfo_, plugin.cleanInfo_, mergeModes.cleanInfo);
+  locations_ =
+      mergeVectors(locations_, plugin.locations_, mergeModes.locations);
 
   return;
 }
@@ -200,10 +215,8 @@ bool PluginMetadata::IsRegexPlugin() const {
 }
 
 bool PluginMetadata::NameMatches(const std::string& pluginName) const {
-  if (IsRegexPlugin()) {
-    return std::regex_match(
-        pluginName,
-        std::regex(name_, std::regex::ECMAScript | std::regex::icase));
+  if (nameRegex_.has_value()) {
+    return regex_match(pluginName, nameRegex_.value());
   }
 
   return CompareFilenames(name_, pluginName) == 0;
@@ -51,6 +51,9 @@ struct convert<loot::Message> {
     if (rhs.IsConditional())
       node["condition"] = rhs.GetCondition();
 
+    if (!rhs.GetId().empty())
+      node["id"] = rhs.GetId();
+
     return node;
   }
 
@@ -124,7 +127,11 @@ struct convert<loot::Message> {
     if (node["condition"])
       condition = node["condition"].as<std::string>();
 
-    rhs = loot::Message(typeNo, content, condition);
+    std::string id;
+    if (node["id"])
+      id = node["id"].as<std::string>();
+
+    rhs = loot::Message(typeNo, content, condition, id);
 
     // Test condition syntax.
     try {
@@ -159,6 +166,9 @@ inline Emitter& operator<<(Emitter& out, const loot::Message& rhs) {
     out << Key << "condition" << Value << YAML::SingleQuoted
         << rhs.GetCondition();
 
+  if (!rhs.GetId().empty())
+    out << Key << "id" << Value << rhs.GetId();
+
   out << EndMap;
 
   return out;
@@ -46,14 +46,15 @@ void MetadataList::Load(const std::filesystem::path& filepath) {
 
   std::ifstream in(filepath);
   if (!in.good())
-    throw FileAccessError("Cannot open " + filepath.u8string());
+    throw FileAccessError("Cannot open " + filepath.u8string(), filepath);
 
   YAML::Node metadataList = YAML::Load(in);
   in.close();
 
   if (!metadataList.IsMap())
     throw FileAccessError("The root of the metadata file " +
-                          filepath.u8string() + " is not a YAML map.");
+                              filepath.u8string() + " is not a YAML map.",
+                          filepath);
 
   if (metadataList["plugins"]) {
     for (const auto& node : metadataList["plugins"]) {
@@ -62,19 +63,25 @@ void MetadataList::Load(const std::filesystem::path& filepath) {
         regexPlugins_.push_back(plugin);
       else if (!plugins_.emplace(Filename(plugin.GetName()), plugin).second)
         throw FileAccessError("More than one entry exists for plugin \"" +
-                              plugin.GetName() + "\"");
+                                  plugin.GetName() + "\"",
+                              filepath);
     }
   }
   if (metadataList["globals"])
     messages_ = metadataList["globals"].as<std::vector<Message>>();
 
+  if (metadataList["suppressed_messages"])
+    suppressedMessageIds_ =
+        metadataList["suppressed_messages"].as<std::vector<std::string>>();
+
   std::unordered_set<std::string> bashTags;
   if (metadataList["bash_tags"]) {
     for (const auto& node : metadataList["bash_tags"]) {
       auto bashTag = node.as<std::string>();
       if (bashTags.count(bashTag) != 0) {
-        throw FileAccessError("More than one entry exists for Bash Tag \"" +
-                              bashTag + "\"");
+        throw FileAccessError(
+            "More than one entry exists for Bash Tag \"" + bashTag + "\"",
+            filepath);
       }
       bashTags_.push_back(bashTag);
       bashTags.insert(bashTag);
@@ -87,7 +94,8 @@ void MetadataList::Load(const std::filesystem::path& filepath) {
       auto group = node.as<Group>();
       if (groupNames.count(group.GetName()) != 0) {
         throw FileAccessError("More than one entry exists for group \"" +
-                              group.GetName() + "\"");
+                                  group.GetName() + "\"",
+                              filepath);
       }
       groups_.push_back(group);
       groupNames.insert(group.GetName());
@@ -123,6 +131,10 @@ void MetadataList::Save(const std::filesystem::path& filepath) const {
   if (!messages_.empty())
     emitter << YAML::Key << "globals" << YAML::Value << messages_;
 
+  if (!suppressedMessageIds_.empty())
+    emitter << YAML::Key << "suppressed_messages" << YAML::Value
+            << suppressedMessageIds_;
+
   auto plugins = Plugins();
   std::sort(plugins.begin(),
             plugins.end(),
@@ -137,7 +149,7 @@ void MetadataList::Save(const std::filesystem::path& filepath) const {
 
   std::ofstream out(filepath);
   if (out.fail())
-    throw FileAccessError("Couldn't open output file.");
+    throw FileAccessError("Couldn't open output file.", filepath);
 
   out << emitter.c_str();
   out.close();
@@ -149,6 +161,7 @@ void MetadataList::Clear() {
   plugins_.clear();
   regexPlugins_.clear();
   messages_.clear();
+  suppressedMessageIds_.clear();
 
   unevaluatedPlugins_.clear();
   unevaluatedRegexPlugins_.clear();
@@ -178,6 +191,10 @@ std::vector<Group> MetadataList::Groups() const {
   return groups_;
 }
 
+std::vector<std::string> MetadataList::SuppressedMessageIds() const {
+  return suppressedMessageIds_;
+}
+
 void MetadataList::SetGroups(const std::vector<Group>& groups) {
   // In case the default group is missing.
   auto defaultGroupName = Group().GetName();
@@ -195,6 +212,11 @@ void MetadataList::SetGroups(const std::vector<Group>& groups) {
   }
 }
 
+void MetadataList::SetSuppressedMessageIds(
+    const std::vector<std::string>& ids) {
+  suppressedMessageIds_ = ids;
+}
+
 // Merges multiple matching regex entries if any are found.
 std::optional<PluginMetadata> MetadataList::FindPlugin(
     const std::string& pluginName) const {
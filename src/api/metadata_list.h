@@ -36,15 +36,6 @@
 #include "loot/metadata/group.h"
 #include "loot/metadata/plugin_metadata.h"
 
-namespace std {
-template<>
-struct hash<loot::Filename> {
-  size_t operator()(const loot::Filename& filename) const {
-    return hash<string>()(loot::NormalizeFilename(std::string(filename)));
-  }
-};
-}
-
 namespace loot {
 class MetadataList {
 public:
@@ -56,8 +47,10 @@ public:
   std::vector<Message> Messages() const;
   std::vector<std::string> BashTags() const;
   std::vector<Group> Groups() const;
+  std::vector<std::string> SuppressedMessageIds() const;
 
   void SetGroups(const std::vector<Group>& groups);
+  void SetSuppressedMessageIds(const std::vector<std::string>& ids);
 
   // Merges multiple matching regex entries if any are found.
   std::optional<PluginMetadata> FindPlugin(const std::string& pluginName) const;
@@ -78,6 +71,7 @@ protected:
   std::unordered_map<Filename, PluginMetadata> plugins_;
   std::vector<PluginMetadata> regexPlugins_;
   std::vector<Message> messages_;
+  std::vector<std::string> suppressedMessageIds_;
 
   std::unordered_map<Filename, PluginMetadata> unevaluatedPlugins_;
   std::vector<PluginMetadata> unevaluatedRegexPlugins_;
@@ -24,7 +24,9 @@
 
 #include "api/plugin.h"
 
+#include <exception>
 #include <filesystem>
+#include <map>
 
 #include <boost/algorithm/string.hpp>
 
@@ -32,20 +34,29 @@
 #include "api/helpers/crc.h"
 #include "api/helpers/logging.h"
 #include "api/helpers/text.h"
+#include "api/plugin_integrity.h"
 #include "loot/exception/file_access_error.h"
 
 using std::set;
 using std::string;
 
 namespace loot {
+static constexpr float EXTENDED_LIGHT_PLUGIN_HEADER_VERSION = 1.71f;
+static constexpr uint32_t MAX_LIGHT_PLUGIN_OBJECT_ID = 0xFFF;
+
 Plugin::Plugin(const GameType gameType,
                std::shared_ptr<GameCache> gameCache,
                std::filesystem::path pluginPath,
-               const bool headerOnly) :
+               const bool headerOnly,
+               const bool extendedLightPluginRange) :
     name_(pluginPath.filename().u8string()),
     esPlugin(nullptr),
     isEmpty_(true),
     loadsArchive_(false),
+    isFullyLoaded_(!headerOnly),
+    extendedLightPluginRange_(extendedLightPluginRange),
+    gameType_(gameType),
+    scanState_(std::make_shared<ScanState>()),
     numOverrideRecords_(0) {
   auto logger = getLogger();
 
@@ -55,6 +66,8 @@ Plugin::Plugin(const GameType gameType,
       pluginPath += ".ghost";
     }
 
+    path_ = pluginPath;
+
     Load(pluginPath, gameType, headerOnly);
 
     auto ret = esp_plugin_is_empty(esPlugin.get(), &isEmpty_);
@@ -77,14 +90,21 @@ Plugin::Plugin(const GameType gameType,
     }
 
     tags_ = ExtractBashTags(GetDescription());
-    loadsArchive_ = LoadsArchive(gameType, gameCache, pluginPath);
+
+    const auto archivePaths =
+        FindAssociatedArchives(gameType, gameCache, pluginPath);
+    loadsArchive_ = !archivePaths.empty();
+
+    if (!headerOnly) {
+      archiveAssets_ = ReadArchiveAssets(gameCache, archivePaths);
+    }
   } catch (std::exception& e) {
     if (logger) {
       logger->error(
           "Cannot read plugin file \"{}\". Details: {}", name_, e.what());
     }
-    throw FileAccessError("Cannot read \"" + name_ +
-                          "\". Details: " + e.what());
+    std::throw_with_nested(FileAccessError(
+        "Cannot read \"" + name_ + "\". Details: " + e.what(), pluginPath));
   }
 }
 
@@ -124,6 +144,15 @@ std::vector<Tag> Plugin::GetBashTags() const { return tags_; }
 
 std::optional<uint32_t> Plugin::GetCRC() const { return crc_; }
 
+std::optional<std::vector<PluginIntegrityIssue>> Plugin::GetIntegrityIssues()
+    const {
+  if (!isFullyLoaded_) {
+    return std::nullopt;
+  }
+
+  return GetScanResult().integrityIssues;
+}
+
 bool Plugin::IsMaster() const {
   bool isMaster;
   auto ret = esp_plugin_is_master(esPlugin.get(), &isMaster);
@@ -155,6 +184,15 @@ bool Plugin::IsValidAsLightMaster() const {
 }
 
 bool Plugin::IsValidAsLightPlugin() const {
+  // The new records' object IDs are only known if the whole plugin was
+  // loaded.
+  if (extendedLightPluginRange_ && isFullyLoaded_ &&
+      GetHeaderVersion() >= EXTENDED_LIGHT_PLUGIN_HEADER_VERSION) {
+    const auto& maxNewObjectId = GetScanResult().maxNewObjectId;
+    return !maxNewObjectId.has_value() ||
+           maxNewObjectId.value() <= MAX_LIGHT_PLUGIN_OBJECT_ID;
+  }
+
   bool isValid;
   auto ret = esp_plugin_is_valid_as_light_plugin(esPlugin.get(), &isValid);
   if (ret != ESP_OK) {
@@ -169,6 +207,10 @@ bool Plugin::IsEmpty() const { return isEmpty_; }
 
 bool Plugin::LoadsArchive() const { return loadsArchive_; }
 
+const std::vector<ArchiveAsset>& Plugin::GetArchiveAssets() const {
+  return archiveAssets_;
+}
+
 bool Plugin::DoFormIDsOverlap(const PluginInterface& plugin) const {
   try {
     auto otherPlugin = dynamic_cast<const Plugin&>(plugin);
@@ -194,6 +236,48 @@ bool Plugin::DoFormIDsOverlap(const PluginInterface& plugin) const {
   return false;
 }
 
+std::vector<std::string> Plugin::GetOverlappingAssets(
+    const PluginInterface& plugin) const {
+  try {
+    const auto& otherPlugin = dynamic_cast<const Plugin&>(plugin);
+
+    std::map<std::pair<uint64_t, uint64_t>, const ArchiveAsset*> otherAssets;
+    for (const auto& asset : otherPlugin.archiveAssets_) {
+      otherAssets.emplace(std::make_pair(asset.folderHash, asset.fileHash),
+                          &asset);
+    }
+
+    std::set<std::string> overlappingPaths;
+    for (const auto& asset : archiveAssets_) {
+      auto it =
+          otherAssets.find(std::make_pair(asset.folderHash, asset.fileHash));
+      if (it == otherAssets.end()) {
+        continue;
+      }
+
+      // Prefer whichever record of the asset has its name, as not all
+      // archives store them.
+      if (asset.fileName.has_value() || !it->second->fileName.has_value()) {
+        overlappingPaths.insert(GetAssetPath(asset));
+      } else {
+        overlappingPaths.insert(GetAssetPath(*it->second));
+      }
+    }
+
+    return std::vector<std::string>(overlappingPaths.begin(),
+                                    overlappingPaths.end());
+  } catch (std::bad_cast&) {
+    auto logger = getLogger();
+    if (logger) {
+      logger->error(
+          "Tried to get overlapping assets with a non-Plugin "
+          "implementation of PluginInterface.");
+    }
+  }
+
+  return {};
+}
+
 size_t Plugin::GetOverlapSize(
     const std::vector<std::shared_ptr<const Plugin>> plugins) const {
   if (plugins.empty()) {
@@ -276,8 +360,9 @@ void Plugin::Load(const std::filesystem::path& path,
   int ret = esp_plugin_new(
       &plugin, GetEspluginGameId(gameType), path.u8string().c_str());
   if (ret != ESP_OK) {
-    throw FileAccessError(path.u8string() +
-                          " : esplugin error code: " + std::to_string(ret));
+    throw FileAccessError(
+        path.u8string() + " : esplugin error code: " + std::to_string(ret),
+        path);
   }
 
   esPlugin = std::shared_ptr<std::remove_pointer<::Plugin>::type>(
@@ -285,8 +370,9 @@ void Plugin::Load(const std::filesystem::path& path,
 
   ret = esp_plugin_parse(esPlugin.get(), headerOnly);
   if (ret != ESP_OK) {
-    throw FileAccessError(path.u8string() +
-                          " : esplugin error code: " + std::to_string(ret));
+    throw FileAccessError(
+        path.u8string() + " : esplugin error code: " + std::to_string(ret),
+        path);
   }
 }
 
@@ -307,6 +393,22 @@ std::string Plugin::GetDescription() const {
   return descriptionStr;
 }
 
+const PluginScanResult& Plugin::GetScanResult() const {
+  // If the scan throws, the flag is left unset and the next call tries again.
+  std::call_once(scanState_->flag, [&]() {
+    scanState_->result = ScanPlugin(path_, gameType_, GetMasters().size());
+
+    auto logger = getLogger();
+    if (logger && !scanState_->result.value().integrityIssues.empty()) {
+      logger->warn("Found {} structural problems in \"{}\".",
+                   scanState_->result.value().integrityIssues.size(),
+                   name_);
+    }
+  });
+
+  return scanState_->result.value();
+}
+
 std::string GetArchiveFileExtension(const GameType gameType) {
   if (gameType == GameType::fo4 || gameType == GameType::fo4vr)
     return ".ba2";
@@ -353,11 +455,12 @@ bool equivalent(const std::filesystem::path& path1,
 }
 
 // Get whether the plugin loads an archive (BSA/BA2) or not.
-bool Plugin::LoadsArchive(const GameType gameType,
-                          const std::shared_ptr<GameCache> gameCache,
-                          const std::filesystem::path& pluginPath) {
+std::vector<std::filesystem::path> Plugin::FindAssociatedArchives(
+    const GameType gameType,
+    const std::shared_ptr<GameCache> gameCache,
+    const std::filesystem::path& pluginPath) {
   if (gameType == GameType::tes3) {
-    return false;
+    return {};
   }
 
   const string archiveExtension = GetArchiveFileExtension(gameType);
@@ -367,7 +470,9 @@ bool Plugin::LoadsArchive(const GameType gameType,
     // basename, ignoring file extensions.
     auto archiveFilename = replaceExtension(pluginPath, archiveExtension);
 
-    return std::filesystem::exists(archiveFilename);
+    if (std::filesystem::exists(archiveFilename)) {
+      return {archiveFilename};
+    }
   } else if (gameType == GameType::tes5se || gameType == GameType::tes5vr) {
     // Skyrim SE can load BSAs that have exactly the same
     // basename, ignoring file extensions, and also BSAs with filenames of
@@ -377,8 +482,15 @@ bool Plugin::LoadsArchive(const GameType gameType,
     auto texturesArchiveFilename =
         getTexturesArchivePath(pluginPath, archiveExtension);
 
-    return std::filesystem::exists(archiveFilename) ||
-           std::filesystem::exists(texturesArchiveFilename);
+    std::vector<std::filesystem::path> archivePaths;
+    if (std::filesystem::exists(archiveFilename)) {
+      archivePaths.push_back(archiveFilename);
+    }
+    if (std::filesystem::exists(texturesArchiveFilename)) {
+      archivePaths.push_back(texturesArchiveFilename);
+    }
+
+    return archivePaths;
   } else if (gameType != GameType::tes4 ||
              boost::iends_with(pluginPath.filename().u8string(), ".esp")) {
     // Oblivion .esp files and FO3, FNV, FO4 plugins can load archives which
@@ -388,6 +500,7 @@ bool Plugin::LoadsArchive(const GameType gameType,
     auto basenameLength = pluginPath.stem().native().length();
     auto pluginExtension = pluginPath.extension().native();
 
+    std::vector<std::filesystem::path> archivePaths;
     for (const auto& archivePath : gameCache->GetArchivePaths()) {
       // Need to check if it starts with the given plugin's basename,
       // but case insensitively. This is hard to do accurately, so
@@ -398,12 +511,41 @@ bool Plugin::LoadsArchive(const GameType gameType,
           pluginExtension;
       auto bsaPluginPath = pluginPath.parent_path() / bsaPluginFilename;
       if (loot::equivalent(pluginPath, bsaPluginPath)) {
-        return true;
+        archivePaths.push_back(archivePath);
       }
     }
+
+    return archivePaths;
   }
 
-  return false;
+  return {};
+}
+
+std::vector<ArchiveAsset> Plugin::ReadArchiveAssets(
+    const std::shared_ptr<GameCache> gameCache,
+    const std::vector<std::filesystem::path>& archivePaths) {
+  const auto archiveCache = gameCache->GetArchiveCache();
+
+  std::vector<ArchiveAsset> assets;
+  for (const auto& archivePath : archivePaths) {
+    try {
+      auto archiveAssets = archiveCache->GetAssets(archivePath);
+      assets.insert(assets.end(), archiveAssets.begin(), archiveAssets.end());
+    } catch (FileAccessError& e) {
+      // An unreadable archive shouldn't stop the plugin from being loaded,
+      // but its assets won't be counted when checking for overlap.
+      auto logger = getLogger();
+      if (logger) {
+        logger->error(
+            "Cannot read the assets in \"{}\", so they will not be checked "
+            "for overlap. Details: {}",
+            archivePath.u8string(),
+            e.what());
+      }
+    }
+  }
+
+  return assets;
 }
 
 unsigned int Plugin::GetEspluginGameId(GameType gameType) {
@@ -426,9 +568,7 @@ unsigned int Plugin::GetEspluginGameId(GameType gameType) {
 }
 
 bool hasPluginFileExtension(std::string filename, GameType gameType) {
-  if (boost::iends_with(filename, ".ghost")) {
-    filename = filename.substr(0, filename.length() - 6);
-  }
+  filename = RemoveGhostExtension(filename);
 
   bool isEspOrEsm = boost::iends_with(filename, ".esp") ||
                   boost::iends_with(filename, ".esm");
@@ -26,6 +26,8 @@
 
 #include <cstdint>
 #include <list>
+#include <memory>
+#include <mutex>
 #include <set>
 #include <string>
 #include <type_traits>
@@ -33,7 +35,9 @@
 
 #include <esplugin.hpp>
 
+#include "api/archive/bsa.h"
 #include "api/game/load_order_handler.h"
+#include "api/plugin_integrity.h"
 #include "loot/enum/game_type.h"
 #include "loot/metadata/plugin_metadata.h"
 #include "loot/plugin_interface.h"
@@ -43,10 +47,14 @@ class GameCache;
 
 class Plugin : public PluginInterface {
 public:
+  // If extendedLightPluginRange is true, plugins with a header version of at
+  // least 1.71 can be light plugins if their new records have object IDs up to
+  // 0xFFF, instead of between 0x800 and 0xFFF.
   explicit Plugin(const GameType gameType,
          std::shared_ptr<GameCache> gameCache,
          std::filesystem::path pluginPath,
-         const bool headerOnly);
+         const bool headerOnly,
+         const bool extendedLightPluginRange = false);
 
   std::string GetName() const;
   float GetHeaderVersion() const;
@@ -54,6 +62,8 @@ public:
   std::vector<std::string> GetMasters() const;
   std::vector<Tag> GetBashTags() const;
   std::optional<uint32_t> GetCRC() const;
+  std::optional<std::vector<PluginIntegrityIssue>> GetIntegrityIssues()
+      const;
 
   bool IsMaster() const;
 
@@ -66,7 +76,10 @@ public:
   bool IsValidAsLightPlugin() const;
   bool IsEmpty() const;
   bool LoadsArchive() const;
+  const std::vector<ArchiveAsset>& GetArchiveAssets() const;
   bool DoFormIDsOverlap(const PluginInterface& plugin) const;
+  std::vector<std::string> GetOverlappingAssets(
+      const PluginInterface& plugin) const;
   size_t GetOverlapSize(
       const std::vector<std::shared_ptr<const Plugin>> plugins) const;
 
@@ -79,15 +92,21 @@ public:
                       const std::filesystem::path& pluginPath);
   static uintmax_t GetFileSize(std::filesystem::path pluginPath);
 
+  static std::vector<std::filesystem::path> FindAssociatedArchives(
+      const GameType gameType,
+      const std::shared_ptr<GameCache> gameCache,
+      const std::filesystem::path& pluginPath);
+
 private:
   void Load(const std::filesystem::path& path,
             GameType gameType,
             bool headerOnly);
   std::string GetDescription() const;
+  const PluginScanResult& GetScanResult() const;
 
-  static bool LoadsArchive(const GameType gameType,
-                           const std::shared_ptr<GameCache> gameCache,
-                           const std::filesystem::path& pluginPath);
+  static std::vector<ArchiveAsset> ReadArchiveAssets(
+      const std::shared_ptr<GameCache> gameCache,
+      const std::vector<std::filesystem::path>& archivePaths);
   static unsigned int GetEspluginGameId(GameType gameType);
 
   bool isEmpty_;  // Does the plugin contain any records other than the TES4
@@ -96,7 +115,21 @@ private:
   const std::string name_;
   std::optional<std::string> version_;  // Obtained from description field.
   std::optional<uint32_t> crc_;
+  bool isFullyLoaded_;
+  bool extendedLightPluginRange_;
+  GameType gameType_;
+  std::filesystem::path path_;
+
+  // Scanning the plugin's records is relatively slow and its results aren't
+  // needed to sort plugins, so it's only done when first required. Copies
+  // read the same file, so they share the scan.
+  struct ScanState {
+    std::once_flag flag;
+    std::optional<PluginScanResult> result;
+  };
+  std::shared_ptr<ScanState> scanState_;
   std::vector<Tag> tags_;
+  std::vector<ArchiveAsset> archiveAssets_;
 
   // Useful caches.
   size_t numOverrideRecords_;
@@ -0,0 +1,201 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#include "api/plugin_integrity.h"
+
+#include <fstream>
+
+#include "api/archive/binary_io.h"
+#include "loot/exception/file_access_error.h"
+
+namespace loot {
+static constexpr uint32_t COMPRESSED_RECORD_FLAG = 0x00040000;
+
+class PluginIntegrityChecker {
+public:
+  PluginIntegrityChecker(std::istream& in,
+                         GameType gameType,
+                         size_t masterCount) :
+      in_(in),
+      isMorrowind_(gameType == GameType::tes3),
+      headerSize_(gameType == GameType::tes3   ? 16
+                  : gameType == GameType::tes4 ? 20
+                                               : 24),
+      masterCount_(masterCount) {}
+
+  PluginScanResult Check(uintmax_t fileSize) {
+    CheckEntries(0, fileSize);
+
+    return result_;
+  }
+
+private:
+  std::string ReadType() {
+    std::string type(4, '\0');
+    in_.read(&type[0], type.size());
+    return type;
+  }
+
+  void AddIssue(IntegrityIssueType type,
+                uintmax_t offset,
+                const std::string& recordType,
+                uint32_t formId) {
+    result_.integrityIssues.push_back(
+        PluginIntegrityIssue{type, offset, recordType, formId});
+  }
+
+  // Check the records and groups between the given offsets. Morrowind
+  // plugins don't have groups.
+  void CheckEntries(uintmax_t start, uintmax_t end) {
+    auto offset = start;
+    while (offset < end) {
+      if (end - offset < headerSize_) {
+        AddIssue(IntegrityIssueType::truncatedData, offset, "", 0);
+        return;
+      }
+
+      in_.seekg(offset);
+      const auto type = ReadType();
+      const auto size = ReadLittleEndian<uint32_t>(in_);
+
+      if (!isMorrowind_ && type == "GRUP") {
+        // Group sizes include the group header.
+        if (size < headerSize_ || size > end - offset) {
+          AddIssue(IntegrityIssueType::truncatedData, offset, type, 0);
+          return;
+        }
+
+        CheckEntries(offset + headerSize_, offset + size);
+        offset += size;
+        continue;
+      }
+
+      const auto dataStart = offset + headerSize_;
+      if (size > end - dataStart) {
+        AddIssue(IntegrityIssueType::truncatedData, offset, type, 0);
+        return;
+      }
+
+      uint32_t flags = 0;
+      uint32_t formId = 0;
+      if (isMorrowind_) {
+        ReadLittleEndian<uint32_t>(in_);
+        flags = ReadLittleEndian<uint32_t>(in_);
+      } else {
+        flags = ReadLittleEndian<uint32_t>(in_);
+        formId = ReadLittleEndian<uint32_t>(in_);
+      }
+
+      // The plugin's own mod index is equal to its number of masters.
+      if (!isMorrowind_ && type != "TES4" && (formId >> 24) > masterCount_) {
+        AddIssue(IntegrityIssueType::invalidFormId, offset, type, formId);
+      } else if (!isMorrowind_ && type != "TES4" &&
+                 (formId >> 24) == masterCount_) {
+        const auto objectId = formId & 0xFFFFFF;
+        if (!result_.maxNewObjectId.has_value() ||
+            objectId > result_.maxNewObjectId.value()) {
+          result_.maxNewObjectId = objectId;
+        }
+      }
+
+      if (isMorrowind_ || (flags & COMPRESSED_RECORD_FLAG) == 0) {
+        if (!AreSubrecordSizesValid(dataStart, dataStart + size)) {
+          AddIssue(
+              IntegrityIssueType::subrecordSizeMismatch, offset, type, formId);
+        }
+      }
+
+      offset = dataStart + size;
+    }
+  }
+
+  bool AreSubrecordSizesValid(uintmax_t start, uintmax_t end) {
+    const uintmax_t subrecordHeaderSize = isMorrowind_ ? 8 : 6;
+
+    in_.seekg(start);
+    auto offset = start;
+    uint32_t largeSubrecordSize = 0;
+    while (offset < end) {
+      if (end - offset < subrecordHeaderSize) {
+        return false;
+      }
+
+      const auto type = ReadType();
+      uint32_t size = isMorrowind_ ? ReadLittleEndian<uint32_t>(in_)
+                                   : ReadLittleEndian<uint16_t>(in_);
+      offset += subrecordHeaderSize;
+
+      // An XXXX subrecord holds the size of the subrecord that follows it,
+      // for subrecords that are too large for their own size field.
+      if (!isMorrowind_ && type == "XXXX" && size == 4 && end - offset >= 4) {
+        largeSubrecordSize = ReadLittleEndian<uint32_t>(in_);
+        offset += size;
+        continue;
+      }
+
+      if (largeSubrecordSize != 0) {
+        size = largeSubrecordSize;
+        largeSubrecordSize = 0;
+      }
+
+      if (size > end - offset) {
+        return false;
+      }
+
+      offset += size;
+      in_.seekg(offset);
+    }
+
+    return true;
+  }
+
+  std::istream& in_;
+  const bool isMorrowind_;
+  const uintmax_t headerSize_;
+  const size_t masterCount_;
+  PluginScanResult result_;
+};
+
+PluginScanResult ScanPlugin(
+    const std::filesystem::path& pluginPath,
+    GameType gameType,
+    size_t masterCount) {
+  std::ifstream in(pluginPath, std::ios::binary);
+  if (!in.is_open()) {
+    throw FileAccessError(
+        "Couldn't open \"" + pluginPath.u8string() + "\" to check it.",
+        pluginPath);
+  }
+
+  std::error_code ec;
+  const auto fileSize = std::filesystem::file_size(pluginPath, ec);
+  if (ec) {
+    throw FileAccessError("Couldn't get the size of \"" +
+                              pluginPath.u8string() + "\": " + ec.message(),
+                          pluginPath);
+  }
+
+  return PluginIntegrityChecker(in, gameType, masterCount).Check(fileSize);
+}
+}
@@ -0,0 +1,53 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_API_PLUGIN_INTEGRITY
+#define LOOT_API_PLUGIN_INTEGRITY
+
+#include <cstdint>
+#include <filesystem>
+#include <optional>
+#include <vector>
+
+#include "loot/enum/game_type.h"
+#include "loot/struct/plugin_integrity_issue.h"
+
+namespace loot {
+struct PluginScanResult {
+  std::vector<PluginIntegrityIssue> integrityIssues;
+  // The highest object ID (the lower 24 bits of a FormID) of the records that
+  // the plugin adds, or no value if it adds none.
+  std::optional<uint32_t> maxNewObjectId;
+};
+
+// Walk the records and groups in a plugin file, checking that their sizes are
+// consistent and that their FormIDs have valid mod indices. Compressed
+// records' subrecords are not checked.
+PluginScanResult ScanPlugin(
+    const std::filesystem::path& pluginPath,
+    GameType gameType,
+    size_t masterCount);
+}
+
+#endif
@@ -26,6 +26,7 @@
 
 #include <cstdlib>
 #include <queue>
+#include <set>
 
 #include <boost/algorithm/string.hpp>
 #include <boost/graph/breadth_first_search.hpp>
@@ -168,6 +169,120 @@ std::vector<std::string> PluginGraph::TopologicalSort() const {
   return plugins;
 }
 
+std::vector<LoadOrderViolation> PluginGraph::FindViolations(
+    const std::vector<std::string>& loadOrder) const {
+  std::unordered_map<std::string, size_t> positions;
+  for (size_t i = 0; i < loadOrder.size(); ++i) {
+    positions.emplace(NormalizeFilename(loadOrder[i]), i);
+  }
+
+  auto logger = getLogger();
+  std::vector<LoadOrderViolation> violations;
+  for (const auto& edge : boost::make_iterator_range(boost::edges(graph_))) {
+    const auto& fromPlugin = graph_[boost::source(edge, graph_)];
+    const auto& toPlugin = graph_[boost::target(edge, graph_)];
+
+    const auto fromIt = positions.find(NormalizeFilename(fromPlugin.GetName()));
+    const auto toIt = positions.find(NormalizeFilename(toPlugin.GetName()));
+    if (fromIt == positions.end() || toIt == positions.end() ||
+        fromIt->second < toIt->second) {
+      continue;
+    }
+
+    if (logger) {
+      logger->debug("\"{}\" loads before \"{}\", breaking a {} edge.",
+                    toPlugin.GetName(),
+                    fromPlugin.GetName(),
+                    describeEdgeType(graph_[edge]));
+    }
+
+    violations.push_back(
+        LoadOrderViolation{toPlugin.GetName(), fromPlugin.GetName(),
+                           graph_[edge]});
+  }
+
+  return violations;
+}
+
+std::vector<std::string> PluginGraph::MinimiseMovement(
+    std::vector<std::string> sortedPlugins,
+    const std::vector<std::string>& loadOrder) const {
+  std::unordered_map<std::string, size_t> positions;
+  for (size_t i = 0; i < loadOrder.size(); ++i) {
+    positions.emplace(NormalizeFilename(loadOrder[i]), i);
+  }
+
+  // Look up each plugin's previous position and vertex once, as the sort
+  // below may compare each plugin many times.
+  std::vector<std::optional<size_t>> previousPositions;
+  std::vector<std::optional<vertex_t>> vertices;
+  for (const auto& plugin : sortedPlugins) {
+    const auto normalizedName = NormalizeFilename(plugin);
+    const auto positionIt = positions.find(normalizedName);
+    previousPositions.push_back(
+        positionIt == positions.end()
+            ? std::nullopt
+            : std::optional<size_t>(positionIt->second));
+
+    const auto vertexIt = verticesByName_.find(normalizedName);
+    vertices.push_back(vertexIt == verticesByName_.end()
+                           ? std::nullopt
+                           : std::optional<vertex_t>(vertexIt->second));
+  }
+
+  // The graph may have parallel edges, so record every pair of vertices that
+  // is joined by at least one edge that isn't a tie-break edge.
+  std::set<std::pair<vertex_t, vertex_t>> constrainedPairs;
+  for (const auto& edge : boost::make_iterator_range(boost::edges(graph_))) {
+    if (graph_[edge] != EdgeType::tieBreak) {
+      constrainedPairs.emplace(boost::source(edge, graph_),
+                               boost::target(edge, graph_));
+    }
+  }
+
+  const auto canSwap = [&](size_t earlier, size_t later) {
+    const auto& earlierPosition = previousPositions[earlier];
+    const auto& laterPosition = previousPositions[later];
+    if (!earlierPosition.has_value() || !laterPosition.has_value() ||
+        earlierPosition.value() < laterPosition.value()) {
+      return false;
+    }
+
+    const auto& fromVertex = vertices[earlier];
+    const auto& toVertex = vertices[later];
+    if (!fromVertex.has_value() || !toVertex.has_value()) {
+      return false;
+    }
+
+    // The plugins are adjacent, so the only way that one can be constrained
+    // to load before the other is by a direct edge.
+    return constrainedPairs.count(
+               std::make_pair(fromVertex.value(), toVertex.value())) == 0;
+  };
+
+  // Insertion sort of indices into sortedPlugins, moving each plugin earlier
+  // until it reaches a plugin that it must load after or that it loaded
+  // after in the given load order.
+  std::vector<size_t> order(sortedPlugins.size());
+  for (size_t i = 0; i < order.size(); ++i) {
+    order[i] = i;
+  }
+
+  for (size_t i = 1; i < order.size(); ++i) {
+    for (size_t j = i; j > 0 && canSwap(order[j - 1], order[j]); --j) {
+      std::swap(order[j - 1], order[j]);
+    }
+  }
+
+  std::vector<std::string> minimised;
+  minimised.reserve(order.size());
+  for (const auto index : order) {
+    minimised.push_back(std::move(sortedPlugins[index]));
+  }
+
+  return minimised;
+}
+
 void PluginGraph::AddPluginVertices(Game& game,
                                     const std::vector<std::string>& loadOrder) {
   std::unordered_map<std::string, std::vector<std::string>> groupPlugins;
@@ -228,13 +343,13 @@ void PluginGraph::AddPluginVertices(Game& game,
       groupIt->second.push_back(plugin->GetName());
     }
 
-    boost::add_vertex(pluginSortingData, graph_);
+    auto vertex = boost::add_vertex(pluginSortingData, graph_);
+    verticesByName_.emplace(NormalizeFilename(plugin->GetName()), vertex);
   }
 
   // Map sets of transitive group dependencies to sets of transitive plugin
   // dependencies.
-  auto groups = GetTransitiveAfterGroups(game.GetDatabase()->GetGroups(false),
-                                         game.GetDatabase()->GetUserGroups());
+  auto groups = *game.GetApiDatabase()->GetTransitiveAfterGroups();
   for (auto& group : groups) {
     std::unordered_set<std::string> transitivePlugins;
     for (const auto& afterGroup : group.second) {
@@ -272,14 +387,12 @@ void PluginGraph::AddPluginVertices(Game& game,
 
 std::optional<vertex_t> PluginGraph::GetVertexByName(
     const std::string& name) const {
-  for (const auto& vertex :
-       boost::make_iterator_range(boost::vertices(graph_))) {
-    if (CompareFilenames(graph_[vertex].GetName(), name) == 0) {
-      return vertex;
-    }
+  const auto it = verticesByName_.find(NormalizeFilename(name));
+  if (it == verticesByName_.end()) {
+    return std::nullopt;
   }
 
-  return std::nullopt;
+  return it->second;
 }
 
 void PluginGraph::CheckForCycles() const {
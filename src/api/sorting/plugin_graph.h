@@ -92,6 +92,18 @@ public:
 
   std::vector<std::string> TopologicalSort() const;
 
+  // Get the edges that go from a later to an earlier plugin in the given load
+  // order. Plugins that aren't in the load order are ignored.
+  std::vector<LoadOrderViolation> FindViolations(
+      const std::vector<std::string>& loadOrder) const;
+
+  // Swap adjacent plugins in the given sorted order back into their relative
+  // positions in the given load order where the only edge between them is a
+  // tie-break edge. Plugins that aren't in the load order are not moved.
+  std::vector<std::string> MinimiseMovement(
+      std::vector<std::string> sortedPlugins,
+      const std::vector<std::string>& loadOrder) const;
+
 private:
   std::optional<vertex_t> GetVertexByName(const std::string& name) const;
   bool EdgeCreatesCycle(const vertex_t& u, const vertex_t& v);
@@ -102,6 +114,10 @@ private:
 
   RawPluginGraph graph_;
   std::unordered_set<GraphPath> pathsCache_;
+  // Keyed by normalised plugin filename, so that GetVertexByName() doesn't
+  // have to scan every vertex. Plugin names are not interned: the game cache
+  // and metadata lists are already keyed by normalised filename.
+  std::unordered_map<std::string, vertex_t> verticesByName_;
 };
 }
 
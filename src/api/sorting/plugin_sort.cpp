@@ -24,16 +24,42 @@
 
 #include "plugin_sort.h"
 
+#include <algorithm>
+
 #include "api/helpers/logging.h"
+#include "api/helpers/text.h"
+#include "api/helpers/timing.h"
 #include "api/sorting/plugin_graph.h"
 
 namespace loot {
+void ReportSortingProgress(const SortingProgressCallback& progressCallback,
+                           const std::string& phase,
+                           size_t phaseIndex) {
+  if (progressCallback &&
+      !progressCallback(phase, phaseIndex, SORTING_PHASE_COUNT)) {
+    auto logger = getLogger();
+    if (logger) {
+      logger->info("Sorting was cancelled before the \"{}\" phase", phase);
+    }
+
+    throw SortingCancelledError(phase);
+  }
+}
+
 std::vector<std::string> SortPlugins(
     Game& game,
-    const std::vector<std::string>& loadOrder) {
+    const std::vector<std::string>& loadOrder,
+    const SortingProgressCallback& progressCallback) {
+  const auto timings = game.GetTimings();
+  ScopedTimer sortTimer(timings, "SortPlugins");
+
   PluginGraph graph;
 
-  graph.AddPluginVertices(game, loadOrder);
+  {
+    ReportSortingProgress(progressCallback, "AddPluginVertices", 1);
+    ScopedTimer timer(timings, "SortPlugins/AddPluginVertices");
+    graph.AddPluginVertices(game, loadOrder);
+  }
 
   // If there aren't any vertices, exit early, because sorting assumes
   // there is at least one plugin.
@@ -49,19 +75,92 @@ std::vector<std::string> SortPlugins(
   }
 
   // Now add the interactions between plugins to the graph as edges.
-  graph.AddSpecificEdges();
-  graph.AddHardcodedPluginEdges(game);
+  {
+    ReportSortingProgress(progressCallback, "AddSpecificEdges", 2);
+    ScopedTimer timer(timings, "SortPlugins/AddSpecificEdges");
+    graph.AddSpecificEdges();
+  }
+  {
+    ReportSortingProgress(progressCallback, "AddHardcodedPluginEdges", 3);
+    ScopedTimer timer(timings, "SortPlugins/AddHardcodedPluginEdges");
+    graph.AddHardcodedPluginEdges(game);
+  }
+  {
+    ReportSortingProgress(progressCallback, "AddGroupEdges", 4);
+    ScopedTimer timer(timings, "SortPlugins/AddGroupEdges");
+    std::unordered_map<std::string, Group> groups;
+    for (const auto& group : game.GetDatabase()->GetGroups()) {
+      groups.emplace(group.GetName(), group);
+    }
+    graph.AddGroupEdges(groups);
+  }
+  {
+    ReportSortingProgress(progressCallback, "AddOverlapEdges", 5);
+    ScopedTimer timer(timings, "SortPlugins/AddOverlapEdges");
+    graph.AddOverlapEdges();
+  }
+  {
+    ReportSortingProgress(progressCallback, "AddTieBreakEdges", 6);
+    ScopedTimer timer(timings, "SortPlugins/AddTieBreakEdges");
+    graph.AddTieBreakEdges();
+  }
+  {
+    ReportSortingProgress(progressCallback, "CheckForCycles", 7);
+    ScopedTimer timer(timings, "SortPlugins/CheckForCycles");
+    graph.CheckForCycles();
+  }
+
+  std::vector<std::string> sortedPlugins;
+  {
+    ReportSortingProgress(progressCallback, "TopologicalSort", 8);
+    ScopedTimer timer(timings, "SortPlugins/TopologicalSort");
+    sortedPlugins = graph.TopologicalSort();
+  }
+
+  ReportSortingProgress(progressCallback, "MinimiseMovement", 9);
+  ScopedTimer timer(timings, "SortPlugins/MinimiseMovement");
+  return graph.MinimiseMovement(sortedPlugins, loadOrder);
+}
+
+size_t CountMovedPlugins(const std::vector<std::string>& loadOrder,
+                         const std::vector<std::string>& sortedPlugins) {
+  std::unordered_map<std::string, size_t> positions;
+  for (size_t i = 0; i < loadOrder.size(); ++i) {
+    positions.emplace(NormalizeFilename(loadOrder[i]), i);
+  }
+
+  // The plugins that don't need to move are those in the longest sequence
+  // that keeps its relative order, i.e. the longest increasing subsequence of
+  // their previous positions.
+  std::vector<size_t> sequenceTails;
+  size_t commonPluginCount = 0;
+  for (const auto& plugin : sortedPlugins) {
+    const auto it = positions.find(NormalizeFilename(plugin));
+    if (it == positions.end()) {
+      continue;
+    }
 
-  std::unordered_map<std::string, Group> groups;
-  for (const auto& group : game.GetDatabase()->GetGroups()) {
-    groups.emplace(group.GetName(), group);
+    ++commonPluginCount;
+    const auto tailIt = std::lower_bound(
+        sequenceTails.begin(), sequenceTails.end(), it->second);
+    if (tailIt == sequenceTails.end()) {
+      sequenceTails.push_back(it->second);
+    } else {
+      *tailIt = it->second;
+    }
   }
-  graph.AddGroupEdges(groups);
-  graph.AddOverlapEdges();
-  graph.AddTieBreakEdges();
 
-  graph.CheckForCycles();
+  return commonPluginCount - sequenceTails.size();
+}
+
+std::vector<LoadOrderViolation> VerifyLoadOrder(
+    Game& game,
+    const std::vector<std::string>& loadOrder) {
+  PluginGraph graph;
+  graph.AddPluginVertices(game, loadOrder);
+  graph.AddSpecificEdges();
+  graph.AddHardcodedPluginEdges(game);
 
-  return graph.TopologicalSort();
+  return graph.FindViolations(loadOrder);
 }
 }
@@ -31,8 +31,28 @@
 #include "api/game/game.h"
 
 namespace loot {
-std::vector<std::string> SortPlugins(Game& game,
-                                     const std::vector<std::string>& loadOrder);
+// Loading plugins is counted as the first sorting phase.
+static constexpr size_t SORTING_PHASE_COUNT = 10;
+
+void ReportSortingProgress(const SortingProgressCallback& progressCallback,
+                           const std::string& phase,
+                           size_t phaseIndex);
+
+std::vector<std::string> SortPlugins(
+    Game& game,
+    const std::vector<std::string>& loadOrder,
+    const SortingProgressCallback& progressCallback =
+        SortingProgressCallback());
+
+// Counts the fewest plugins that would need to be moved to get from the
+// given load order to the given sorted order. Plugins that are not in both
+// are ignored.
+size_t CountMovedPlugins(const std::vector<std::string>& loadOrder,
+                         const std::vector<std::string>& sortedPlugins);
+
+std::vector<LoadOrderViolation> VerifyLoadOrder(
+    Game& game,
+    const std::vector<std::string>& loadOrder);
 }
 
 #endif
@@ -0,0 +1,179 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#include "api/sorting/redundant_rules.h"
+
+#include <algorithm>
+#include <queue>
+#include <unordered_map>
+#include <unordered_set>
+
+#include "api/helpers/logging.h"
+#include "api/helpers/text.h"
+
+namespace loot {
+typedef std::unordered_map<std::string, std::vector<std::string>> ParentsMap;
+
+// Check if the plugin must load after the given file, by following the
+// plugins that each plugin must load after.
+bool mustLoadAfter(const ParentsMap& parents,
+                   const std::string& plugin,
+                   const std::string& file) {
+  std::queue<std::string> queue;
+  std::unordered_set<std::string> visited;
+
+  queue.push(plugin);
+  visited.insert(plugin);
+
+  while (!queue.empty()) {
+    auto current = queue.front();
+    queue.pop();
+
+    auto it = parents.find(current);
+    if (it == parents.end()) {
+      continue;
+    }
+
+    for (const auto& parent : it->second) {
+      if (parent == file) {
+        return true;
+      }
+
+      if (visited.count(parent) == 0) {
+        visited.insert(parent);
+        queue.push(parent);
+      }
+    }
+  }
+
+  return false;
+}
+
+std::vector<RedundantUserRule> FindRedundantUserRules(
+    Game& game,
+    const std::vector<std::string>& loadOrder) {
+  std::vector<std::shared_ptr<const Plugin>> plugins;
+  std::vector<std::string> masterFlaggedPlugins;
+  for (const auto& pluginName : loadOrder) {
+    auto plugin = game.GetCache()->GetPlugin(pluginName);
+    if (plugin) {
+      plugins.push_back(plugin);
+
+      if (plugin->IsMaster()) {
+        masterFlaggedPlugins.push_back(NormalizeFilename(plugin->GetName()));
+      }
+    }
+  }
+
+  // Record the plugins that each plugin must load after and the files that it
+  // requires without any user metadata, keyed by normalised filename.
+  ParentsMap parents;
+  ParentsMap requirements;
+  std::unordered_map<std::string, PluginMetadata> masterlistMetadata;
+  for (const auto& plugin : plugins) {
+    auto key = NormalizeFilename(plugin->GetName());
+    auto metadata = game.GetDatabase()
+                        ->GetPluginMetadata(plugin->GetName(), false, true)
+                        .value_or(PluginMetadata(plugin->GetName()));
+
+    auto& pluginParents = parents[key];
+    auto& pluginRequirements = requirements[key];
+    for (const auto& master : plugin->GetMasters()) {
+      pluginParents.push_back(NormalizeFilename(master));
+      pluginRequirements.push_back(NormalizeFilename(master));
+    }
+
+    if (!plugin->IsMaster()) {
+      pluginParents.insert(pluginParents.end(),
+                           masterFlaggedPlugins.begin(),
+                           masterFlaggedPlugins.end());
+    }
+
+    for (const auto& file : metadata.GetLoadAfterFiles()) {
+      pluginParents.push_back(NormalizeFilename(std::string(file.GetName())));
+    }
+
+    for (const auto& file : metadata.GetRequirements()) {
+      pluginParents.push_back(NormalizeFilename(std::string(file.GetName())));
+      pluginRequirements.push_back(
+          NormalizeFilename(std::string(file.GetName())));
+    }
+
+    masterlistMetadata.emplace(key, metadata);
+  }
+
+  auto logger = getLogger();
+  std::vector<RedundantUserRule> rules;
+  for (const auto& plugin : plugins) {
+    auto userMetadata =
+        game.GetDatabase()->GetPluginUserMetadata(plugin->GetName(), true);
+    if (!userMetadata.has_value()) {
+      continue;
+    }
+
+    auto key = NormalizeFilename(plugin->GetName());
+
+    for (const auto& file : userMetadata.value().GetLoadAfterFiles()) {
+      auto fileName = std::string(file.GetName());
+      if (mustLoadAfter(parents, key, NormalizeFilename(fileName))) {
+        rules.push_back(RedundantUserRule{
+            plugin->GetName(), fileName, EdgeType::userLoadAfter});
+      }
+    }
+
+    // A requirement is only redundant if the plugin already requires the
+    // file, because a requirement also causes a message if the file is
+    // missing.
+    const auto& pluginRequirements = requirements.at(key);
+    for (const auto& file : userMetadata.value().GetRequirements()) {
+      auto fileName = std::string(file.GetName());
+      if (std::find(pluginRequirements.begin(),
+                    pluginRequirements.end(),
+                    NormalizeFilename(fileName)) != pluginRequirements.end()) {
+        rules.push_back(RedundantUserRule{
+            plugin->GetName(), fileName, EdgeType::userRequirement});
+      }
+    }
+
+    auto userGroup = userMetadata.value().GetGroup();
+    auto masterlistGroup =
+        masterlistMetadata.at(key).GetGroup().value_or(Group().GetName());
+    if (userGroup.has_value() && userGroup.value() == masterlistGroup) {
+      rules.push_back(RedundantUserRule{
+          plugin->GetName(), userGroup.value(), EdgeType::group});
+    }
+  }
+
+  if (logger) {
+    for (const auto& rule : rules) {
+      logger->debug(
+          "The user metadata for \"{}\" has a redundant rule for \"{}\".",
+          rule.plugin,
+          rule.target);
+    }
+  }
+
+  return rules;
+}
+}
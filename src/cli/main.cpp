@@ -0,0 +1,334 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#include <iostream>
+#include <map>
+#include <optional>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+#include "loot/api.h"
+
+using loot::GameType;
+
+namespace {
+const char* USAGE =
+    "Usage: loot_cli <command> --game <type> --game-path <path> [options] "
+    "[arguments]\n"
+    "\n"
+    "Commands:\n"
+    "  sort                          Print the sorted order of the game's\n"
+    "                                installed plugins.\n"
+    "  validate-metadata             Check that the metadata files can be\n"
+    "                                loaded and that the conditions used by\n"
+    "                                installed plugins are valid.\n"
+    "  evaluate-condition <condition>\n"
+    "                                Print the result of evaluating a\n"
+    "                                condition.\n"
+    "  list-metadata <plugin>        Print the evaluated metadata for a\n"
+    "                                plugin.\n"
+    "  write-minimal-list <file>     Write the masterlist's Bash Tag and\n"
+    "                                dirty info to a file.\n"
+    "\n"
+    "Options:\n"
+    "  --game <type>                 One of tes3, tes4, tes5, tes5se, tes5vr,\n"
+    "                                fo3, fonv, fo4 or fo4vr.\n"
+    "  --game-path <path>            The game's install path.\n"
+    "  --local-path <path>           The game's local application data path.\n"
+    "  --masterlist <path>           The masterlist to load.\n"
+    "  --userlist <path>             The userlist to load.\n"
+    "  --overwrite                   Replace the output file if it exists.\n";
+
+struct Arguments {
+  std::string command;
+  std::optional<GameType> gameType;
+  std::string gamePath;
+  std::string localPath;
+  std::string masterlistPath;
+  std::string userlistPath;
+  bool overwrite = false;
+  std::vector<std::string> positional;
+};
+
+class UsageError : public std::runtime_error {
+public:
+  explicit UsageError(const std::string& message) :
+      std::runtime_error(message) {}
+};
+
+GameType ParseGameType(const std::string& value) {
+  static const std::map<std::string, GameType> gameTypes = {
+      {"tes3", GameType::tes3},
+      {"tes4", GameType::tes4},
+      {"tes5", GameType::tes5},
+      {"tes5se", GameType::tes5se},
+      {"tes5vr", GameType::tes5vr},
+      {"fo3", GameType::fo3},
+      {"fonv", GameType::fonv},
+      {"fo4", GameType::fo4},
+      {"fo4vr", GameType::fo4vr},
+  };
+
+  const auto it = gameTypes.find(value);
+  if (it == gameTypes.end()) {
+    throw UsageError("Unrecognised game type \"" + value + "\".");
+  }
+
+  return it->second;
+}
+
+Arguments ParseArguments(int argc, char** argv) {
+  if (argc < 2) {
+    throw UsageError("No command given.");
+  }
+
+  Arguments arguments;
+  arguments.command = argv[1];
+
+  for (int i = 2; i < argc; ++i) {
+    const std::string arg = argv[i];
+
+    if (arg == "--overwrite") {
+      arguments.overwrite = true;
+      continue;
+    }
+
+    if (arg.rfind("--", 0) != 0) {
+      arguments.positional.push_back(arg);
+      continue;
+    }
+
+    if (i + 1 == argc) {
+      throw UsageError("No value given for " + arg + ".");
+    }
+
+    const std::string value = argv[++i];
+    if (arg == "--game") {
+      arguments.gameType = ParseGameType(value);
+    } else if (arg == "--game-path") {
+      arguments.gamePath = value;
+    } else if (arg == "--local-path") {
+      arguments.localPath = value;
+    } else if (arg == "--masterlist") {
+      arguments.masterlistPath = value;
+    } else if (arg == "--userlist") {
+      arguments.userlistPath = value;
+    } else {
+      throw UsageError("Unrecognised option " + arg + ".");
+    }
+  }
+
+  if (!arguments.gameType.has_value() || arguments.gamePath.empty()) {
+    throw UsageError("The --game and --game-path options are required.");
+  }
+
+  return arguments;
+}
+
+const std::string& GetPositional(const Arguments& arguments) {
+  if (arguments.positional.size() != 1) {
+    throw UsageError("The " + arguments.command +
+                     " command takes exactly one argument.");
+  }
+
+  return arguments.positional.front();
+}
+
+std::shared_ptr<loot::GameInterface> CreateGame(const Arguments& arguments) {
+  auto game = loot::CreateGameHandle(
+      arguments.gameType.value(), arguments.gamePath, arguments.localPath);
+
+  if (!arguments.masterlistPath.empty() || !arguments.userlistPath.empty()) {
+    game->GetDatabase()->LoadLists(arguments.masterlistPath,
+                                   arguments.userlistPath);
+  }
+
+  game->LoadCurrentLoadOrderState();
+
+  return game;
+}
+
+void PrintFiles(const std::string& label,
+                const std::vector<loot::File>& files) {
+  for (const auto& file : files) {
+    std::cout << label << ": " << std::string(file.GetName()) << std::endl;
+  }
+}
+
+int Sort(const Arguments& arguments) {
+  auto game = CreateGame(arguments);
+
+  for (const auto& plugin : game->SortPlugins(game->GetLoadOrder())) {
+    std::cout << plugin << std::endl;
+  }
+
+  return 0;
+}
+
+int ValidateMetadata(const Arguments& arguments) {
+  if (arguments.masterlistPath.empty() && arguments.userlistPath.empty()) {
+    throw UsageError("No metadata files given to validate.");
+  }
+
+  auto game = CreateGame(arguments);
+  auto database = game->GetDatabase();
+
+  std::vector<std::string> conditions;
+  for (const auto& message : database->GetGeneralMessages(false)) {
+    conditions.push_back(message.GetCondition());
+  }
+
+  for (const auto& plugin : game->GetLoadOrder()) {
+    auto metadata = database->GetPluginMetadata(plugin, true, false);
+    if (!metadata.has_value()) {
+      continue;
+    }
+
+    for (const auto& file : metadata.value().GetLoadAfterFiles()) {
+      conditions.push_back(file.GetCondition());
+    }
+    for (const auto& file : metadata.value().GetRequirements()) {
+      conditions.push_back(file.GetCondition());
+    }
+    for (const auto& file : metadata.value().GetIncompatibilities()) {
+      conditions.push_back(file.GetCondition());
+    }
+    for (const auto& message : metadata.value().GetMessages()) {
+      conditions.push_back(message.GetCondition());
+    }
+    for (const auto& tag : metadata.value().GetTags()) {
+      conditions.push_back(tag.GetCondition());
+    }
+  }
+
+  int invalidCount = 0;
+  for (const auto& condition : conditions) {
+    if (condition.empty()) {
+      continue;
+    }
+
+    try {
+      database->ValidateCondition(condition);
+    } catch (const loot::ConditionSyntaxError& e) {
+      std::cerr << e.what() << std::endl;
+      invalidCount += 1;
+    }
+  }
+
+  if (invalidCount > 0) {
+    std::cerr << invalidCount << " invalid conditions found." << std::endl;
+    return 1;
+  }
+
+  std::cout << "The metadata is valid." << std::endl;
+  return 0;
+}
+
+int EvaluateCondition(const Arguments& arguments) {
+  const auto& condition = GetPositional(arguments);
+  auto game = CreateGame(arguments);
+
+  const auto results = game->GetDatabase()->EvaluateConditions({condition});
+  std::cout << (results.front() ? "true" : "false") << std::endl;
+
+  return 0;
+}
+
+int ListMetadata(const Arguments& arguments) {
+  const auto& plugin = GetPositional(arguments);
+  auto game = CreateGame(arguments);
+
+  auto metadata = game->GetDatabase()->GetPluginMetadata(plugin, true, true);
+  if (!metadata.has_value()) {
+    std::cout << "No metadata found for " << plugin << "." << std::endl;
+    return 0;
+  }
+
+  std::cout << "name: " << metadata.value().GetName() << std::endl;
+  if (metadata.value().GetGroup().has_value()) {
+    std::cout << "group: " << metadata.value().GetGroup().value()
+              << std::endl;
+  }
+
+  PrintFiles("after", metadata.value().GetLoadAfterFiles());
+  PrintFiles("req", metadata.value().GetRequirements());
+  PrintFiles("inc", metadata.value().GetIncompatibilities());
+
+  for (const auto& tag : metadata.value().GetTags()) {
+    std::cout << "tag: " << (tag.IsAddition() ? "" : "-") << tag.GetName()
+              << std::endl;
+  }
+
+  const auto messages = metadata.value().GetSimpleMessages(
+      loot::MessageContent::defaultLanguage);
+  for (const auto& message : messages) {
+    std::cout << "msg: " << message.text << std::endl;
+  }
+
+  for (const auto& info : metadata.value().GetDirtyInfo()) {
+    std::cout << "dirty: " << std::hex << info.GetCRC() << std::dec
+              << std::endl;
+  }
+
+  return 0;
+}
+
+int WriteMinimalList(const Arguments& arguments) {
+  const auto& outputPath = GetPositional(arguments);
+  auto game = CreateGame(arguments);
+
+  game->GetDatabase()->WriteMinimalList(outputPath, arguments.overwrite);
+
+  return 0;
+}
+
+int Run(const Arguments& arguments) {
+  if (arguments.command == "sort") {
+    return Sort(arguments);
+  } else if (arguments.command == "validate-metadata") {
+    return ValidateMetadata(arguments);
+  } else if (arguments.command == "evaluate-condition") {
+    return EvaluateCondition(arguments);
+  } else if (arguments.command == "list-metadata") {
+    return ListMetadata(arguments);
+  } else if (arguments.command == "write-minimal-list") {
+    return WriteMinimalList(arguments);
+  }
+
+  throw UsageError("Unrecognised command \"" + arguments.command + "\".");
+}
+}
+
+int main(int argc, char** argv) {
+  try {
+    return Run(ParseArguments(argc, argv));
+  } catch (const UsageError& e) {
+    std::cerr << e.what() << std::endl << std::endl << USAGE;
+    return 2;
+  } catch (const std::exception& e) {
+    std::cerr << "Error: " << e.what() << std::endl;
+    return 1;
+  }
+}
@@ -0,0 +1,56 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_TESTS_API_INTERFACE_CAPABILITIES_TEST
+#define LOOT_TESTS_API_INTERFACE_CAPABILITIES_TEST
+
+#include "loot/api.h"
+
+#include <algorithm>
+
+#include <gtest/gtest.h>
+
+namespace loot {
+namespace test {
+TEST(GetCapabilities, shouldReturnCapabilitiesInAlphabeticalOrder) {
+  auto capabilities = GetCapabilities();
+
+  ASSERT_FALSE(capabilities.empty());
+  EXPECT_TRUE(std::is_sorted(capabilities.begin(), capabilities.end()));
+}
+
+TEST(HasCapability, shouldBeTrueForEveryCapabilityThatIsListed) {
+  for (const auto& capability : GetCapabilities()) {
+    EXPECT_TRUE(HasCapability(capability));
+  }
+}
+
+TEST(HasCapability, shouldBeFalseForAnUnknownCapability) {
+  EXPECT_FALSE(HasCapability("missing-capability"));
+  EXPECT_FALSE(HasCapability(""));
+}
+}
+}
+
+#endif
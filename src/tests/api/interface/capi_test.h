@@ -0,0 +1,272 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_TESTS_API_INTERFACE_CAPI_TEST
+#define LOOT_TESTS_API_INTERFACE_CAPI_TEST
+
+#include "loot/capi.h"
+
+#include <fstream>
+
+#include "loot/api.h"
+#include "tests/common_game_test_fixture.h"
+
+namespace loot {
+namespace test {
+class CApiTest : public CommonGameTestFixture {
+protected:
+  CApiTest() : game_(nullptr) {}
+
+  void SetUp() {
+    CommonGameTestFixture::SetUp();
+
+    ASSERT_EQ(LOOT_OK,
+              loot_create_game(static_cast<unsigned int>(GetParam()),
+                               dataPath.parent_path().u8string().c_str(),
+                               localPath.u8string().c_str(),
+                               &game_));
+  }
+
+  void TearDown() {
+    loot_destroy_game(game_);
+
+    CommonGameTestFixture::TearDown();
+  }
+
+  loot_game* game_;
+};
+
+// Pass an empty first argument, as it's a prefix for the test instantation,
+// but we only have the one so no prefix is necessary.
+INSTANTIATE_TEST_CASE_P(,
+                        CApiTest,
+                        ::testing::Values(GameType::tes4,
+                                          GameType::tes5,
+                                          GameType::fo3,
+                                          GameType::fonv,
+                                          GameType::fo4,
+                                          GameType::tes5se));
+
+TEST_P(CApiTest, getVersionShouldReturnTheLibraryVersion) {
+  char* version = nullptr;
+  ASSERT_EQ(LOOT_OK, loot_get_version(&version));
+
+  EXPECT_EQ(LootVersion::GetVersionString(), std::string(version));
+
+  loot_free_string(version);
+}
+
+TEST_P(CApiTest, hasCapabilityShouldMatchTheCppApi) {
+  EXPECT_TRUE(loot_has_capability("sorting-progress"));
+  EXPECT_FALSE(loot_has_capability("missing-capability"));
+  EXPECT_FALSE(loot_has_capability(nullptr));
+}
+
+TEST_P(CApiTest, createGameShouldReturnAGameNotFoundErrorForAMissingPath) {
+  loot_game* game = nullptr;
+  EXPECT_EQ(LOOT_ERROR_GAME_NOT_FOUND,
+            loot_create_game(static_cast<unsigned int>(GetParam()),
+                             "missing",
+                             nullptr,
+                             &game));
+  EXPECT_EQ(nullptr, game);
+  EXPECT_NE("", std::string(loot_get_last_error_message()));
+}
+
+TEST_P(CApiTest, createGameExShouldCreateAThreadSafeGameHandle) {
+  loot_game* game = nullptr;
+  ASSERT_EQ(LOOT_OK,
+            loot_create_game_ex(static_cast<unsigned int>(GetParam()),
+                                dataPath.parent_path().u8string().c_str(),
+                                localPath.u8string().c_str(),
+                                true,
+                                &game));
+  ASSERT_NE(nullptr, game);
+
+  bool isValid = false;
+  EXPECT_EQ(LOOT_OK,
+            loot_game_is_valid_plugin(game, blankEsm.c_str(), &isValid));
+  EXPECT_TRUE(isValid);
+
+  loot_destroy_game(game);
+}
+
+TEST_P(CApiTest, functionsShouldReturnAnInvalidArgumentErrorForNullArguments) {
+  bool isValid = false;
+  EXPECT_EQ(LOOT_ERROR_INVALID_ARGUMENT,
+            loot_game_is_valid_plugin(game_, nullptr, &isValid));
+  EXPECT_EQ(LOOT_ERROR_INVALID_ARGUMENT,
+            loot_game_is_valid_plugin(nullptr, blankEsm.c_str(), &isValid));
+}
+
+TEST_P(CApiTest, successfulCallsShouldClearTheLastErrorMessage) {
+  ASSERT_EQ(LOOT_ERROR_INVALID_ARGUMENT,
+            loot_game_is_valid_plugin(game_, nullptr, nullptr));
+
+  bool isValid = false;
+  EXPECT_EQ(LOOT_OK,
+            loot_game_is_valid_plugin(game_, blankEsm.c_str(), &isValid));
+  EXPECT_TRUE(isValid);
+  EXPECT_EQ("", std::string(loot_get_last_error_message()));
+}
+
+TEST_P(CApiTest,
+       getPluginInfoShouldReturnAPluginNotLoadedErrorForAnUnloadedPlugin) {
+  bool isMaster = false;
+  EXPECT_EQ(LOOT_ERROR_PLUGIN_NOT_LOADED,
+            loot_game_get_plugin_info(game_,
+                                      blankEsp.c_str(),
+                                      nullptr,
+                                      nullptr,
+                                      &isMaster,
+                                      nullptr,
+                                      nullptr));
+}
+
+TEST_P(CApiTest, getLoadOrderShouldReturnTheCurrentLoadOrder) {
+  ASSERT_EQ(LOOT_OK, loot_game_load_current_load_order_state(game_));
+
+  loot_string_array loadOrder;
+  ASSERT_EQ(LOOT_OK, loot_game_get_load_order(game_, &loadOrder));
+
+  std::vector<std::string> actual(loadOrder.items,
+                                  loadOrder.items + loadOrder.count);
+  loot_free_string_array(&loadOrder);
+
+  EXPECT_EQ(getLoadOrder(), actual);
+}
+
+TEST_P(CApiTest,
+       sortPluginsWithProgressShouldReturnACancelledErrorIfCallbackCancels) {
+  ASSERT_EQ(LOOT_OK, loot_game_load_current_load_order_state(game_));
+
+  const char* plugins[] = {masterFile.c_str(), blankEsm.c_str()};
+  size_t callCount = 0;
+  auto callback = [](const char*, size_t, size_t, void* context) {
+    *static_cast<size_t*>(context) += 1;
+    return false;
+  };
+
+  loot_string_array sortedPlugins;
+  EXPECT_EQ(LOOT_ERROR_SORTING_CANCELLED,
+            loot_game_sort_plugins_with_progress(
+                game_, plugins, 2, callback, &callCount, &sortedPlugins));
+  EXPECT_EQ(1, callCount);
+}
+
+TEST_P(CApiTest,
+       loadListsShouldReturnAFileAccessErrorIfTheMasterlistIsMissing) {
+  const auto masterlistPath = (localPath / "missing.yaml").u8string();
+
+  EXPECT_EQ(LOOT_ERROR_FILE_ACCESS,
+            loot_db_load_lists(game_, masterlistPath.c_str(), nullptr));
+}
+
+TEST_P(CApiTest,
+       loadListsShouldReturnAMetadataParseErrorIfTheMasterlistIsInvalid) {
+  const auto masterlistPath = localPath / "masterlist.yaml";
+  std::ofstream masterlist(masterlistPath);
+  masterlist << "plugins:\n  - name: [";
+  masterlist.close();
+
+  EXPECT_EQ(LOOT_ERROR_METADATA_PARSE,
+            loot_db_load_lists(
+                game_, masterlistPath.u8string().c_str(), nullptr));
+  EXPECT_NE("", std::string(loot_get_last_error_message()));
+}
+
+TEST_P(CApiTest, writeUserMetadataShouldRespectTheGivenWriteMode) {
+  const auto outputPath = (localPath / "userlist.yaml").u8string();
+
+  ASSERT_EQ(LOOT_OK,
+            loot_db_write_user_metadata(
+                game_, outputPath.c_str(), LOOT_WRITE_MODE_CREATE));
+  EXPECT_EQ(LOOT_ERROR_FILE_ACCESS,
+            loot_db_write_user_metadata(
+                game_, outputPath.c_str(), LOOT_WRITE_MODE_CREATE));
+  EXPECT_EQ(LOOT_OK,
+            loot_db_write_user_metadata(game_,
+                                        outputPath.c_str(),
+                                        LOOT_WRITE_MODE_CREATE_OR_TRUNCATE));
+}
+
+TEST_P(CApiTest,
+       writeMinimalListShouldReturnAnInvalidArgumentErrorForAnInvalidMode) {
+  const auto outputPath = (localPath / "minimal.yaml").u8string();
+
+  EXPECT_EQ(LOOT_ERROR_INVALID_ARGUMENT,
+            loot_db_write_minimal_list(
+                game_, outputPath.c_str(), static_cast<loot_write_mode>(2)));
+}
+
+TEST_P(
+    CApiTest,
+    evaluateConditionShouldReturnAConditionSyntaxErrorForAnInvalidCondition) {
+  bool result = false;
+  EXPECT_EQ(LOOT_ERROR_CONDITION_SYNTAX,
+            loot_db_evaluate_condition(game_, "file(", &result));
+}
+
+TEST_P(CApiTest,
+       createPluginMetadataShouldReturnAnInvalidArgumentErrorForAnInvalidRegex) {
+  loot_plugin_metadata* metadata = nullptr;
+  EXPECT_EQ(LOOT_ERROR_INVALID_ARGUMENT,
+            loot_create_plugin_metadata("Blank[.*\\.esp", &metadata));
+  EXPECT_EQ(nullptr, metadata);
+  EXPECT_NE("", std::string(loot_get_last_error_message()));
+}
+
+TEST_P(CApiTest, pluginUserMetadataShouldRoundTripThroughTheDatabase) {
+  loot_plugin_metadata* metadata = nullptr;
+  ASSERT_EQ(LOOT_OK, loot_create_plugin_metadata(blankEsp.c_str(), &metadata));
+
+  const char* loadAfter[] = {"Blank.esm"};
+  ASSERT_EQ(LOOT_OK,
+            loot_plugin_metadata_set_load_after(metadata, loadAfter, 1));
+  ASSERT_EQ(LOOT_OK, loot_plugin_metadata_set_group(metadata, "group1"));
+  ASSERT_EQ(LOOT_OK, loot_db_set_plugin_user_metadata(game_, metadata));
+  loot_destroy_plugin_metadata(metadata);
+
+  ASSERT_EQ(LOOT_OK,
+            loot_db_get_plugin_user_metadata(
+                game_, blankEsp.c_str(), false, &metadata));
+  ASSERT_NE(nullptr, metadata);
+
+  char* group = nullptr;
+  ASSERT_EQ(LOOT_OK, loot_plugin_metadata_get_group(metadata, &group));
+  EXPECT_EQ("group1", std::string(group));
+  loot_free_string(group);
+
+  loot_string_array files;
+  ASSERT_EQ(LOOT_OK, loot_plugin_metadata_get_load_after(metadata, &files));
+  ASSERT_EQ(1, files.count);
+  EXPECT_EQ("Blank.esm", std::string(files.items[0]));
+  loot_free_string_array(&files);
+
+  loot_destroy_plugin_metadata(metadata);
+}
+}
+}
+
+#endif
@@ -0,0 +1,77 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_TESTS_API_INTERFACE_CONDITION_LANGUAGE_TEST
+#define LOOT_TESTS_API_INTERFACE_CONDITION_LANGUAGE_TEST
+
+#include "loot/api.h"
+
+#include <gtest/gtest.h>
+
+#include <algorithm>
+
+namespace loot {
+namespace test {
+TEST(GetConditionInterpreterVersion,
+     shouldReturnAVersionStringWithThreeComponents) {
+  auto version = GetConditionInterpreterVersion();
+
+  EXPECT_EQ(2, std::count(version.begin(), version.end(), '.'));
+}
+
+// The supported functions and operators are listed by hand, so this fails when
+// the loot-condition-interpreter version changes as a reminder to check them.
+TEST(GetSupportedConditionFunctions,
+     shouldHaveBeenCheckedAgainstTheConditionInterpreterVersion) {
+  EXPECT_EQ("2.2.1", GetConditionInterpreterVersion());
+}
+
+TEST(GetSupportedConditionFunctions, shouldIncludeAllDocumentedFunctions) {
+  auto functions = GetSupportedConditionFunctions();
+
+  EXPECT_EQ(std::vector<std::string>({
+                "file",
+                "active",
+                "many",
+                "many_active",
+                "is_master",
+                "checksum",
+                "version",
+                "product_version",
+            }),
+            functions);
+}
+
+TEST(GetSupportedConditionOperators,
+     shouldIncludeLogicalAndComparisonOperators) {
+  auto operators = GetSupportedConditionOperators();
+
+  EXPECT_EQ(std::vector<std::string>(
+                {"and", "or", "not", "==", "!=", "<", ">", "<=", ">="}),
+            operators);
+}
+}
+}
+
+#endif
@@ -28,6 +28,7 @@ along with LOOT.  If not, see
 #include "loot/api.h"
 
 #include <climits>
+#include <thread>
 
 #include "tests/common_game_test_fixture.h"
 
@@ -109,6 +110,31 @@ TEST_P(CreateGameHandleTest,
   EXPECT_TRUE(handle_);
 }
 
+TEST_P(CreateGameHandleTest,
+       threadSafeHandlesShouldBeUsableFromMultipleThreadsAtOnce) {
+  handle_ = CreateGameHandle(
+      GetParam(), dataPath.parent_path(), localPath, true);
+  ASSERT_TRUE(handle_);
+
+  std::vector<std::thread> threads;
+  for (size_t i = 0; i < 4; ++i) {
+    threads.emplace_back([&]() {
+      for (size_t j = 0; j < 10; ++j) {
+        handle_->LoadCurrentLoadOrderState();
+        handle_->LoadPlugins({blankEsm, blankEsp}, true);
+        handle_->GetDatabase()->GetGroups();
+      }
+    });
+  }
+
+  for (auto& thread : threads) {
+    thread.join();
+  }
+
+  EXPECT_EQ(2, handle_->GetLoadedPlugins().size());
+  EXPECT_FALSE(handle_->GetLoadOrder().empty());
+}
+
 TEST_P(CreateGameHandleTest, shouldThrowIfPassedAGamePathThatDoesNotExist) {
   EXPECT_THROW(CreateGameHandle(GetParam(), missingPath, localPath),
                std::invalid_argument);
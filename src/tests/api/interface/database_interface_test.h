@@ -146,6 +146,16 @@ TEST_P(DatabaseInterfaceTest, loadListsShouldThrowIfNoMasterlistIsPresent) {
   EXPECT_THROW(db_->LoadLists(masterlistPath, ""), FileAccessError);
 }
 
+TEST_P(DatabaseInterfaceTest,
+       loadListsErrorShouldGiveThePathOfTheMissingMasterlist) {
+  try {
+    db_->LoadLists(masterlistPath, "");
+    FAIL();
+  } catch (const FileAccessError& e) {
+    EXPECT_EQ(masterlistPath, e.GetPath());
+  }
+}
+
 TEST_P(
     DatabaseInterfaceTest,
     loadListsShouldThrowIfAMasterlistIsPresentButAUserlistDoesNotExistAtTheGivenPath) {
@@ -195,6 +205,26 @@ TEST_P(
   EXPECT_NO_THROW(db_->WriteUserMetadata(minimalOutputPath_, true));
 }
 
+TEST_P(
+    DatabaseInterfaceTest,
+    writeUserMetadataShouldThrowIfTheFileAlreadyExistsAndTheWriteModeIsCreate) {
+  ASSERT_NO_THROW(db_->WriteUserMetadata(minimalOutputPath_, WriteMode::create));
+  ASSERT_TRUE(std::filesystem::exists(minimalOutputPath_));
+
+  EXPECT_THROW(db_->WriteUserMetadata(minimalOutputPath_, WriteMode::create),
+               FileAccessError);
+}
+
+TEST_P(
+    DatabaseInterfaceTest,
+    writeUserMetadataShouldReturnOkIfTheFileAlreadyExistsAndTheWriteModeIsCreateOrTruncate) {
+  ASSERT_NO_THROW(db_->WriteUserMetadata(minimalOutputPath_, WriteMode::create));
+  ASSERT_TRUE(std::filesystem::exists(minimalOutputPath_));
+
+  EXPECT_NO_THROW(
+      db_->WriteUserMetadata(minimalOutputPath_, WriteMode::createOrTruncate));
+}
+
 TEST_P(DatabaseInterfaceTest,
        writeUserMetadataShouldThrowIfPathGivenExistsAndIsReadOnly) {
   ASSERT_NO_THROW(db_->WriteUserMetadata(minimalOutputPath_, false));
@@ -545,6 +575,119 @@ TEST_P(
   EXPECT_TRUE(messages.empty());
 }
 
+TEST_P(DatabaseInterfaceTest,
+       getSuppressedMessageIdsShouldReturnAnEmptyVectorByDefault) {
+  EXPECT_TRUE(db_->GetSuppressedMessageIds().empty());
+}
+
+TEST_P(DatabaseInterfaceTest,
+       getSuppressedMessageIdsShouldReturnTheIdsThatWereSet) {
+  db_->SetSuppressedMessageIds({"id1", "id2"});
+
+  EXPECT_EQ(std::vector<std::string>({"id1", "id2"}),
+            db_->GetSuppressedMessageIds());
+}
+
+TEST_P(DatabaseInterfaceTest,
+       getGeneralMessagesShouldOmitMessagesWithSuppressedIds) {
+  std::ofstream userlist(userlistPath_);
+  userlist << "globals:\n"
+           << "  - type: say\n"
+           << "    content: 'first'\n"
+           << "    id: first-id\n"
+           << "  - type: say\n"
+           << "    content: 'second'\n"
+           << "    id: second-id\n"
+           << "suppressed_messages:\n"
+           << "  - second-id\n";
+  userlist.close();
+
+  ASSERT_NO_THROW(GenerateMasterlist());
+  ASSERT_NO_THROW(db_->LoadLists(masterlistPath, userlistPath_));
+
+  EXPECT_EQ(std::vector<std::string>({"second-id"}),
+            db_->GetSuppressedMessageIds());
+
+  auto messages = db_->GetGeneralMessages();
+
+  std::vector<Message> expectedMessages({
+      Message(MessageType::say,
+              generalMasterlistMessage,
+              "file(\"" + missingEsp + "\")"),
+      Message(MessageType::say, "first", "", "first-id"),
+  });
+  EXPECT_EQ(expectedMessages, messages);
+}
+
+TEST_P(DatabaseInterfaceTest,
+       getPluginMetadataShouldOmitMessagesWithSuppressedIds) {
+  PluginMetadata plugin(blankEsm);
+  plugin.SetMessages({
+      Message(MessageType::say, "first", "", "first-id"),
+      Message(MessageType::say, "second", "", "second-id"),
+      Message(MessageType::say, "third"),
+  });
+  db_->SetPluginUserMetadata(plugin);
+  db_->SetSuppressedMessageIds({"first-id"});
+
+  auto metadata = db_->GetPluginMetadata(blankEsm).value();
+
+  std::vector<Message> expectedMessages({
+      Message(MessageType::say, "second", "", "second-id"),
+      Message(MessageType::say, "third"),
+  });
+  EXPECT_EQ(expectedMessages, metadata.GetMessages());
+}
+
+TEST_P(DatabaseInterfaceTest,
+       getPluginUserMetadataShouldNotOmitMessagesWithSuppressedIds) {
+  PluginMetadata plugin(blankEsm);
+  plugin.SetMessages({
+      Message(MessageType::say, "first", "", "first-id"),
+      Message(MessageType::say, "second", "", "second-id"),
+  });
+  db_->SetPluginUserMetadata(plugin);
+  db_->SetSuppressedMessageIds({"first-id"});
+
+  auto metadata = db_->GetPluginUserMetadata(blankEsm).value();
+
+  EXPECT_EQ(plugin.GetMessages(), metadata.GetMessages());
+}
+
+TEST_P(
+    DatabaseInterfaceTest,
+    getPluginMetadataShouldOmitSuppressedMessagesIfUserMetadataIsNotIncluded) {
+  std::ofstream masterlist(masterlistPath);
+  masterlist << "plugins:\n"
+             << "  - name: " << blankEsm << "\n"
+             << "    msg:\n"
+             << "      - type: say\n"
+             << "        content: 'first'\n"
+             << "        id: first-id\n"
+             << "      - type: say\n"
+             << "        content: 'second'\n";
+  masterlist.close();
+
+  ASSERT_NO_THROW(db_->LoadLists(masterlistPath, ""));
+  db_->SetSuppressedMessageIds({"first-id"});
+
+  auto metadata = db_->GetPluginMetadata(blankEsm, false).value();
+
+  EXPECT_EQ(std::vector<Message>({Message(MessageType::say, "second")}),
+            metadata.GetMessages());
+}
+
+TEST_P(DatabaseInterfaceTest,
+       writeUserMetadataShouldWriteSuppressedMessageIds) {
+  db_->SetSuppressedMessageIds({"an-id"});
+
+  ASSERT_NO_THROW(db_->WriteUserMetadata(minimalOutputPath_, true));
+  ASSERT_NO_THROW(db_->LoadLists("", minimalOutputPath_));
+
+  EXPECT_EQ(std::vector<std::string>({"an-id"}),
+            db_->GetSuppressedMessageIds());
+}
+
 TEST_P(DatabaseInterfaceTest,
        getPluginMetadataShouldReturnAnEmptyOptionalIfThePluginHasNoMetadata) {
   EXPECT_FALSE(db_->GetPluginMetadata(blankEsm));
@@ -611,6 +754,85 @@ TEST_P(
   EXPECT_TRUE(metadata.GetMessages().empty());
 }
 
+TEST_P(DatabaseInterfaceTest,
+       getPluginMetadataWithMergeModesShouldReplaceListsSetByUserMetadata) {
+  ASSERT_NO_THROW(GenerateMasterlist());
+  ASSERT_NO_THROW(GenerateUserlist());
+  ASSERT_NO_THROW(db_->LoadLists(masterlistPath, userlistPath_));
+
+  MetadataMergeModes mergeModes;
+  mergeModes.loadAfter = MergeMode::replace;
+  mergeModes.messages = MergeMode::replace;
+  auto metadata = db_->GetPluginMetadata(blankEsm, mergeModes).value();
+
+  EXPECT_EQ(std::vector<File>({File(blankDifferentEsm)}),
+            metadata.GetLoadAfterFiles());
+  EXPECT_EQ(1, metadata.GetMessages().size());
+  EXPECT_EQ(4, metadata.GetTags().size());
+}
+
+TEST_P(DatabaseInterfaceTest,
+       getOverriddenPluginMetadataShouldReturnMasterlistListsThatAreReplaced) {
+  ASSERT_NO_THROW(GenerateMasterlist());
+  ASSERT_NO_THROW(GenerateUserlist());
+  ASSERT_NO_THROW(db_->LoadLists(masterlistPath, userlistPath_));
+
+  MetadataMergeModes mergeModes;
+  mergeModes.loadAfter = MergeMode::replace;
+  mergeModes.messages = MergeMode::replace;
+  auto metadata =
+      db_->GetOverriddenPluginMetadata(blankEsm, mergeModes).value();
+
+  EXPECT_EQ(blankEsm, metadata.GetName());
+  EXPECT_EQ(std::vector<File>({File(masterFile)}),
+            metadata.GetLoadAfterFiles());
+  EXPECT_TRUE(metadata.GetMessages().empty());
+  EXPECT_TRUE(metadata.GetTags().empty());
+}
+
+TEST_P(
+    DatabaseInterfaceTest,
+    getOverriddenPluginMetadataShouldReturnAnEmptyOptionalIfListsAreAppended) {
+  ASSERT_NO_THROW(GenerateMasterlist());
+  ASSERT_NO_THROW(GenerateUserlist());
+  ASSERT_NO_THROW(db_->LoadLists(masterlistPath, userlistPath_));
+
+  EXPECT_FALSE(
+      db_->GetOverriddenPluginMetadata(blankEsm, MetadataMergeModes()));
+}
+
+TEST_P(DatabaseInterfaceTest,
+       getEvaluatedMetadataShouldEvaluateGeneralMessagesAndPluginMetadata) {
+  ASSERT_NO_THROW(GenerateMasterlist());
+  ASSERT_NO_THROW(GenerateUserlist());
+  ASSERT_NO_THROW(db_->LoadLists(masterlistPath, userlistPath_));
+
+  auto evaluated = db_->GetEvaluatedMetadata({blankEsm});
+
+  EXPECT_EQ(std::vector<Message>({
+                Message(MessageType::say, generalUserlistMessage),
+            }),
+            evaluated.generalMessages);
+
+  ASSERT_EQ(1, evaluated.pluginMetadata.count(blankEsm));
+  auto expectedMetadata = db_->GetPluginMetadata(blankEsm, true, true).value();
+  EXPECT_EQ(expectedMetadata.GetTags(),
+            evaluated.pluginMetadata.at(blankEsm).GetTags());
+  EXPECT_EQ(expectedMetadata.GetLoadAfterFiles(),
+            evaluated.pluginMetadata.at(blankEsm).GetLoadAfterFiles());
+}
+
+TEST_P(DatabaseInterfaceTest,
+       getEvaluatedMetadataShouldNotIncludePluginsThatHaveNoMetadata) {
+  ASSERT_NO_THROW(GenerateMasterlist());
+  ASSERT_NO_THROW(db_->LoadLists(masterlistPath, ""));
+
+  auto evaluated = db_->GetEvaluatedMetadata({blankEsm, "NoMetadata.esp"});
+
+  EXPECT_EQ(1, evaluated.pluginMetadata.size());
+  EXPECT_EQ(1, evaluated.pluginMetadata.count(blankEsm));
+}
+
 TEST_P(
     DatabaseInterfaceTest,
     getPluginUserMetadataShouldReturnAnEmptyPluginMetadataObjectIfThePluginHasNoUserMetadata) {
@@ -834,6 +1056,26 @@ TEST_P(
   EXPECT_NO_THROW(db_->WriteMinimalList(minimalOutputPath_, true));
 }
 
+TEST_P(
+    DatabaseInterfaceTest,
+    writeMinimalListShouldThrowIfTheFileAlreadyExistsAndTheWriteModeIsCreate) {
+  ASSERT_NO_THROW(db_->WriteMinimalList(minimalOutputPath_, WriteMode::create));
+  ASSERT_TRUE(std::filesystem::exists(minimalOutputPath_));
+
+  EXPECT_THROW(db_->WriteMinimalList(minimalOutputPath_, WriteMode::create),
+               FileAccessError);
+}
+
+TEST_P(
+    DatabaseInterfaceTest,
+    writeMinimalListShouldReturnOkIfTheFileAlreadyExistsAndTheWriteModeIsCreateOrTruncate) {
+  ASSERT_NO_THROW(db_->WriteMinimalList(minimalOutputPath_, WriteMode::create));
+  ASSERT_TRUE(std::filesystem::exists(minimalOutputPath_));
+
+  EXPECT_NO_THROW(
+      db_->WriteMinimalList(minimalOutputPath_, WriteMode::createOrTruncate));
+}
+
 TEST_P(DatabaseInterfaceTest,
        writeMinimalListShouldThrowIfPathGivenExistsAndIsReadOnly) {
   ASSERT_NO_THROW(db_->WriteMinimalList(minimalOutputPath_, false));
@@ -856,6 +1098,42 @@ TEST_P(DatabaseInterfaceTest,
 
   EXPECT_EQ(GetExpectedMinimalContent(), GetFileContent(minimalOutputPath_));
 }
+
+TEST_P(DatabaseInterfaceTest, validateConditionShouldNotThrowForAValidCondition) {
+  EXPECT_NO_THROW(db_->ValidateCondition("file(\"" + missingEsp + "\")"));
+}
+
+TEST_P(DatabaseInterfaceTest,
+       validateConditionShouldThrowForAConditionWithInvalidSyntax) {
+  EXPECT_THROW(db_->ValidateCondition("file(\"" + missingEsp + "\""),
+               ConditionSyntaxError);
+}
+
+TEST_P(DatabaseInterfaceTest,
+       validateConditionShouldThrowForAConditionUsingAnUnknownFunction) {
+  EXPECT_THROW(db_->ValidateCondition("unknown(\"" + missingEsp + "\")"),
+               ConditionSyntaxError);
+}
+
+TEST_P(DatabaseInterfaceTest,
+       evaluateConditionsShouldReturnAResultForEachGivenCondition) {
+  auto results = db_->EvaluateConditions({
+      "file(\"" + blankEsm + "\")",
+      "file(\"" + missingEsp + "\")",
+  });
+
+  EXPECT_EQ(std::vector<bool>({true, false}), results);
+}
+
+TEST_P(DatabaseInterfaceTest,
+       clearConditionCacheShouldDiscardCachedConditionResults) {
+  db_->EvaluateConditions({"file(\"" + blankEsm + "\")"});
+  ASSERT_EQ(1, db_->GetConditionCacheStats().entries);
+
+  db_->ClearConditionCache();
+
+  EXPECT_EQ(0, db_->GetConditionCacheStats().entries);
+}
 }
 }
 
@@ -28,6 +28,7 @@ along with LOOT.  If not, see
 #include "loot/api.h"
 
 #include "tests/api/interface/api_game_operations_test.h"
+#include "tests/plugin_generator.h"
 
 namespace loot {
 namespace test {
@@ -70,6 +71,47 @@ INSTANTIATE_TEST_CASE_P(,
                                           GameType::fo4,
                                           GameType::tes5se));
 
+TEST_P(GameInterfaceTest,
+       getOrphanedUserMetadataShouldReturnNothingIfThereIsNoUserMetadata) {
+  auto orphanedMetadata = handle_->GetOrphanedUserMetadata();
+
+  EXPECT_TRUE(orphanedMetadata.plugins.empty());
+  EXPECT_TRUE(orphanedMetadata.groups.empty());
+  EXPECT_TRUE(orphanedMetadata.pluginsInUndefinedGroups.empty());
+}
+
+TEST_P(GameInterfaceTest,
+       getOrphanedUserMetadataShouldReturnMissingPluginsAndUndefinedGroups) {
+  handle_->GetDatabase()->SetPluginUserMetadata(PluginMetadata(blankEsm));
+  handle_->GetDatabase()->SetPluginUserMetadata(PluginMetadata(missingEsp));
+  handle_->GetDatabase()->SetPluginUserMetadata(
+      PluginMetadata("Blank.*\\.esp"));
+  handle_->GetDatabase()->SetUserGroups({
+      Group("group1", {"default", "missing group"}),
+  });
+
+  auto orphanedMetadata = handle_->GetOrphanedUserMetadata();
+
+  EXPECT_EQ(std::vector<std::string>({missingEsp}), orphanedMetadata.plugins);
+  EXPECT_EQ(std::vector<std::string>({"missing group"}),
+            orphanedMetadata.groups);
+}
+
+TEST_P(GameInterfaceTest,
+       getOrphanedUserMetadataShouldReturnPluginsInUndefinedGroups) {
+  PluginMetadata plugin(blankEsm);
+  plugin.SetGroup("missing group");
+  handle_->GetDatabase()->SetPluginUserMetadata(plugin);
+
+  auto orphanedMetadata = handle_->GetOrphanedUserMetadata();
+
+  EXPECT_TRUE(orphanedMetadata.plugins.empty());
+  EXPECT_EQ(std::vector<std::string>({"missing group"}),
+            orphanedMetadata.groups);
+  EXPECT_EQ(std::vector<std::string>({blankEsm}),
+            orphanedMetadata.pluginsInUndefinedGroups);
+}
+
 TEST_P(GameInterfaceTest, isValidPluginShouldReturnTrueForAValidPlugin) {
   EXPECT_TRUE(handle_->IsValidPlugin(blankEsm));
 }
@@ -91,6 +133,32 @@ TEST_P(GameInterfaceTest, isValidPluginShouldReturnFalseForAnEmptyFile) {
   EXPECT_FALSE(handle_->IsValidPlugin(emptyFile));
 }
 
+TEST_P(GameInterfaceTest,
+       getGameVersionShouldReturnAnEmptyOptionalIfNoVersionHasBeenSet) {
+  EXPECT_FALSE(handle_->GetGameVersion().has_value());
+}
+
+TEST_P(GameInterfaceTest, getGameVersionShouldReturnTheVersionThatWasSet) {
+  handle_->SetGameVersion("1.6.1130.0");
+
+  EXPECT_EQ("1.6.1130.0", handle_->GetGameVersion());
+}
+
+TEST_P(GameInterfaceTest, setGameVersionShouldUnsetTheVersionIfGivenAnEmptyString) {
+  handle_->SetGameVersion("1.6.1130.0");
+  handle_->SetGameVersion("");
+
+  EXPECT_FALSE(handle_->GetGameVersion().has_value());
+}
+
+TEST_P(GameInterfaceTest, setGameVersionShouldThrowIfGivenAnInvalidVersion) {
+  EXPECT_THROW(handle_->SetGameVersion("1.6.x"), std::invalid_argument);
+  EXPECT_THROW(handle_->SetGameVersion("1..6"), std::invalid_argument);
+  EXPECT_THROW(handle_->SetGameVersion("1.99999999999999999999999"),
+               std::invalid_argument);
+  EXPECT_FALSE(handle_->GetGameVersion().has_value());
+}
+
 TEST_P(
     GameInterfaceTest,
     loadPluginsWithHeadersOnlyTrueShouldLoadTheHeadersOfAllInstalledPlugins) {
@@ -141,6 +209,211 @@ TEST_P(GameInterfaceTest,
   EXPECT_TRUE(handle_->GetLoadedPlugins().empty());
 }
 
+TEST_P(GameInterfaceTest, getMasterInfoShouldThrowIfThePluginIsNotLoaded) {
+  EXPECT_THROW(handle_->GetMasterInfo(blankMasterDependentEsp),
+               std::invalid_argument);
+}
+
+TEST_P(GameInterfaceTest,
+       getMasterInfoShouldGiveTheInstalledLightAndActiveStatesOfEachMaster) {
+  handle_->LoadCurrentLoadOrderState();
+  handle_->LoadPlugins({blankEsm, blankMasterDependentEsp}, true);
+
+  auto masters = handle_->GetMasterInfo(blankMasterDependentEsp);
+
+  ASSERT_EQ(1, masters.size());
+  EXPECT_EQ(blankEsm, masters[0].name);
+  EXPECT_TRUE(masters[0].isInstalled);
+  EXPECT_FALSE(masters[0].isLightPlugin.value());
+  EXPECT_TRUE(masters[0].isActive);
+}
+
+TEST_P(GameInterfaceTest,
+       getMasterInfoShouldNotGiveTheLightStateOfAMasterThatIsNotLoaded) {
+  handle_->LoadCurrentLoadOrderState();
+  handle_->LoadPlugins({blankMasterDependentEsp}, true);
+
+  auto masters = handle_->GetMasterInfo(blankMasterDependentEsp);
+
+  ASSERT_EQ(1, masters.size());
+  EXPECT_TRUE(masters[0].isInstalled);
+  EXPECT_FALSE(masters[0].isLightPlugin.has_value());
+}
+
+TEST_P(GameInterfaceTest,
+       getMasterInfoShouldGiveAMasterThatIsNotPresentAsNotInstalled) {
+  handle_->LoadCurrentLoadOrderState();
+  handle_->LoadPlugins({blankMasterDependentEsp}, true);
+  std::filesystem::remove(dataPath / blankEsm);
+
+  auto masters = handle_->GetMasterInfo(blankMasterDependentEsp);
+
+  ASSERT_EQ(1, masters.size());
+  EXPECT_EQ(blankEsm, masters[0].name);
+  EXPECT_FALSE(masters[0].isInstalled);
+}
+
+TEST_P(GameInterfaceTest,
+       getMasterDependencyGraphShouldThrowIfThePluginIsNotLoaded) {
+  EXPECT_THROW(handle_->GetMasterDependencyGraph(blankMasterDependentEsp),
+               std::invalid_argument);
+}
+
+TEST_P(GameInterfaceTest,
+       getMasterDependencyGraphShouldIncludeTransitiveAndMissingMasters) {
+  GeneratedPlugin generated;
+  generated.masters = {blankMasterDependentEsm, "Missing.esm"};
+  PluginWriter(GetParam()).Write(generated, dataPath / "Generated.esp");
+
+  handle_->LoadCurrentLoadOrderState();
+  handle_->LoadPlugins({blankEsm, blankMasterDependentEsm, "Generated.esp"},
+                       true);
+
+  auto nodes = handle_->GetMasterDependencyGraph("Generated.esp");
+
+  ASSERT_EQ(4, nodes.size());
+
+  EXPECT_EQ("Generated.esp", nodes[0].name);
+  EXPECT_TRUE(nodes[0].isInstalled);
+  EXPECT_TRUE(nodes[0].isLoaded);
+  EXPECT_FALSE(nodes[0].isActive);
+  EXPECT_EQ(generated.masters, nodes[0].masters);
+
+  EXPECT_EQ(blankMasterDependentEsm, nodes[1].name);
+  EXPECT_TRUE(nodes[1].isInstalled);
+  EXPECT_TRUE(nodes[1].isLoaded);
+  EXPECT_EQ(std::vector<std::string>({blankEsm}), nodes[1].masters);
+
+  EXPECT_EQ("Missing.esm", nodes[2].name);
+  EXPECT_FALSE(nodes[2].isInstalled);
+  EXPECT_FALSE(nodes[2].isLoaded);
+  EXPECT_FALSE(nodes[2].isActive);
+  EXPECT_TRUE(nodes[2].masters.empty());
+
+  EXPECT_EQ(blankEsm, nodes[3].name);
+  EXPECT_TRUE(nodes[3].isActive);
+  EXPECT_TRUE(nodes[3].masters.empty());
+}
+
+TEST_P(GameInterfaceTest,
+       getMasterDependencyGraphShouldIncludeAMasterSharedByManyPluginsOnce) {
+  GeneratedPlugin generated;
+  generated.masters = {blankEsm, blankMasterDependentEsm};
+  PluginWriter(GetParam()).Write(generated, dataPath / "Generated.esp");
+
+  handle_->LoadPlugins({blankEsm, blankMasterDependentEsm, "Generated.esp"},
+                       true);
+
+  auto nodes = handle_->GetMasterDependencyGraph("Generated.esp");
+
+  ASSERT_EQ(3, nodes.size());
+  EXPECT_EQ("Generated.esp", nodes[0].name);
+  EXPECT_EQ(blankEsm, nodes[1].name);
+  EXPECT_EQ(blankMasterDependentEsm, nodes[2].name);
+  EXPECT_EQ(std::vector<std::string>({blankEsm}), nodes[2].masters);
+}
+
+TEST_P(GameInterfaceTest,
+       getEffectiveBashTagsShouldThrowIfThePluginIsNotLoaded) {
+  EXPECT_THROW(handle_->GetEffectiveBashTags(blankEsm), std::invalid_argument);
+}
+
+TEST_P(GameInterfaceTest,
+       getEffectiveBashTagsShouldCombineMetadataAndBashTagsFileTags) {
+  ASSERT_NO_THROW(GenerateMasterlist());
+  ASSERT_NO_THROW(handle_->GetDatabase()->LoadLists(masterlistPath, ""));
+
+  PluginMetadata userMetadata(blankEsm);
+  userMetadata.SetTags({Tag("Actors.AIData", false), Tag("Relev")});
+  handle_->GetDatabase()->SetPluginUserMetadata(userMetadata);
+
+  std::filesystem::create_directory(dataPath / "BashTags");
+  std::ofstream out(dataPath / "BashTags" / "Blank.txt");
+  out << "C.Water, -Relev";
+  out.close();
+
+  handle_->LoadCurrentLoadOrderState();
+  handle_->LoadPlugins({blankEsm}, true);
+
+  auto tags = handle_->GetEffectiveBashTags(blankEsm);
+
+  EXPECT_EQ(std::vector<std::string>({"Actors.ACBS", "C.Water"}), tags.added);
+  EXPECT_EQ(std::vector<std::string>({"Actors.AIData", "Relev"}),
+            tags.removed);
+}
+
+TEST_P(GameInterfaceTest, saveArchiveCacheShouldWriteAFileThatCanBeLoaded) {
+  const auto cachePath = localPath / "archives.cache";
+
+  EXPECT_NO_THROW(handle_->SaveArchiveCache(cachePath));
+  EXPECT_TRUE(std::filesystem::exists(cachePath));
+  EXPECT_NO_THROW(handle_->LoadArchiveCache(cachePath));
+}
+
+TEST_P(GameInterfaceTest, loadArchiveCacheShouldThrowIfTheFileDoesNotExist) {
+  EXPECT_THROW(handle_->LoadArchiveCache(localPath / "missing.cache"),
+               FileAccessError);
+}
+
+TEST_P(GameInterfaceTest,
+       getDiagnosticsReportShouldIncludeTheLoadOrderAndLoadedPlugins) {
+  handle_->LoadCurrentLoadOrderState();
+  handle_->LoadPlugins({blankEsm}, true);
+
+  auto report = handle_->GetDiagnosticsReport();
+
+  EXPECT_EQ('{', report.front());
+  EXPECT_EQ('}', report.back());
+  EXPECT_NE(std::string::npos,
+            report.find("{\"name\":\"" + blankEsp + "\",\"active\":false}"));
+  EXPECT_NE(std::string::npos,
+            report.find("\"plugins\":[{\"name\":\"" + blankEsm + "\""));
+}
+
+TEST_P(GameInterfaceTest, getDiagnosticsReportShouldIncludeTheGameAndListPaths) {
+  ASSERT_NO_THROW(GenerateMasterlist());
+  ASSERT_NO_THROW(handle_->GetDatabase()->LoadLists(masterlistPath, ""));
+
+  auto report = handle_->GetDiagnosticsReport();
+
+  EXPECT_NE(std::string::npos, report.find("\"gamePath\":\""));
+  EXPECT_NE(std::string::npos, report.find("\"localPath\":\""));
+  EXPECT_NE(std::string::npos, report.find("\"dataPath\":\""));
+  EXPECT_NE(std::string::npos, report.find("\"masterlistPath\":\""));
+  EXPECT_NE(std::string::npos, report.find("\"userlistPath\":null"));
+}
+
+TEST_P(GameInterfaceTest,
+       getOperationTimingsShouldReturnAnEmptyVectorIfNothingHasBeenTimed) {
+  EXPECT_TRUE(handle_->GetOperationTimings().empty());
+}
+
+TEST_P(GameInterfaceTest,
+       getOperationTimingsShouldIncludePluginLoadingAndSortingPhases) {
+  handle_->LoadCurrentLoadOrderState();
+  handle_->SortPlugins({masterFile, blankEsm, blankEsp});
+
+  std::vector<std::string> operations;
+  for (const auto& timing : handle_->GetOperationTimings()) {
+    operations.push_back(timing.operation);
+  }
+
+  EXPECT_EQ(std::vector<std::string>({
+                "LoadPlugins",
+                "SortPlugins/AddPluginVertices",
+                "SortPlugins/AddSpecificEdges",
+                "SortPlugins/AddHardcodedPluginEdges",
+                "SortPlugins/AddGroupEdges",
+                "SortPlugins/AddOverlapEdges",
+                "SortPlugins/AddTieBreakEdges",
+                "SortPlugins/CheckForCycles",
+                "SortPlugins/TopologicalSort",
+                "SortPlugins/MinimiseMovement",
+                "SortPlugins",
+            }),
+            operations);
+}
+
 TEST_P(GameInterfaceTest, sortPluginsShouldSucceedIfPassedValidArguments) {
   std::vector<std::string> expectedOrder = {
       masterFile,
@@ -188,6 +461,149 @@ TEST_P(GameInterfaceTest, sortPluginsShouldSucceedIfPassedValidArguments) {
   EXPECT_EQ(expectedOrder, actualOrder);
 }
 
+TEST_P(GameInterfaceTest,
+       sortPluginsShouldKeepTheRelativeOrderOfUnconstrainedPlugins) {
+  handle_->LoadCurrentLoadOrderState();
+
+  std::vector<std::string> pluginsToSort({
+      masterFile,
+      blankEsm,
+      blankDifferentEsp,
+      blankEsp,
+  });
+
+  EXPECT_EQ(pluginsToSort, handle_->SortPlugins(pluginsToSort));
+  EXPECT_EQ(0, handle_->GetLastSortMovedPluginCount());
+}
+
+TEST_P(GameInterfaceTest,
+       getLastSortMovedPluginCountShouldReturnZeroIfPluginsHaveNotBeenSorted) {
+  EXPECT_EQ(0, handle_->GetLastSortMovedPluginCount());
+}
+
+TEST_P(GameInterfaceTest,
+       getLastSortMovedPluginCountShouldCountThePluginsMovedByTheLastSort) {
+  handle_->LoadCurrentLoadOrderState();
+
+  handle_->SortPlugins({blankEsp, masterFile, blankEsm});
+
+  EXPECT_EQ(1, handle_->GetLastSortMovedPluginCount());
+}
+
+TEST_P(GameInterfaceTest,
+       sortPluginsShouldReportEachPhaseToTheProgressCallback) {
+  handle_->LoadCurrentLoadOrderState();
+
+  std::vector<std::string> phases;
+  handle_->SortPlugins({masterFile, blankEsm, blankEsp},
+                       [&](const std::string& phase,
+                           size_t phaseIndex,
+                           size_t phaseCount) {
+                         EXPECT_EQ(phases.size(), phaseIndex);
+                         EXPECT_EQ(10, phaseCount);
+                         phases.push_back(phase);
+                         return true;
+                       });
+
+  EXPECT_EQ(std::vector<std::string>({
+                "LoadPlugins",
+                "AddPluginVertices",
+                "AddSpecificEdges",
+                "AddHardcodedPluginEdges",
+                "AddGroupEdges",
+                "AddOverlapEdges",
+                "AddTieBreakEdges",
+                "CheckForCycles",
+                "TopologicalSort",
+                "MinimiseMovement",
+            }),
+            phases);
+}
+
+TEST_P(GameInterfaceTest,
+       sortPluginsShouldThrowIfTheProgressCallbackReturnsFalse) {
+  handle_->LoadCurrentLoadOrderState();
+
+  try {
+    handle_->SortPlugins({masterFile, blankEsm, blankEsp},
+                         [](const std::string& phase, size_t, size_t) {
+                           return phase != "AddGroupEdges";
+                         });
+    FAIL();
+  } catch (const SortingCancelledError& e) {
+    EXPECT_EQ("AddGroupEdges", e.GetPhase());
+  }
+}
+
+TEST_P(GameInterfaceTest,
+       verifyLoadOrderShouldReturnNoViolationsForAValidLoadOrder) {
+  handle_->LoadCurrentLoadOrderState();
+  handle_->LoadPlugins({blankEsm, blankMasterDependentEsm}, true);
+
+  EXPECT_TRUE(
+      handle_->VerifyLoadOrder({blankEsm, blankMasterDependentEsm}).empty());
+}
+
+TEST_P(GameInterfaceTest,
+       verifyLoadOrderShouldReturnAViolationIfAPluginLoadsBeforeItsMaster) {
+  handle_->LoadCurrentLoadOrderState();
+  handle_->LoadPlugins({blankEsm, blankMasterDependentEsm}, true);
+
+  auto violations =
+      handle_->VerifyLoadOrder({blankMasterDependentEsm, blankEsm});
+
+  ASSERT_EQ(1, violations.size());
+  EXPECT_EQ(blankMasterDependentEsm, violations[0].plugin);
+  EXPECT_EQ(blankEsm, violations[0].mustLoadAfter);
+  EXPECT_EQ(EdgeType::master, violations[0].type);
+}
+
+TEST_P(GameInterfaceTest,
+       findRedundantUserRulesShouldReturnAnEmptyVectorIfThereIsNoUserMetadata) {
+  handle_->LoadCurrentLoadOrderState();
+  handle_->LoadPlugins({blankEsm, blankMasterDependentEsp}, true);
+
+  EXPECT_TRUE(
+      handle_->FindRedundantUserRules({blankEsm, blankMasterDependentEsp})
+          .empty());
+}
+
+TEST_P(GameInterfaceTest,
+       findRedundantUserRulesShouldReturnUserMetadataThatIsAlreadyImplied) {
+  handle_->LoadCurrentLoadOrderState();
+  handle_->LoadPlugins(
+      {blankEsm, blankDifferentEsm, blankDifferentEsp, blankMasterDependentEsp},
+      true);
+
+  PluginMetadata metadata(blankMasterDependentEsp);
+  metadata.SetLoadAfterFiles({
+      File(blankEsm),
+      File(blankDifferentEsm),
+      File(blankDifferentEsp),
+  });
+  metadata.SetRequirements({File(blankEsm), File(blankDifferentEsm)});
+  metadata.SetGroup("default");
+  handle_->GetDatabase()->SetPluginUserMetadata(metadata);
+
+  auto rules = handle_->FindRedundantUserRules({
+      blankEsm,
+      blankDifferentEsm,
+      blankDifferentEsp,
+      blankMasterDependentEsp,
+  });
+
+  ASSERT_EQ(4, rules.size());
+  EXPECT_EQ(blankMasterDependentEsp, rules[0].plugin);
+  EXPECT_EQ(blankEsm, rules[0].target);
+  EXPECT_EQ(EdgeType::userLoadAfter, rules[0].type);
+  EXPECT_EQ(blankDifferentEsm, rules[1].target);
+  EXPECT_EQ(EdgeType::userLoadAfter, rules[1].type);
+  EXPECT_EQ(blankEsm, rules[2].target);
+  EXPECT_EQ(EdgeType::userRequirement, rules[2].type);
+  EXPECT_EQ("default", rules[3].target);
+  EXPECT_EQ(EdgeType::group, rules[3].type);
+}
+
 TEST_P(GameInterfaceTest,
        isPluginActiveShouldReturnFalseIfTheGivenPluginIsNotActive) {
   handle_->LoadCurrentLoadOrderState();
@@ -200,6 +616,35 @@ TEST_P(GameInterfaceTest,
   EXPECT_FALSE(handle_->IsPluginActive(blankEsp));
 }
 
+TEST_P(GameInterfaceTest,
+       getPluginSlotUsageShouldCountActivePluginsAndGiveTheGameSlotLimits) {
+  handle_->LoadCurrentLoadOrderState();
+
+  auto usage = handle_->GetPluginSlotUsage();
+
+  EXPECT_EQ(3, usage.fullPlugins);
+  EXPECT_EQ(0, usage.lightPlugins);
+  if (GetParam() == GameType::tes5se || GetParam() == GameType::tes5vr ||
+      GetParam() == GameType::fo4 || GetParam() == GameType::fo4vr) {
+    EXPECT_EQ(254, usage.fullPluginSlots);
+    EXPECT_EQ(4096, usage.lightPluginSlots);
+  } else {
+    EXPECT_EQ(255, usage.fullPluginSlots);
+    EXPECT_EQ(0, usage.lightPluginSlots);
+  }
+}
+
+TEST_P(GameInterfaceTest,
+       getPluginSlotUsageShouldGiveNoLightPluginSlotsForAnOldGameVersion) {
+  handle_->SetGameVersion("1.4.2.0");
+  handle_->LoadCurrentLoadOrderState();
+
+  auto usage = handle_->GetPluginSlotUsage();
+
+  EXPECT_EQ(255, usage.fullPluginSlots);
+  EXPECT_EQ(0, usage.lightPluginSlots);
+}
+
 TEST_P(GameInterfaceTest, getLoadOrderShouldReturnTheCurrentLoadOrder) {
   // Remove the non-ASCII duplicate plugin.
   std::filesystem::remove(dataPath / std::filesystem::u8path(nonAsciiEsm));
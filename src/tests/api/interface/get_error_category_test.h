@@ -0,0 +1,82 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_TESTS_API_INTERFACE_GET_ERROR_CATEGORY_TEST
+#define LOOT_TESTS_API_INTERFACE_GET_ERROR_CATEGORY_TEST
+
+#include "loot/api.h"
+
+#include <gtest/gtest.h>
+
+#include <regex>
+
+namespace loot {
+namespace test {
+TEST(GetErrorCategory, shouldCategoriseSortingErrors) {
+  EXPECT_EQ(ErrorCategory::sorting,
+            GetErrorCategory(SortingCancelledError("phase")));
+  EXPECT_EQ(ErrorCategory::sorting,
+            GetErrorCategory(UndefinedGroupError("group")));
+  EXPECT_EQ(ErrorCategory::sorting,
+            GetErrorCategory(CyclicInteractionError({})));
+}
+
+TEST(GetErrorCategory, shouldCategoriseAConditionSyntaxErrorAsACondition) {
+  EXPECT_EQ(ErrorCategory::condition,
+            GetErrorCategory(ConditionSyntaxError("error")));
+}
+
+TEST(GetErrorCategory, shouldCategoriseAnArchiveParseErrorAsAParseError) {
+  EXPECT_EQ(ErrorCategory::parse,
+            GetErrorCategory(ArchiveParseError("Blank.bsa", "error")));
+}
+
+TEST(GetErrorCategory, shouldCategoriseFileAndSystemErrorsAsIoErrors) {
+  EXPECT_EQ(ErrorCategory::io, GetErrorCategory(FileAccessError("error")));
+  EXPECT_EQ(ErrorCategory::io,
+            GetErrorCategory(std::system_error(1, libloadorder_category())));
+}
+
+TEST(GetErrorCategory, shouldCategoriseAGitStateErrorAsAStateError) {
+  EXPECT_EQ(ErrorCategory::state, GetErrorCategory(GitStateError("error")));
+}
+
+TEST(GetErrorCategory, shouldCategoriseAnInvalidArgumentAsAnInvalidArgument) {
+  EXPECT_EQ(ErrorCategory::invalidArgument,
+            GetErrorCategory(std::invalid_argument("error")));
+}
+
+TEST(GetErrorCategory, shouldCategoriseARegexErrorAsAnInvalidArgument) {
+  const std::regex_error error(std::regex_constants::error_brack);
+
+  EXPECT_EQ(ErrorCategory::invalidArgument, GetErrorCategory(error));
+}
+
+TEST(GetErrorCategory, shouldReturnNoValueForOtherExceptions) {
+  EXPECT_FALSE(GetErrorCategory(std::runtime_error("error")));
+}
+}
+}
+
+#endif
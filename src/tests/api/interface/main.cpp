@@ -25,10 +25,16 @@
 #include <gtest/gtest.h>
 
 #include "loot/api.h"
+#include "tests/api/interface/capabilities_test.h"
+#include "tests/api/interface/capi_test.h"
+#include "tests/api/interface/condition_language_test.h"
 #include "tests/api/interface/create_game_handle_test.h"
 #include "tests/api/interface/database_interface_test.h"
 #include "tests/api/interface/game_interface_test.h"
+#include "tests/api/interface/get_build_info_test.h"
+#include "tests/api/interface/get_error_category_test.h"
 #include "tests/api/interface/is_compatible_test.h"
+#include "tests/api/interface/plugin_filename_functions_test.h"
 
 int main(int argc, char **argv) {
   ::testing::InitGoogleTest(&argc, argv);
@@ -137,5 +143,158 @@ TEST(SetLoggingCallback, shouldNotBreakLoggingIfPassedLambdaFunctionGoesOutOfSco
     SetLoggingCallback([](LogLevel, const char *) {});
   }
 }
+
+class SetLoggingCallbackTest : public ::testing::Test {
+protected:
+  void TearDown() override {
+    SetLoggingCallback([](LogLevel, const char*) {});
+  }
+};
+
+TEST_F(SetLoggingCallbackTest, shouldAcceptACallbackThatTakesALogRecord) {
+  std::vector<LogRecord> records;
+  auto before = std::chrono::system_clock::now();
+  SetLoggingCallback([&](const LogRecord& record) {
+    records.push_back(record);
+  });
+
+  try {
+    CreateGameHandle(GameType::tes4, "dummy");
+    FAIL();
+  } catch (...) {
+    ASSERT_EQ(1, records.size());
+    EXPECT_EQ(LogLevel::info, records[0].level);
+    EXPECT_EQ("loot_api_logger", records[0].target);
+    EXPECT_LE(before, records[0].timestamp);
+    EXPECT_EQ(
+        "Attempting to create a game handle with game path \"dummy\" "
+        "and local path \"\"",
+        records[0].message);
+  }
+}
+
+TEST_F(SetLoggingCallbackTest,
+       shouldNotCallTheCallbackForMessagesBelowTheMinimumLevel) {
+  std::string loggedMessages;
+  SetLoggingCallback(
+      [&](LogLevel level, const char* string) {
+        loggedMessages += std::string(string);
+      },
+      LogLevel::warning);
+
+  try {
+    CreateGameHandle(GameType::tes4, "dummy");
+    FAIL();
+  } catch (...) {
+    EXPECT_TRUE(loggedMessages.empty());
+  }
+}
+
+class SetLogThrottlingTest : public ::testing::Test {
+protected:
+  void TearDown() override {
+    SetLogThrottling(false, 0);
+    SetLoggingCallback([](LogLevel, const char*) {});
+  }
+};
+
+TEST_F(SetLogThrottlingTest, shouldCollapseRepeatedMessagesIntoASummary) {
+  std::vector<std::string> loggedMessages;
+  SetLoggingCallback([&](LogLevel level, const char* string) {
+    loggedMessages.push_back(string);
+  });
+  SetLogThrottling(true, 0);
+
+  for (const auto& path : {"dummy", "dummy", "dummy", "dummy2"}) {
+    try {
+      CreateGameHandle(GameType::tes4, path);
+      FAIL();
+    } catch (...) {
+    }
+  }
+
+  ASSERT_EQ(3, loggedMessages.size());
+  EXPECT_EQ("The previous message was repeated 2 more times.",
+            loggedMessages[1]);
+  EXPECT_EQ(
+      "Attempting to create a game handle with game path \"dummy2\" "
+      "and local path \"\"",
+      loggedMessages[2]);
+}
+
+TEST_F(SetLogThrottlingTest, shouldSuppressMessagesOverTheRateLimit) {
+  std::vector<std::string> loggedMessages;
+  SetLoggingCallback([&](LogLevel level, const char* string) {
+    loggedMessages.push_back(string);
+  });
+  SetLogThrottling(false, 1);
+
+  for (const auto& path : {"dummy1", "dummy2", "dummy3"}) {
+    try {
+      CreateGameHandle(GameType::tes4, path);
+      FAIL();
+    } catch (...) {
+    }
+  }
+
+  ASSERT_EQ(2, loggedMessages.size());
+  EXPECT_EQ("2 messages were suppressed by the log rate limit.",
+            loggedMessages[1]);
+}
+
+TEST(SetLogBufferCapacity, shouldBufferMessagesEvenIfNoCallbackIsSet) {
+  SetLogBufferCapacity(10);
+
+  try {
+    CreateGameHandle(GameType::tes4, "dummy");
+    FAIL();
+  } catch (...) {
+    auto records = GetBufferedLogRecords();
+    SetLogBufferCapacity(0);
+
+    ASSERT_FALSE(records.empty());
+    EXPECT_EQ(
+        "Attempting to create a game handle with game path \"dummy\" "
+        "and local path \"\"",
+        records.back().message);
+  }
+}
+
+TEST(SetLogBufferCapacity, shouldKeepOnlyTheMostRecentMessages) {
+  SetLogBufferCapacity(1);
+
+  try {
+    CreateGameHandle(GameType::tes4, "dummy1");
+    FAIL();
+  } catch (...) {
+  }
+
+  try {
+    CreateGameHandle(GameType::tes4, "dummy2");
+    FAIL();
+  } catch (...) {
+    auto records = GetBufferedLogRecords();
+    SetLogBufferCapacity(0);
+
+    ASSERT_EQ(1, records.size());
+    EXPECT_EQ(
+        "Attempting to create a game handle with game path \"dummy2\" "
+        "and local path \"\"",
+        records[0].message);
+  }
+}
+
+TEST(SetLogBufferCapacity, shouldDiscardBufferedMessagesIfSetToZero) {
+  SetLogBufferCapacity(10);
+
+  try {
+    CreateGameHandle(GameType::tes4, "dummy");
+    FAIL();
+  } catch (...) {
+    SetLogBufferCapacity(0);
+
+    EXPECT_TRUE(GetBufferedLogRecords().empty());
+  }
+}
 }
 }
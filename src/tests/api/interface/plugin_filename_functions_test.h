@@ -0,0 +1,74 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_TESTS_API_INTERFACE_PLUGIN_FILENAME_FUNCTIONS_TEST
+#define LOOT_TESTS_API_INTERFACE_PLUGIN_FILENAME_FUNCTIONS_TEST
+
+#include "loot/api.h"
+
+#include <gtest/gtest.h>
+
+namespace loot {
+namespace test {
+TEST(HasPluginFileExtension, shouldBeTrueForEspAndEsmFilesForAllGames) {
+  for (const auto game : {GameType::tes3, GameType::tes4, GameType::fo4}) {
+    EXPECT_TRUE(HasPluginFileExtension(game, "Blank.esp"));
+    EXPECT_TRUE(HasPluginFileExtension(game, "Blank.ESM"));
+  }
+}
+
+TEST(HasPluginFileExtension,
+     shouldOnlyBeTrueForEslFilesForGamesThatSupportThem) {
+  EXPECT_FALSE(HasPluginFileExtension(GameType::tes5, "Blank.esl"));
+  EXPECT_TRUE(HasPluginFileExtension(GameType::tes5se, "Blank.esl"));
+  EXPECT_TRUE(HasPluginFileExtension(GameType::fo4, "Blank.esl"));
+}
+
+TEST(HasPluginFileExtension, shouldIgnoreAGhostExtension) {
+  EXPECT_TRUE(HasPluginFileExtension(GameType::tes4, "Blank.esp.ghost"));
+  EXPECT_FALSE(HasPluginFileExtension(GameType::tes4, "Blank.bsa.ghost"));
+}
+
+TEST(HasPluginFileExtension, shouldBeFalseForOtherExtensions) {
+  EXPECT_FALSE(HasPluginFileExtension(GameType::tes4, "Blank.bsa"));
+  EXPECT_FALSE(HasPluginFileExtension(GameType::tes4, "Blank.esp.bak"));
+}
+
+TEST(IsGhostedFilename, shouldBeTrueIfTheFilenameEndsInDotGhost) {
+  EXPECT_TRUE(IsGhostedFilename("Blank.esp.ghost"));
+  EXPECT_TRUE(IsGhostedFilename("Blank.esp.GHOST"));
+  EXPECT_FALSE(IsGhostedFilename("Blank.esp"));
+}
+
+TEST(TrimGhostExtension, shouldRemoveAGhostExtension) {
+  EXPECT_EQ("Blank.esp", TrimGhostExtension("Blank.esp.Ghost"));
+}
+
+TEST(TrimGhostExtension, shouldNotChangeAnUnghostedFilename) {
+  EXPECT_EQ("Blank.esp", TrimGhostExtension("Blank.esp"));
+}
+}
+}
+
+#endif
@@ -0,0 +1,184 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_TESTS_API_INTERNALS_ARCHIVE_ARCHIVE_CACHE_TEST
+#define LOOT_TESTS_API_INTERNALS_ARCHIVE_ARCHIVE_CACHE_TEST
+
+#include "api/archive/archive_cache.h"
+
+#include "api/archive/binary_io.h"
+
+#include "loot/exception/file_access_error.h"
+#include "tests/api/internals/archive/bsa_test.h"
+#include "tests/common_game_test_fixture.h"
+
+namespace loot {
+namespace test {
+class ArchiveCacheTest : public CommonGameTestFixture {
+protected:
+  ArchiveCacheTest() :
+      archivePath(dataPath / "Test.bsa"),
+      cachePath(localPath / "archives.cache") {}
+
+  // Overwrite the archive with the same number of bytes and restore its last
+  // write time, so that only a cache miss would notice the change.
+  void CorruptArchiveUndetectably() {
+    const auto size = std::filesystem::file_size(archivePath);
+    const auto lastWriteTime = std::filesystem::last_write_time(archivePath);
+
+    std::ofstream out(archivePath, std::ios::binary);
+    out << std::string(size, '\0');
+    out.close();
+
+    std::filesystem::last_write_time(archivePath, lastWriteTime);
+  }
+
+  const std::filesystem::path archivePath;
+  const std::filesystem::path cachePath;
+};
+
+// Pass an empty first argument, as it's a prefix for the test instantation,
+// but we only have the one so no prefix is necessary.
+// Just test with one game because the cache is independent of the game.
+INSTANTIATE_TEST_CASE_P(, ArchiveCacheTest, ::testing::Values(GameType::tes5));
+
+TEST_P(ArchiveCacheTest, getAssetsShouldThrowIfTheArchiveDoesNotExist) {
+  ArchiveCache cache;
+
+  EXPECT_THROW(cache.GetAssets(dataPath / "missing.bsa"), FileAccessError);
+}
+
+TEST_P(ArchiveCacheTest,
+       getAssetsShouldReturnCachedAssetsIfTheArchiveHasNotChanged) {
+  WriteTestBsa(archivePath, 0x3);
+
+  ArchiveCache cache;
+  ASSERT_EQ(2, cache.GetAssets(archivePath).size());
+
+  CorruptArchiveUndetectably();
+
+  auto assets = cache.GetAssets(archivePath);
+  ASSERT_EQ(2, assets.size());
+  EXPECT_EQ("a.nif", assets[0].fileName.value());
+}
+
+TEST_P(ArchiveCacheTest, getAssetsShouldReadTheArchiveAgainIfItHasChanged) {
+  WriteTestBsa(archivePath, 0x3);
+
+  ArchiveCache cache;
+  ASSERT_TRUE(cache.GetAssets(archivePath)[0].fileName.has_value());
+
+  WriteTestBsa(archivePath, 0);
+
+  auto assets = cache.GetAssets(archivePath);
+  ASSERT_EQ(2, assets.size());
+  EXPECT_FALSE(assets[0].fileName.has_value());
+}
+
+TEST_P(ArchiveCacheTest, loadShouldReadAssetsWrittenBySave) {
+  WriteTestBsa(archivePath, 0x3);
+
+  ArchiveCache cache;
+  cache.GetAssets(archivePath);
+  cache.Save(cachePath);
+
+  CorruptArchiveUndetectably();
+
+  ArchiveCache loadedCache;
+  loadedCache.Load(cachePath);
+
+  auto assets = loadedCache.GetAssets(archivePath);
+  ASSERT_EQ(2, assets.size());
+  EXPECT_EQ(0x1122334455667788, assets[0].folderHash);
+  EXPECT_EQ(0xA, assets[0].fileHash);
+  EXPECT_EQ("meshes", assets[0].folderName.value());
+  EXPECT_EQ("a.nif", assets[0].fileName.value());
+  EXPECT_EQ("b.nif", assets[1].fileName.value());
+}
+
+TEST_P(ArchiveCacheTest, loadShouldThrowIfTheFileIsNotAnArchiveCache) {
+  ArchiveCache cache;
+
+  EXPECT_THROW(cache.Load(dataPath / blankEsm), FileAccessError);
+}
+
+TEST_P(ArchiveCacheTest,
+       loadShouldThrowIfTheFileWasWrittenByADifferentBuild) {
+  ArchiveCache cache;
+  cache.Save(cachePath);
+
+  // Replace the build identifier that follows the magic and version.
+  std::ifstream in(cachePath, std::ios::binary);
+  std::string header(10, '\0');
+  in.read(&header[0], header.size());
+  in.close();
+
+  const std::string buildId = "0.0.0+unknown";
+  std::ofstream out(cachePath, std::ios::binary);
+  out.write(header.data(), header.size());
+  WriteLittleEndian<uint32_t>(out, static_cast<uint32_t>(buildId.size()));
+  out.write(buildId.data(), buildId.size());
+  WriteLittleEndian<uint32_t>(out, 0);
+  out.close();
+
+  ArchiveCache loadedCache;
+  EXPECT_THROW(loadedCache.Load(cachePath), FileAccessError);
+}
+
+TEST_P(ArchiveCacheTest, loadShouldThrowIfAnAssetCountIsLargerThanTheFile) {
+  ArchiveCache cache;
+  cache.Save(cachePath);
+
+  // Keep everything before the entry count, which is the last field of an
+  // empty cache.
+  const auto headerSize = std::filesystem::file_size(cachePath) - 4;
+  std::ifstream in(cachePath, std::ios::binary);
+  std::string header(headerSize, '\0');
+  in.read(&header[0], header.size());
+  in.close();
+
+  const std::string path = "Test.bsa";
+  std::ofstream out(cachePath, std::ios::binary);
+  out.write(header.data(), header.size());
+  WriteLittleEndian<uint32_t>(out, 1);
+  WriteLittleEndian<uint32_t>(out, static_cast<uint32_t>(path.size()));
+  out.write(path.data(), path.size());
+  WriteLittleEndian<uint64_t>(out, 0);
+  WriteLittleEndian<uint64_t>(out, 0);
+  WriteLittleEndian<uint32_t>(out, 0xFFFFFFFF);
+  out.close();
+
+  ArchiveCache loadedCache;
+  EXPECT_THROW(loadedCache.Load(cachePath), FileAccessError);
+}
+
+TEST_P(ArchiveCacheTest, loadShouldThrowIfTheFileDoesNotExist) {
+  ArchiveCache cache;
+
+  EXPECT_THROW(cache.Load(cachePath), FileAccessError);
+}
+}
+}
+
+#endif
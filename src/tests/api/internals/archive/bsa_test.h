@@ -0,0 +1,306 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_TESTS_API_INTERNALS_ARCHIVE_BSA_TEST
+#define LOOT_TESTS_API_INTERNALS_ARCHIVE_BSA_TEST
+
+#include "api/archive/bsa.h"
+
+#include "api/archive/binary_io.h"
+
+#include "loot/exception/archive_parse_error.h"
+#include "tests/common_game_test_fixture.h"
+
+namespace loot {
+namespace test {
+// Write a v104 BSA containing a single "meshes" folder that holds "a.nif"
+// and "b.nif".
+inline void WriteTestBsa(const std::filesystem::path& path,
+                         uint32_t archiveFlags) {
+  const std::string folderName = "meshes";
+  const std::vector<std::string> fileNames = {"a.nif", "b.nif"};
+
+  std::ofstream out(path, std::ios::binary);
+  out.write("BSA\0", 4);
+  WriteLittleEndian<uint32_t>(out, 104);
+  WriteLittleEndian<uint32_t>(out, 36);
+  WriteLittleEndian<uint32_t>(out, archiveFlags);
+  WriteLittleEndian<uint32_t>(out, 1);
+  WriteLittleEndian<uint32_t>(out, 2);
+  WriteLittleEndian<uint32_t>(out, 7);
+  WriteLittleEndian<uint32_t>(out, 12);
+  WriteLittleEndian<uint32_t>(out, 0);
+
+  WriteLittleEndian<uint64_t>(out, 0x1122334455667788);
+  WriteLittleEndian<uint32_t>(out, 2);
+  WriteLittleEndian<uint32_t>(out, 0);
+
+  if (archiveFlags & 0x1) {
+    WriteLittleEndian<uint8_t>(out, folderName.size() + 1);
+    out.write(folderName.c_str(), folderName.size() + 1);
+  }
+
+  WriteLittleEndian<uint64_t>(out, 0xA);
+  WriteLittleEndian<uint64_t>(out, 0);
+  WriteLittleEndian<uint64_t>(out, 0xB);
+  WriteLittleEndian<uint64_t>(out, 0);
+
+  if (archiveFlags & 0x2) {
+    for (const auto& fileName : fileNames) {
+      out.write(fileName.c_str(), fileName.size() + 1);
+    }
+  }
+}
+
+// Write a v1 general BA2 containing "meshes\a.nif" and "b.nif".
+inline void WriteTestBa2(const std::filesystem::path& path, bool includeNames) {
+  const std::vector<std::string> filePaths = {"meshes\\a.nif", "b.nif"};
+
+  std::ofstream out(path, std::ios::binary);
+  out.write("BTDX", 4);
+  WriteLittleEndian<uint32_t>(out, 1);
+  out.write("GNRL", 4);
+  WriteLittleEndian<uint32_t>(out, 2);
+  WriteLittleEndian<uint64_t>(out, includeNames ? 24 + 2 * 36 : 0);
+
+  for (uint32_t i = 0; i < filePaths.size(); ++i) {
+    WriteLittleEndian<uint32_t>(out, 0xA + i);
+    WriteLittleEndian<uint32_t>(out, 0x66696E);
+    WriteLittleEndian<uint32_t>(out, 0xC);
+    WriteLittleEndian<uint32_t>(out, 0);
+    WriteLittleEndian<uint64_t>(out, 0);
+    WriteLittleEndian<uint32_t>(out, 0);
+    WriteLittleEndian<uint32_t>(out, 0);
+    WriteLittleEndian<uint32_t>(out, 0xBAADF00D);
+  }
+
+  if (includeNames) {
+    for (const auto& filePath : filePaths) {
+      WriteLittleEndian<uint16_t>(out, filePath.size());
+      out.write(filePath.c_str(), filePath.size());
+    }
+  }
+}
+
+// Write a v104 BSA that stores the path of its one file, "meshes\a.nif", at
+// the start of the file's data instead of in a file names block.
+inline void WriteTestBsaWithEmbeddedNames(const std::filesystem::path& path) {
+  const std::string folderName = "meshes";
+  const std::string filePath = "meshes\\a.nif";
+
+  std::ofstream out(path, std::ios::binary);
+  out.write("BSA\0", 4);
+  WriteLittleEndian<uint32_t>(out, 104);
+  WriteLittleEndian<uint32_t>(out, 36);
+  WriteLittleEndian<uint32_t>(out, 0x101);
+  WriteLittleEndian<uint32_t>(out, 1);
+  WriteLittleEndian<uint32_t>(out, 1);
+  WriteLittleEndian<uint32_t>(out, 7);
+  WriteLittleEndian<uint32_t>(out, 0);
+  WriteLittleEndian<uint32_t>(out, 0);
+
+  WriteLittleEndian<uint64_t>(out, 0x1122334455667788);
+  WriteLittleEndian<uint32_t>(out, 1);
+  WriteLittleEndian<uint32_t>(out, 0);
+
+  WriteLittleEndian<uint8_t>(out, folderName.size() + 1);
+  out.write(folderName.c_str(), folderName.size() + 1);
+
+  // The file's data starts immediately after its record.
+  WriteLittleEndian<uint64_t>(out, 0xA);
+  WriteLittleEndian<uint32_t>(out, 1 + filePath.size() + 4);
+  WriteLittleEndian<uint32_t>(out, 76);
+
+  WriteLittleEndian<uint8_t>(out, filePath.size());
+  out.write(filePath.c_str(), filePath.size());
+  WriteLittleEndian<uint32_t>(out, 0xBAADF00D);
+}
+
+class GetAssetsInBethesdaArchiveTest : public CommonGameTestFixture {
+protected:
+  GetAssetsInBethesdaArchiveTest() :
+      bsaPath(dataPath / "Test.bsa"),
+      ba2Path(dataPath / "Test.ba2") {}
+
+  const std::filesystem::path bsaPath;
+  const std::filesystem::path ba2Path;
+};
+
+// Pass an empty first argument, as it's a prefix for the test instantation,
+// but we only have the one so no prefix is necessary.
+// Just test with one game because the archive format is independent of the
+// game fixture.
+INSTANTIATE_TEST_CASE_P(,
+                        GetAssetsInBethesdaArchiveTest,
+                        ::testing::Values(GameType::tes5));
+
+TEST_P(GetAssetsInBethesdaArchiveTest, shouldThrowIfTheArchiveDoesNotExist) {
+  EXPECT_THROW(GetAssetsInBethesdaArchive(dataPath / "missing.bsa"),
+               FileAccessError);
+}
+
+TEST_P(GetAssetsInBethesdaArchiveTest,
+       shouldThrowIfTheFileIsNotARecognisedArchive) {
+  EXPECT_THROW(GetAssetsInBethesdaArchive(dataPath / blankEsm),
+               ArchiveParseError);
+}
+
+TEST_P(GetAssetsInBethesdaArchiveTest,
+       shouldReadHashesAndNamesFromABsaThatIncludesNames) {
+  WriteTestBsa(bsaPath, 0x3);
+
+  auto assets = GetAssetsInBethesdaArchive(bsaPath);
+
+  ASSERT_EQ(2, assets.size());
+  EXPECT_EQ(0x1122334455667788, assets[0].folderHash);
+  EXPECT_EQ(0xA, assets[0].fileHash);
+  EXPECT_EQ("meshes", assets[0].folderName.value());
+  EXPECT_EQ("a.nif", assets[0].fileName.value());
+  EXPECT_EQ(0xB, assets[1].fileHash);
+  EXPECT_EQ("b.nif", assets[1].fileName.value());
+}
+
+TEST_P(GetAssetsInBethesdaArchiveTest,
+       shouldReadOnlyHashesFromABsaThatDoesNotIncludeNames) {
+  WriteTestBsa(bsaPath, 0);
+
+  auto assets = GetAssetsInBethesdaArchive(bsaPath);
+
+  ASSERT_EQ(2, assets.size());
+  EXPECT_EQ(0x1122334455667788, assets[0].folderHash);
+  EXPECT_EQ(0xA, assets[0].fileHash);
+  EXPECT_FALSE(assets[0].folderName.has_value());
+  EXPECT_FALSE(assets[0].fileName.has_value());
+}
+
+TEST_P(GetAssetsInBethesdaArchiveTest,
+       shouldReadFileNamesEmbeddedInTheFileDataOfABsa) {
+  WriteTestBsaWithEmbeddedNames(bsaPath);
+
+  auto assets = GetAssetsInBethesdaArchive(bsaPath);
+
+  ASSERT_EQ(1, assets.size());
+  EXPECT_EQ(0xA, assets[0].fileHash);
+  EXPECT_EQ("meshes", assets[0].folderName.value());
+  EXPECT_EQ("a.nif", assets[0].fileName.value());
+}
+
+TEST_P(GetAssetsInBethesdaArchiveTest,
+       shouldThrowIfABsaHeaderFileCountDoesNotMatchItsFolderRecords) {
+  WriteTestBsa(bsaPath, 0x3);
+
+  std::fstream stream(bsaPath,
+                      std::ios::binary | std::ios::in | std::ios::out);
+  stream.seekp(20);
+  WriteLittleEndian<uint32_t>(stream, 3);
+  stream.close();
+
+  EXPECT_THROW(GetAssetsInBethesdaArchive(bsaPath), ArchiveParseError);
+}
+
+TEST_P(GetAssetsInBethesdaArchiveTest, shouldThrowIfABsaIsTruncated) {
+  WriteTestBsa(bsaPath, 0x3);
+  std::filesystem::resize_file(bsaPath, 60);
+
+  EXPECT_THROW(GetAssetsInBethesdaArchive(bsaPath), ArchiveParseError);
+}
+
+TEST_P(GetAssetsInBethesdaArchiveTest,
+       shouldThrowIfABsaHeaderListsMoreFoldersThanItCanHold) {
+  WriteTestBsa(bsaPath, 0x3);
+
+  std::fstream out(bsaPath, std::ios::binary | std::ios::in | std::ios::out);
+  out.seekp(16);
+  WriteLittleEndian<uint32_t>(out, 0xFFFFFFFF);
+  out.close();
+
+  EXPECT_THROW(GetAssetsInBethesdaArchive(bsaPath), ArchiveParseError);
+}
+
+TEST_P(GetAssetsInBethesdaArchiveTest, shouldThrowForAnXboxBsa) {
+  WriteTestBsa(bsaPath, 0x43);
+
+  EXPECT_THROW(GetAssetsInBethesdaArchive(bsaPath), ArchiveParseError);
+}
+
+TEST_P(GetAssetsInBethesdaArchiveTest,
+       shouldSplitPathsFromAGeneralBa2IntoFolderAndFileNames) {
+  WriteTestBa2(ba2Path, true);
+
+  auto assets = GetAssetsInBethesdaArchive(ba2Path);
+
+  ASSERT_EQ(2, assets.size());
+  EXPECT_EQ(0xC, assets[0].folderHash);
+  EXPECT_EQ(0x0000000A0066696E, assets[0].fileHash);
+  EXPECT_EQ("meshes", assets[0].folderName.value());
+  EXPECT_EQ("a.nif", assets[0].fileName.value());
+  EXPECT_EQ("", assets[1].folderName.value());
+  EXPECT_EQ("b.nif", assets[1].fileName.value());
+}
+
+TEST_P(GetAssetsInBethesdaArchiveTest,
+       shouldThrowIfABa2HeaderListsMoreFilesThanItCanHold) {
+  WriteTestBa2(ba2Path, true);
+
+  std::fstream out(ba2Path, std::ios::binary | std::ios::in | std::ios::out);
+  out.seekp(12);
+  WriteLittleEndian<uint32_t>(out, 0xFFFFFFFF);
+  out.close();
+
+  EXPECT_THROW(GetAssetsInBethesdaArchive(ba2Path), ArchiveParseError);
+}
+
+TEST_P(GetAssetsInBethesdaArchiveTest,
+       shouldReadOnlyHashesFromABa2WithNoNameTable) {
+  WriteTestBa2(ba2Path, false);
+
+  auto assets = GetAssetsInBethesdaArchive(ba2Path);
+
+  ASSERT_EQ(2, assets.size());
+  EXPECT_EQ(0x0000000B0066696E, assets[1].fileHash);
+  EXPECT_FALSE(assets[1].folderName.has_value());
+  EXPECT_FALSE(assets[1].fileName.has_value());
+}
+
+TEST(GetAssetPath, shouldJoinFolderAndFileNamesWithABackslash) {
+  ArchiveAsset asset{0, 0, "meshes", "a.nif"};
+
+  EXPECT_EQ("meshes\\a.nif", GetAssetPath(asset));
+}
+
+TEST(GetAssetPath, shouldOmitAnEmptyFolderName) {
+  ArchiveAsset asset{0, 0, "", "a.nif"};
+
+  EXPECT_EQ("a.nif", GetAssetPath(asset));
+}
+
+TEST(GetAssetPath, shouldUseHexadecimalHashesForUnknownNames) {
+  ArchiveAsset asset{0x1A, 0x2B, std::nullopt, std::nullopt};
+
+  EXPECT_EQ("0x000000000000001a\\0x000000000000002b", GetAssetPath(asset));
+}
+}
+}
+
+#endif
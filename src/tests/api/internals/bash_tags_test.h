@@ -0,0 +1,109 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_TESTS_API_INTERNALS_BASH_TAGS_TEST
+#define LOOT_TESTS_API_INTERNALS_BASH_TAGS_TEST
+
+#include "api/bash_tags.h"
+
+#include <fstream>
+
+#include <gtest/gtest.h>
+
+namespace loot {
+namespace test {
+class ReadBashTagsFileTest : public ::testing::Test {
+protected:
+  ReadBashTagsFileTest() :
+      dataPath(std::filesystem::temp_directory_path() / "libloot-bash-tags") {}
+
+  void SetUp() { std::filesystem::create_directories(dataPath / "BashTags"); }
+
+  void TearDown() { std::filesystem::remove_all(dataPath); }
+
+  void WriteBashTagsFile(const std::string& content) {
+    std::ofstream out(dataPath / "BashTags" / "Blank.txt");
+    out << content;
+  }
+
+  const std::filesystem::path dataPath;
+};
+
+TEST_F(ReadBashTagsFileTest, shouldReturnNoTagsIfTheFileDoesNotExist) {
+  EXPECT_TRUE(ReadBashTagsFile(dataPath, "Blank.esp").empty());
+}
+
+TEST_F(ReadBashTagsFileTest, shouldReadCommaSeparatedTagsAcrossLines) {
+  WriteBashTagsFile("Relev, Delev\nC.Water\n");
+
+  EXPECT_EQ(std::vector<Tag>({Tag("Relev"), Tag("Delev"), Tag("C.Water")}),
+            ReadBashTagsFile(dataPath, "Blank.esp"));
+}
+
+TEST_F(ReadBashTagsFileTest, shouldReadTagsPrefixedWithAMinusSignAsRemovals) {
+  WriteBashTagsFile("Relev, -Delev");
+
+  EXPECT_EQ(std::vector<Tag>({Tag("Relev"), Tag("Delev", false)}),
+            ReadBashTagsFile(dataPath, "Blank.esp"));
+}
+
+TEST_F(ReadBashTagsFileTest, shouldIgnoreComments) {
+  WriteBashTagsFile("# A comment, Delev\nRelev # Another comment\n");
+
+  EXPECT_EQ(std::vector<Tag>({Tag("Relev")}),
+            ReadBashTagsFile(dataPath, "Blank.esp"));
+}
+
+TEST(ResolveBashTags, shouldReturnNoTagsIfThereAreNoSources) {
+  auto tags = ResolveBashTags({});
+
+  EXPECT_TRUE(tags.added.empty());
+  EXPECT_TRUE(tags.removed.empty());
+}
+
+TEST(ResolveBashTags, shouldSortAddedAndRemovedTagsAlphabetically) {
+  auto tags = ResolveBashTags({{
+      Tag("Relev"),
+      Tag("Delev"),
+      Tag("Names", false),
+      Tag("C.Water", false),
+  }});
+
+  EXPECT_EQ(std::vector<std::string>({"Delev", "Relev"}), tags.added);
+  EXPECT_EQ(std::vector<std::string>({"C.Water", "Names"}), tags.removed);
+}
+
+TEST(ResolveBashTags, shouldLetLaterSourcesOverrideEarlierSources) {
+  auto tags = ResolveBashTags({
+      {Tag("Relev"), Tag("Delev", false)},
+      {Tag("Relev", false), Tag("Delev")},
+  });
+
+  EXPECT_EQ(std::vector<std::string>({"Delev"}), tags.added);
+  EXPECT_EQ(std::vector<std::string>({"Relev"}), tags.removed);
+}
+}
+}
+
+#endif
@@ -0,0 +1,53 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_TESTS_API_INTERNALS_DIAGNOSTICS_TEST
+#define LOOT_TESTS_API_INTERNALS_DIAGNOSTICS_TEST
+
+#include "api/diagnostics.h"
+
+#include <gtest/gtest.h>
+
+namespace loot {
+namespace test {
+TEST(EscapeJsonString, shouldWrapTheGivenStringInDoubleQuotes) {
+  EXPECT_EQ("\"Blank.esm\"", EscapeJsonString("Blank.esm"));
+}
+
+TEST(EscapeJsonString, shouldEscapeQuotesAndBackslashes) {
+  EXPECT_EQ("\"C:\\\\Games\\\\\\\"Data\\\"\"",
+            EscapeJsonString("C:\\Games\\\"Data\""));
+}
+
+TEST(EscapeJsonString, shouldEscapeControlCharacters) {
+  EXPECT_EQ("\"a\\nb\\tc\\u0001\"", EscapeJsonString("a\nb\tc\x01"));
+}
+
+TEST(EscapeJsonString, shouldNotEscapeNonAsciiCharacters) {
+  EXPECT_EQ(u8"\"Русский.esm\"", EscapeJsonString(u8"Русский.esm"));
+}
+}
+}
+
+#endif
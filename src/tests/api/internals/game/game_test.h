@@ -27,6 +27,7 @@ along with LOOT.  If not, see
 
 #include "api/game/game.h"
 
+#include "tests/api/internals/archive/bsa_test.h"
 #include "tests/common_game_test_fixture.h"
 
 namespace loot {
@@ -188,6 +189,71 @@ TEST_P(GameTest,
   EXPECT_NO_THROW(loadInstalledPlugins(game, false));
 }
 
+TEST_P(GameTest,
+       getAssetsOverriddenByLooseFilesShouldThrowIfThePluginIsNotLoaded) {
+  Game game = Game(GetParam(), dataPath.parent_path(), localPath);
+
+  EXPECT_THROW(game.GetAssetsOverriddenByLooseFiles(blankEsp),
+               std::invalid_argument);
+}
+
+TEST_P(GameTest,
+       getAssetsOverriddenByLooseFilesShouldReturnArchivedAssetsThatExistAsLooseFiles) {
+  if (GetParam() == GameType::fo4) {
+    WriteTestBa2(dataPath / blankArchive, true);
+  } else {
+    WriteTestBsa(dataPath / blankArchive, 0x3);
+  }
+
+  std::filesystem::create_directories(dataPath / "meshes");
+  std::ofstream out(dataPath / "meshes" / "a.nif");
+  out.close();
+
+  Game game = Game(GetParam(), dataPath.parent_path(), localPath);
+  game.LoadPlugins({blankEsp}, false);
+
+  auto assets = game.GetAssetsOverriddenByLooseFiles(blankEsp);
+
+  EXPECT_EQ(std::vector<std::string>({"meshes\\a.nif"}), assets);
+}
+
+TEST_P(GameTest,
+       getAssetsOverriddenByLooseFilesShouldMatchLooseFilePathsCaseInsensitively) {
+  if (GetParam() == GameType::fo4) {
+    WriteTestBa2(dataPath / blankArchive, true);
+  } else {
+    WriteTestBsa(dataPath / blankArchive, 0x3);
+  }
+
+  std::filesystem::create_directories(dataPath / "Meshes");
+  std::ofstream out(dataPath / "Meshes" / "A.NIF");
+  out.close();
+
+  Game game = Game(GetParam(), dataPath.parent_path(), localPath);
+  game.LoadPlugins({blankEsp}, false);
+
+  auto assets = game.GetAssetsOverriddenByLooseFiles(blankEsp);
+
+  EXPECT_EQ(std::vector<std::string>({"meshes\\a.nif"}), assets);
+}
+
+TEST_P(GameTest, getAssociatedArchivesShouldThrowIfGivenANonPlugin) {
+  Game game = Game(GetParam(), dataPath.parent_path(), localPath);
+
+  EXPECT_THROW(game.GetAssociatedArchives(nonPluginFile),
+               std::invalid_argument);
+}
+
+TEST_P(GameTest,
+       getAssociatedArchivesShouldReturnTheArchivesThatAPluginLoads) {
+  Game game = Game(GetParam(), dataPath.parent_path(), localPath);
+
+  auto archives = game.GetAssociatedArchives(blankEsp);
+
+  EXPECT_EQ(std::vector<std::filesystem::path>({dataPath / blankArchive}),
+            archives);
+}
+
 TEST_P(GameTest, shouldShowBlankEsmAsActiveIfItHasNotBeenLoaded) {
   Game game = Game(GetParam(), dataPath.parent_path(), localPath);
   game.LoadCurrentLoadOrderState();
@@ -237,6 +303,38 @@ TEST_P(GameTest, shouldShowBlankEspAsInactiveIfItHasBeenFullyLoaded) {
 
   EXPECT_FALSE(game.IsPluginActive(blankEsp));
 }
+
+TEST_P(GameTest,
+       transitiveAfterGroupsShouldBeRecalculatedWhenUserGroupsAreChanged) {
+  Game game = Game(GetParam(), dataPath.parent_path(), localPath);
+  auto database = game.GetApiDatabase();
+
+  database->SetUserGroups({Group("a"), Group("b", {"a"})});
+  EXPECT_EQ(std::unordered_set<std::string>({"a"}),
+            database->GetTransitiveAfterGroups()->at("b"));
+
+  database->SetUserGroups(
+      {Group("a"), Group("b", {"a"}), Group("c", {"b"})});
+  EXPECT_EQ(std::unordered_set<std::string>({"a", "b"}),
+            database->GetTransitiveAfterGroups()->at("c"));
+
+  database->DiscardAllUserMetadata();
+  EXPECT_EQ(0, database->GetTransitiveAfterGroups()->count("c"));
+}
+
+TEST_P(GameTest,
+       transitiveAfterGroupsShouldRemainValidAfterUserGroupsAreChanged) {
+  Game game = Game(GetParam(), dataPath.parent_path(), localPath);
+  auto database = game.GetApiDatabase();
+
+  database->SetUserGroups({Group("a"), Group("b", {"a"})});
+  auto groups = database->GetTransitiveAfterGroups();
+
+  database->SetUserGroups({Group("a")});
+
+  EXPECT_EQ(std::unordered_set<std::string>({"a"}), groups->at("b"));
+  EXPECT_EQ(0, database->GetTransitiveAfterGroups()->count("b"));
+}
 }
 }
 
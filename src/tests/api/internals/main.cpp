@@ -22,6 +22,10 @@
     <https://www.gnu.org/licenses/>.
     */
 
+#include "tests/api/internals/archive/archive_cache_test.h"
+#include "tests/api/internals/archive/bsa_test.h"
+#include "tests/api/internals/bash_tags_test.h"
+#include "tests/api/internals/diagnostics_test.h"
 #include "tests/api/internals/game/game_cache_test.h"
 #include "tests/api/internals/game/game_test.h"
 #include "tests/api/internals/game/load_order_handler_test.h"
@@ -33,6 +37,7 @@
 #include "tests/api/internals/metadata/condition_evaluator_test.h"
 #include "tests/api/internals/metadata/conditional_metadata_test.h"
 #include "tests/api/internals/metadata/file_test.h"
+#include "tests/api/internals/metadata/filename_test.h"
 #include "tests/api/internals/metadata/group_test.h"
 #include "tests/api/internals/metadata/location_test.h"
 #include "tests/api/internals/metadata/message_content_test.h"
@@ -164,6 +164,87 @@ TEST_P(ConditionEvaluatorTest,
   EXPECT_FALSE(evaluator_.Evaluate("file(\"" + missingEsp + "\")"));
 }
 
+TEST_P(ConditionEvaluatorTest,
+       evaluateMultipleConditionsShouldReturnAResultForEachConditionInOrder) {
+  std::vector<std::string> conditions({
+      "file(\"" + blankEsm + "\")",
+      "file(\"" + missingEsp + "\")",
+      "",
+      "file(\"" + blankEsm + "\")",
+  });
+
+  EXPECT_EQ(std::vector<bool>({true, false, true, true}),
+            evaluator_.Evaluate(conditions));
+}
+
+TEST_P(ConditionEvaluatorTest,
+       evaluateMultipleConditionsShouldThrowIfAnyConditionStringIsInvalid) {
+  std::vector<std::string> conditions({
+      "file(\"" + blankEsm + "\")",
+      "condition",
+  });
+
+  EXPECT_THROW(evaluator_.Evaluate(conditions), ConditionSyntaxError);
+}
+
+TEST_P(ConditionEvaluatorTest,
+       evaluateWithActivePluginsShouldTreatOnlyTheGivenPluginsAsActive) {
+  std::vector<std::string> conditions({
+      "active(\"" + blankEsm + "\")",
+      "active(\"" + blankEsp + "\")",
+  });
+
+  EXPECT_EQ(std::vector<bool>({false, true}),
+            evaluator_.Evaluate(conditions, {blankEsp}));
+}
+
+TEST_P(ConditionEvaluatorTest,
+       evaluateWithActivePluginsShouldNotAffectTheCurrentLoadOrderState) {
+  std::string condition("active(\"" + blankEsm + "\")");
+
+  evaluator_.Evaluate({condition}, {blankEsp});
+
+  EXPECT_TRUE(evaluator_.Evaluate(condition));
+}
+
+TEST_P(ConditionEvaluatorTest,
+       evaluateWithActivePluginsShouldGiveTheSameVersionResultsAsTheCurrentState) {
+  std::vector<std::string> conditions({
+      "version(\"" + blankEsm + "\", \"5.0\", ==)",
+      "version(\"" + nonAsciiEsm + "\", \"5.0\", ==)",
+      "version(\"" + blankEsp + "\", \"5.0\", ==)",
+  });
+
+  EXPECT_EQ(evaluator_.Evaluate(conditions),
+            evaluator_.Evaluate(conditions, {blankEsm}));
+}
+
+TEST_P(ConditionEvaluatorTest,
+       getConditionCacheStatsShouldCountCacheHitsAndMisses) {
+  evaluator_.ClearConditionCache();
+
+  evaluator_.Evaluate("file(\"" + blankEsm + "\")");
+  evaluator_.Evaluate("file(\"" + missingEsp + "\")");
+  evaluator_.Evaluate("file(\"" + blankEsm + "\")");
+
+  auto stats = evaluator_.GetConditionCacheStats();
+  EXPECT_EQ(2, stats.entries);
+  EXPECT_EQ(1, stats.hits);
+  EXPECT_EQ(2, stats.misses);
+}
+
+TEST_P(ConditionEvaluatorTest, clearConditionCacheShouldResetTheCacheStats) {
+  evaluator_.Evaluate("file(\"" + blankEsm + "\")");
+  evaluator_.Evaluate("file(\"" + blankEsm + "\")");
+
+  evaluator_.ClearConditionCache();
+
+  auto stats = evaluator_.GetConditionCacheStats();
+  EXPECT_EQ(0, stats.entries);
+  EXPECT_EQ(0, stats.hits);
+  EXPECT_EQ(0, stats.misses);
+}
+
 TEST_P(ConditionEvaluatorTest, evaluateAllShouldEvaluateAllMetadataConditions) {
   PluginMetadata plugin(nonAsciiEsm);
   plugin.SetGroup("group1");
@@ -0,0 +1,64 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_TESTS_API_INTERNALS_METADATA_FILENAME_TEST
+#define LOOT_TESTS_API_INTERNALS_METADATA_FILENAME_TEST
+
+#include "loot/metadata/filename.h"
+
+#include <sstream>
+#include <unordered_set>
+
+#include <gtest/gtest.h>
+
+namespace loot {
+namespace test {
+TEST(Filename, equalityShouldBeCaseInsensitive) {
+  EXPECT_EQ(Filename("Blank.esm"), Filename("blank.ESM"));
+  EXPECT_NE(Filename("Blank.esm"), Filename("Blank.esp"));
+}
+
+TEST(Filename, hashShouldBeCaseInsensitive) {
+  std::hash<Filename> hasher;
+
+  EXPECT_EQ(hasher(Filename("Blank.esm")), hasher(Filename("blank.ESM")));
+}
+
+TEST(Filename, shouldBeUsableAsAnUnorderedSetElement) {
+  std::unordered_set<Filename> set({Filename("Blank.esm")});
+
+  EXPECT_EQ(1, set.count(Filename("blank.esm")));
+  EXPECT_EQ(0, set.count(Filename("Blank.esp")));
+}
+
+TEST(Filename, outputStreamOperatorShouldWriteTheFilenameAsGiven) {
+  std::stringstream stream;
+  stream << Filename("Blank.esm");
+
+  EXPECT_EQ("Blank.esm", stream.str());
+}
+}
+}
+
+#endif
@@ -331,6 +331,74 @@ TEST(
   EXPECT_EQ("test3", content.GetText());
 }
 
+TEST(MessageContent, chooseShouldTreatHyphensInLocaleCodesAsUnderscores) {
+  auto contents = {MessageContent("test1", "en"),
+                   MessageContent("test2", "pt"),
+                   MessageContent("test3", "pt_BR")};
+  auto content = MessageContent::Choose(contents, "pt-BR");
+
+  EXPECT_EQ("pt_BR", content.GetLanguage());
+  EXPECT_EQ("test3", content.GetText());
+}
+
+TEST(MessageContent, chooseShouldCompareLocaleCodesCaseInsensitively) {
+  auto contents = {MessageContent("test1", "en"),
+                   MessageContent("test2", "pt"),
+                   MessageContent("test3", "pt_BR")};
+  auto content = MessageContent::Choose(contents, "pt-br");
+
+  EXPECT_EQ("pt_BR", content.GetLanguage());
+  EXPECT_EQ("test3", content.GetText());
+}
+
+TEST(MessageContent, chooseShouldRemoveOneSubtagAtATimeWhenFallingBack) {
+  auto contents = {MessageContent("test1", "en"),
+                   MessageContent("test2", "zh"),
+                   MessageContent("test3", "zh_Hant")};
+  auto content = MessageContent::Choose(contents, "zh-Hant-TW");
+
+  EXPECT_EQ("zh_Hant", content.GetLanguage());
+  EXPECT_EQ("test3", content.GetText());
+}
+
+TEST(MessageContent,
+     chooseWithMultipleLanguagesShouldReturnTheFirstLanguageThatMatches) {
+  auto contents = {MessageContent("test1", "en"),
+                   MessageContent("test2", "de"),
+                   MessageContent("test3", "fr")};
+  auto content = MessageContent::Choose(
+      contents, std::vector<std::string>({"pt_BR", "fr", "de"}));
+
+  EXPECT_EQ("fr", content.GetLanguage());
+  EXPECT_EQ("test3", content.GetText());
+}
+
+TEST(
+    MessageContent,
+    chooseWithMultipleLanguagesShouldFallBackToALocaleLanguageBeforeTheNextPreference) {
+  auto contents = {MessageContent("test1", "en"),
+                   MessageContent("test2", "de"),
+                   MessageContent("test3", "pt")};
+  auto content = MessageContent::Choose(
+      contents, std::vector<std::string>({"pt-BR", "de"}));
+
+  EXPECT_EQ("pt", content.GetLanguage());
+  EXPECT_EQ("test3", content.GetText());
+}
+
+TEST(
+    MessageContent,
+    chooseWithMultipleLanguagesShouldReturnEnglishContentIfNoLanguagesMatch) {
+  auto contents = {MessageContent("test1", "de"),
+                   MessageContent("test2", "en"),
+                   MessageContent("test3", "fr")};
+  auto content = MessageContent::Choose(
+      contents, std::vector<std::string>({"pt_BR", "es"}));
+
+  EXPECT_EQ("en", content.GetLanguage());
+  EXPECT_EQ("test2", content.GetText());
+}
+
 TEST(MessageContent, emittingAsYamlShouldOutputDataCorrectly) {
   MessageContent content("content", french);
   YAML::Emitter emitter;
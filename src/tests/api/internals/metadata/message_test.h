@@ -123,6 +123,18 @@ TEST_P(MessageTest, equalityShouldRequireEqualContent) {
   EXPECT_FALSE(message1 == message2);
 }
 
+TEST_P(MessageTest, equalityShouldRequireEqualIds) {
+  Message message1(MessageType::say, "content", "", "id");
+  Message message2(MessageType::say, "content", "", "id");
+
+  EXPECT_TRUE(message1 == message2);
+
+  message1 = Message(MessageType::say, "content", "", "id1");
+  message2 = Message(MessageType::say, "content", "", "id2");
+
+  EXPECT_FALSE(message1 == message2);
+}
+
 TEST_P(MessageTest, inequalityShouldBeTheInverseOfEquality) {
   Message message1(MessageType::say, "content");
   Message message2(MessageType::say, "content");
@@ -348,6 +360,18 @@ TEST_P(
   EXPECT_TRUE(message2 >= message1);
 }
 
+TEST_P(MessageTest, getIdShouldReturnAnEmptyStringIfNoIdWasGiven) {
+  Message message(MessageType::say, "content1");
+
+  EXPECT_TRUE(message.GetId().empty());
+}
+
+TEST_P(MessageTest, getIdShouldReturnTheGivenId) {
+  Message message(MessageType::say, "content1", "", "id1");
+
+  EXPECT_EQ("id1", message.GetId());
+}
+
 TEST_P(MessageTest, getContentShouldReturnADefaultContentObjectIfNoneExists) {
   Message message;
   EXPECT_EQ(MessageContent(),
@@ -449,6 +473,19 @@ TEST_P(MessageTest, emittingAsYamlShouldOutputConditionIfItIsNotEmpty) {
       emitter.c_str());
 }
 
+TEST_P(MessageTest, emittingAsYamlShouldOutputIdIfItIsNotEmpty) {
+  Message message(MessageType::say, "content1", "condition1", "id1");
+  YAML::Emitter emitter;
+  emitter << message;
+
+  EXPECT_STREQ(
+      "type: say\n"
+      "content: 'content1'\n"
+      "condition: 'condition1'\n"
+      "id: id1",
+      emitter.c_str());
+}
+
 TEST_P(MessageTest, emittingAsYamlShouldOutputMultipleContentStringsAsAList) {
   Message message(MessageType::say,
                   MessageContents({MessageContent("content1"),
@@ -506,6 +543,22 @@ TEST_P(MessageTest, encodingAsYamlShouldStoreConditionFieldIfItIsNotEmpty) {
   EXPECT_EQ("condition1", node["condition"].as<std::string>());
 }
 
+TEST_P(MessageTest, encodingAsYamlShouldOmitIdFieldIfItIsEmpty) {
+  Message message(MessageType::say, "content1");
+  YAML::Node node;
+  node = message;
+
+  EXPECT_FALSE(node["id"]);
+}
+
+TEST_P(MessageTest, encodingAsYamlShouldStoreIdFieldIfItIsNotEmpty) {
+  Message message(MessageType::say, "content1", "", "id1");
+  YAML::Node node;
+  node = message;
+
+  EXPECT_EQ("id1", node["id"].as<std::string>());
+}
+
 TEST_P(MessageTest, encodingAsYamlShouldStoreASingleContentStringInAVector) {
   Message message(MessageType::say, "content1");
   YAML::Node node;
@@ -582,6 +635,16 @@ TEST_P(MessageTest, decodingFromYamlShouldStoreANonEmptyConditionField) {
   EXPECT_EQ("file(\"Foo.esp\")", message.GetCondition());
 }
 
+TEST_P(MessageTest, decodingFromYamlShouldStoreAnIdField) {
+  YAML::Node node = YAML::Load(
+      "type: say\n"
+      "content: content1\n"
+      "id: id1");
+  Message message = node.as<Message>();
+
+  EXPECT_EQ("id1", message.GetId());
+}
+
 TEST_P(MessageTest, decodingFromYamlShouldStoreAScalarContentValueCorrectly) {
   YAML::Node node = YAML::Load(
       "type: say\n"
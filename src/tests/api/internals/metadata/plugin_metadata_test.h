@@ -90,6 +90,20 @@ TEST_P(PluginMetadataTest, nameMatchesShouldUseCaseInsensitiveRegexMatchingForAR
   EXPECT_FALSE(plugin.NameMatches(blankDifferentEsm));
 }
 
+TEST_P(PluginMetadataTest,
+       stringConstructorShouldThrowIfGivenAnInvalidRegexName) {
+  EXPECT_THROW(PluginMetadata("RagnvaldBook(Farengar(+Ragnvald)?)?\\.esp"),
+               std::regex_error);
+}
+
+TEST_P(PluginMetadataTest, copiesOfARegexPluginShouldMatchTheSameNames) {
+  PluginMetadata original("Blan.\\.esm");
+  PluginMetadata copy = original;
+
+  EXPECT_TRUE(copy.NameMatches(blankEsm));
+  EXPECT_FALSE(copy.NameMatches(blankDifferentEsm));
+}
+
 TEST_P(PluginMetadataTest, mergeMetadataShouldNotChangeName) {
   PluginMetadata plugin1(blankEsm);
   PluginMetadata plugin2(blankDifferentEsm);
@@ -251,6 +265,60 @@ TEST_P(PluginMetadataTest, mergeMetadataShouldMergeLocationData) {
             plugin1.GetLocations());
 }
 
+TEST_P(PluginMetadataTest,
+       mergeMetadataWithReplaceModeShouldNotMergeListsThatAreAlreadySet) {
+  PluginMetadata plugin1;
+  PluginMetadata plugin2;
+  File file1(blankEsm);
+  File file2(blankDifferentEsm);
+  Tag tag1("Relev");
+  Tag tag2("Delev");
+
+  plugin1.SetLoadAfterFiles({file1});
+  plugin1.SetTags({tag1});
+  plugin2.SetLoadAfterFiles({file2});
+  plugin2.SetTags({tag2});
+
+  MetadataMergeModes mergeModes;
+  mergeModes.loadAfter = MergeMode::replace;
+  plugin1.MergeMetadata(plugin2, mergeModes);
+
+  EXPECT_EQ(std::vector<File>({file1}), plugin1.GetLoadAfterFiles());
+  EXPECT_EQ(std::vector<Tag>({tag1, tag2}), plugin1.GetTags());
+}
+
+TEST_P(PluginMetadataTest,
+       mergeMetadataWithReplaceModeShouldMergeListsThatAreNotAlreadySet) {
+  PluginMetadata plugin1;
+  PluginMetadata plugin2;
+  Message message(MessageType::say, "content");
+
+  plugin2.SetMessages({message});
+
+  MetadataMergeModes mergeModes;
+  mergeModes.messages = MergeMode::replace;
+  plugin1.MergeMetadata(plugin2, mergeModes);
+
+  EXPECT_EQ(std::vector<Message>({message}), plugin1.GetMessages());
+}
+
+TEST_P(PluginMetadataTest,
+       mergeMetadataWithReplaceModeShouldNotMergeMessagesIfAnyAreAlreadySet) {
+  PluginMetadata plugin1;
+  PluginMetadata plugin2;
+  Message message1(MessageType::say, "content1");
+  Message message2(MessageType::warn, "content2");
+
+  plugin1.SetMessages({message1});
+  plugin2.SetMessages({message2});
+
+  MetadataMergeModes mergeModes;
+  mergeModes.messages = MergeMode::replace;
+  plugin1.MergeMetadata(plugin2, mergeModes);
+
+  EXPECT_EQ(std::vector<Message>({message1}), plugin1.GetMessages());
+}
+
 TEST_P(PluginMetadataTest, newMetadataShouldUseSourcePluginName) {
   PluginMetadata plugin1(blankEsm);
   PluginMetadata plugin2(blankDifferentEsm);
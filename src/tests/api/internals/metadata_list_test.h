@@ -25,6 +25,9 @@ along with LOOT.  If not, see
 #ifndef LOOT_TESTS_API_INTERNALS_METADATA_LIST_TEST
 #define LOOT_TESTS_API_INTERNALS_METADATA_LIST_TEST
 
+#include <fstream>
+#include <sstream>
+
 #include "api/metadata_list.h"
 
 #include "tests/common_game_test_fixture.h"
@@ -71,6 +74,22 @@ protected:
     return metadata.GetName();
   }
 
+  std::string LoadAndSave(const std::string& metadata) {
+    std::ofstream out(metadataPath);
+    out << metadata;
+    out.close();
+
+    MetadataList metadataList;
+    metadataList.Load(metadataPath);
+    metadataList.Save(savedMetadataPath);
+
+    std::ifstream in(savedMetadataPath);
+    std::stringstream content;
+    content << in.rdbuf();
+
+    return content.str();
+  }
+
   const std::filesystem::path metadataPath;
   const std::filesystem::path savedMetadataPath;
   const std::filesystem::path groupMetadataPath;
@@ -247,6 +266,139 @@ TEST_P(MetadataListTest, saveShouldWriteTheLoadedMetadataToTheGivenFilePath) {
             names);
 }
 
+TEST_P(MetadataListTest, saveShouldWriteSuppressedMessageIds) {
+  MetadataList metadataList;
+  metadataList.SetSuppressedMessageIds({"id1", "id2"});
+
+  EXPECT_NO_THROW(metadataList.Save(savedMetadataPath));
+  EXPECT_NO_THROW(metadataList.Load(savedMetadataPath));
+
+  EXPECT_EQ(std::vector<std::string>({"id1", "id2"}),
+            metadataList.SuppressedMessageIds());
+}
+
+// The saveShouldWrite...InAStableFormat tests between them cover every
+// metadata type, so if the emitted format changes then they must be updated,
+// and the change reviewed, as tools that diff saved metadata files will be
+// affected.
+TEST_P(MetadataListTest, saveShouldWriteMetadataInAStableFormat) {
+  const std::string golden =
+      "bash_tags:\n"
+      "  - C.Climate\n"
+      "  - Relev\n"
+      "groups:\n"
+      "  - name: 'default'\n"
+      "  - name: 'early'\n"
+      "    description: 'Plugins that shouldn''t load late.'\n"
+      "    after:\n"
+      "      - default\n"
+      "globals:\n"
+      "  - type: say\n"
+      "    content: 'A global message.'\n"
+      "  - type: warn\n"
+      "    content:\n"
+      "      - lang: en\n"
+      "        text: 'An English message.'\n"
+      "      - lang: de\n"
+      "        text: 'Eine deutsche Nachricht.'\n"
+      "    condition: 'file(\"Blank.esm\")'\n"
+      "plugins:\n"
+      "  - name: 'Blank.+\\.esp'\n"
+      "    tag:\n"
+      "      - Relev\n"
+      "  - name: 'Blank.esm'\n"
+      "    group: 'early'\n"
+      "    after:\n"
+      "      - 'Blank.esp'\n"
+      "    req:\n"
+      "      - name: 'Blank - Different.esm'\n"
+      "        condition: 'active(\"Blank.esp\")'\n"
+      "    tag:\n"
+      "      - Relev\n"
+      "      - -Delev\n"
+      "    dirty:\n"
+      "      - crc: 0x5\n"
+      "        util: 'utility'\n"
+      "        itm: 2\n"
+      "    url:\n"
+      "      - 'https://www.example.com'\n"
+      "  - name: 'Blank.esp'\n"
+      "    inc:\n"
+      "      - 'Blank - Different.esp'\n"
+      "    msg:\n"
+      "      - type: error\n"
+      "        content: 'An error message.'\n"
+      "    clean:\n"
+      "      - crc: 0xa\n"
+      "        util: 'utility'";
+
+  EXPECT_EQ(golden, LoadAndSave(golden));
+}
+
+TEST_P(MetadataListTest,
+       saveShouldWriteMessageIdsAndSuppressedMessagesInAStableFormat) {
+  const std::string golden =
+      "globals:\n"
+      "  - type: say\n"
+      "    content: 'A global message.'\n"
+      "    id: global-say\n"
+      "  - type: warn\n"
+      "    content:\n"
+      "      - lang: en\n"
+      "        text: 'An English message.'\n"
+      "      - lang: de\n"
+      "        text: 'Eine deutsche Nachricht.'\n"
+      "    condition: 'file(\"Blank.esm\")'\n"
+      "    id: global-warn\n"
+      "suppressed_messages:\n"
+      "  - global-say\n"
+      "  - blank-error\n"
+      "plugins:\n"
+      "  - name: 'Blank.esm'\n"
+      "    msg:\n"
+      "      - type: error\n"
+      "        content: 'An error message.'\n"
+      "        id: blank-error";
+
+  EXPECT_EQ(golden, LoadAndSave(golden));
+}
+
+TEST_P(MetadataListTest,
+       saveShouldWriteFileDisplayNamesAndNamedLocationsInAStableFormat) {
+  const std::string golden =
+      "plugins:\n"
+      "  - name: 'Blank.esm'\n"
+      "    req:\n"
+      "      - name: 'Blank.esp'\n"
+      "        display: 'The blank plugin'\n"
+      "    inc:\n"
+      "      - name: 'Blank - Different.esp'\n"
+      "        condition: 'file(\"Blank.esp\")'\n"
+      "        display: 'A different blank plugin'\n"
+      "    url:\n"
+      "      - 'https://www.example.com'\n"
+      "      - link: 'https://www.example.com/other'\n"
+      "        name: 'Another example'";
+
+  EXPECT_EQ(golden, LoadAndSave(golden));
+}
+
+TEST_P(MetadataListTest, saveShouldQuoteNamesContainingQuotesAndColons) {
+  const std::string golden =
+      "plugins:\n"
+      "  - name: 'Blank: Extra.esp'\n"
+      "    after:\n"
+      "      - 'Bob''s Plugin.esp'\n"
+      "    msg:\n"
+      "      - type: say\n"
+      "        content: 'Don''t use \"this\": it''s old.'\n"
+      "  - name: 'Bob''s Plugin.esp'\n"
+      "    tag:\n"
+      "      - Relev";
+
+  EXPECT_EQ(golden, LoadAndSave(golden));
+}
+
 TEST_P(MetadataListTest, clearShouldClearLoadedData) {
   MetadataList metadataList;
   ASSERT_NO_THROW(metadataList.Load(metadataPath));
@@ -27,8 +27,11 @@ along with LOOT.  If not, see
 
 #include "api/plugin.h"
 
+#include <fstream>
+
 #include "api/game/game.h"
 #include "tests/common_game_test_fixture.h"
+#include "tests/plugin_generator.h"
 
 namespace loot {
 namespace test {
@@ -147,6 +150,9 @@ public:
   }
   std::vector<Tag> GetBashTags() const { return std::vector<Tag>(); }
   std::optional<uint32_t> GetCRC() const { return std::nullopt; }
+  std::optional<std::vector<PluginIntegrityIssue>> GetIntegrityIssues() const {
+    return std::nullopt;
+  }
 
   bool IsMaster() const { return false; }
   bool IsLightMaster() const { return false; }
@@ -156,6 +162,10 @@ public:
   bool IsEmpty() const { return false; }
   bool LoadsArchive() const { return false; }
   bool DoFormIDsOverlap(const PluginInterface& plugin) const { return true; }
+  std::vector<std::string> GetOverlappingAssets(
+      const PluginInterface& plugin) const {
+    return {"meshes\\a.nif"};
+  }
 };
 
 // Pass an empty first argument, as it's a prefix for the test instantation,
@@ -281,6 +291,137 @@ TEST_P(PluginTest, loadingAPluginWithMastersShouldReadThemCorrectly) {
   EXPECT_EQ(std::vector<std::string>({blankEsm}), plugin.GetMasters());
 }
 
+TEST_P(PluginTest, loadingAGeneratedPluginShouldReadItsHeaderData) {
+  GeneratedPlugin generated;
+  generated.masters = {blankEsm, blankDifferentEsm};
+  generated.description = "Version: 1.2.3";
+  generated.isMaster = true;
+  PluginWriter(GetParam()).Write(generated, dataPath / "Generated.esm");
+
+  Plugin plugin(
+      game_.Type(), game_.GetCache(), game_.DataPath() / "Generated.esm", true);
+
+  EXPECT_EQ(generated.masters, plugin.GetMasters());
+  EXPECT_TRUE(plugin.IsMaster());
+  EXPECT_EQ("1.2.3", plugin.GetVersion());
+}
+
+TEST_P(PluginTest, loadingAGeneratedPluginShouldReadItsRecords) {
+  GeneratedPlugin generated;
+  generated.masters = {blankEsm};
+  generated.newRecordCount = 2;
+  generated.overrideRecordCount = 3;
+  PluginWriter(GetParam()).Write(generated, dataPath / "Generated.esp");
+
+  Plugin plugin(game_.Type(),
+                game_.GetCache(),
+                game_.DataPath() / "Generated.esp",
+                false);
+
+  EXPECT_FALSE(plugin.IsMaster());
+  EXPECT_FALSE(plugin.IsEmpty());
+
+  if (GetParam() == GameType::tes3) {
+    EXPECT_EQ(0, plugin.NumOverrideFormIDs());
+  } else {
+    EXPECT_EQ(3, plugin.NumOverrideFormIDs());
+  }
+}
+
+TEST_P(PluginTest, loadingAGeneratedLightPluginShouldReadItsLightFlag) {
+  GeneratedPlugin generated;
+  generated.isLight = true;
+  PluginWriter(GetParam()).Write(generated, dataPath / "Generated.esp");
+
+  Plugin plugin(
+      game_.Type(), game_.GetCache(), game_.DataPath() / "Generated.esp", true);
+
+  EXPECT_EQ(GetParam() == GameType::fo4 || GetParam() == GameType::tes5se,
+            plugin.IsLightPlugin());
+}
+
+TEST_P(PluginTest, getIntegrityIssuesShouldHaveNoValueIfOnlyTheHeaderIsLoaded) {
+  Plugin plugin(
+      game_.Type(), game_.GetCache(), game_.DataPath() / blankEsm, true);
+
+  EXPECT_FALSE(plugin.GetIntegrityIssues().has_value());
+}
+
+TEST_P(PluginTest, getIntegrityIssuesShouldBeEmptyForAnUndamagedPlugin) {
+  GeneratedPlugin generated;
+  generated.masters = {blankEsm};
+  generated.newRecordCount = 2;
+  generated.overrideRecordCount = 1;
+  PluginWriter(GetParam()).Write(generated, dataPath / "Generated.esp");
+
+  Plugin plugin(game_.Type(),
+                game_.GetCache(),
+                game_.DataPath() / "Generated.esp",
+                false);
+
+  EXPECT_TRUE(plugin.GetIntegrityIssues().value().empty());
+}
+
+TEST_P(PluginTest, getIntegrityIssuesShouldIncludeRecordsWithInvalidFormIds) {
+  if (GetParam() == GameType::tes3) {
+    return;
+  }
+
+  GeneratedPlugin generated;
+  generated.newRecordCount = 1;
+  auto data = PluginWriter(GetParam()).Write(generated);
+
+  // Give the record a mod index that doesn't belong to any plugin.
+  const auto recordOffset = data.find("GLOB", data.find("GRUP") + 12);
+  data[recordOffset + 15] = 0x05;
+  std::ofstream out(dataPath / "Generated.esp", std::ios::binary);
+  out << data;
+  out.close();
+
+  Plugin plugin(game_.Type(),
+                game_.GetCache(),
+                game_.DataPath() / "Generated.esp",
+                false);
+
+  auto issues = plugin.GetIntegrityIssues().value();
+  ASSERT_EQ(1, issues.size());
+  EXPECT_EQ(IntegrityIssueType::invalidFormId, issues[0].type);
+  EXPECT_EQ(recordOffset, issues[0].offset);
+  EXPECT_EQ("GLOB", issues[0].recordType);
+  EXPECT_EQ(0x05000800, issues[0].formId);
+}
+
+TEST_P(PluginTest,
+       getIntegrityIssuesShouldIncludeRecordsWithMismatchedSubrecordSizes) {
+  GeneratedPlugin generated;
+  generated.newRecordCount = 1;
+  auto data = PluginWriter(GetParam()).Write(generated);
+
+  // Shrink the size of the record's only subrecord.
+  size_t recordOffset = 0;
+  size_t headerSize = 16;
+  if (GetParam() == GameType::tes3) {
+    recordOffset = data.find("GLOB");
+  } else {
+    recordOffset = data.find("GLOB", data.find("GRUP") + 12);
+    headerSize = GetParam() == GameType::tes4 ? 20 : 24;
+  }
+  data[recordOffset + headerSize + 4] = 0x01;
+  std::ofstream out(dataPath / "Generated.esp", std::ios::binary);
+  out << data;
+  out.close();
+
+  Plugin plugin(game_.Type(),
+                game_.GetCache(),
+                game_.DataPath() / "Generated.esp",
+                false);
+
+  auto issues = plugin.GetIntegrityIssues().value();
+  ASSERT_EQ(1, issues.size());
+  EXPECT_EQ(IntegrityIssueType::subrecordSizeMismatch, issues[0].type);
+  EXPECT_EQ(recordOffset, issues[0].offset);
+}
+
 TEST_P(PluginTest, loadingAPluginThatDoesNotExistShouldThrow) {
   EXPECT_THROW(Plugin(game_.Type(),
                       game_.GetCache(),
@@ -289,6 +430,17 @@ TEST_P(PluginTest, loadingAPluginThatDoesNotExistShouldThrow) {
                FileAccessError);
 }
 
+TEST_P(PluginTest,
+       loadingAPluginThatDoesNotExistShouldGiveItsPathAndTheCauseOfTheError) {
+  try {
+    Plugin(game_.Type(), game_.GetCache(), game_.DataPath() / missingEsp, true);
+    FAIL();
+  } catch (const FileAccessError& e) {
+    EXPECT_EQ(game_.DataPath() / (missingEsp + ".ghost"), e.GetPath());
+    EXPECT_THROW(std::rethrow_if_nested(e), FileAccessError);
+  }
+}
+
 TEST_P(
     PluginTest,
     loadsArchiveForAnArchiveThatExactlyMatchesAnEsmFileBasenameShouldReturnTrueForAllGamesExceptMorrowindAndOblivion) {
@@ -423,6 +575,67 @@ TEST_P(
   }
 }
 
+TEST_P(
+    PluginTest,
+    isValidAsLightPluginShouldAllowNewObjectIdsBelow0x800WithAnExtendedRangeAndAHeaderVersionOfAtLeast1Point71) {
+  if (GetParam() != GameType::tes5se) {
+    return;
+  }
+
+  GeneratedPlugin generated;
+  generated.newRecordCount = 1;
+  generated.firstNewObjectId = 0x001;
+  generated.headerVersion = 1.71f;
+  PluginWriter(GetParam()).Write(generated, dataPath / "Generated.esp");
+
+  const auto pluginPath = game_.DataPath() / "Generated.esp";
+
+  EXPECT_TRUE(Plugin(game_.Type(), game_.GetCache(), pluginPath, false, true)
+                  .IsValidAsLightPlugin());
+  EXPECT_FALSE(Plugin(game_.Type(), game_.GetCache(), pluginPath, false, false)
+                   .IsValidAsLightPlugin());
+}
+
+TEST_P(
+    PluginTest,
+    isValidAsLightPluginShouldNotAllowNewObjectIdsBelow0x800WithAnExtendedRangeAndAHeaderVersionBelow1Point71) {
+  if (GetParam() != GameType::tes5se) {
+    return;
+  }
+
+  GeneratedPlugin generated;
+  generated.newRecordCount = 1;
+  generated.firstNewObjectId = 0x001;
+  PluginWriter(GetParam()).Write(generated, dataPath / "Generated.esp");
+
+  EXPECT_FALSE(Plugin(game_.Type(),
+                      game_.GetCache(),
+                      game_.DataPath() / "Generated.esp",
+                      false,
+                      true)
+                   .IsValidAsLightPlugin());
+}
+
+TEST_P(PluginTest,
+       isValidAsLightPluginShouldNotAllowNewObjectIdsAbove0xFFFWithAnExtendedRange) {
+  if (GetParam() != GameType::tes5se) {
+    return;
+  }
+
+  GeneratedPlugin generated;
+  generated.newRecordCount = 2;
+  generated.firstNewObjectId = 0xFFF;
+  generated.headerVersion = 1.71f;
+  PluginWriter(GetParam()).Write(generated, dataPath / "Generated.esp");
+
+  EXPECT_FALSE(Plugin(game_.Type(),
+                      game_.GetCache(),
+                      game_.DataPath() / "Generated.esp",
+                      false,
+                      true)
+                   .IsValidAsLightPlugin());
+}
+
 TEST_P(PluginTest, getFileSizeShouldThrowForAMissingPlugin) {
   EXPECT_THROW(Plugin::GetFileSize(game_.DataPath() / missingEsp),
                std::filesystem::filesystem_error);
@@ -486,6 +699,28 @@ TEST_P(PluginTest,
   EXPECT_TRUE(plugin2.DoFormIDsOverlap(plugin1));
 }
 
+TEST_P(
+    PluginTest,
+    getOverlappingAssetsShouldReturnAnEmptyVectorIfTheArgumentIsNotAPluginObject) {
+  Plugin plugin1(
+      game_.Type(), game_.GetCache(), game_.DataPath() / blankEsm, false);
+  OtherPluginType plugin2;
+
+  EXPECT_TRUE(plugin1.GetOverlappingAssets(plugin2).empty());
+  EXPECT_FALSE(plugin2.GetOverlappingAssets(plugin1).empty());
+}
+
+TEST_P(PluginTest,
+       getOverlappingAssetsShouldReturnAnEmptyVectorIfNeitherPluginLoadsArchives) {
+  Plugin plugin1(
+      game_.Type(), game_.GetCache(), game_.DataPath() / blankEsm, false);
+  Plugin plugin2(
+      game_.Type(), game_.GetCache(), game_.DataPath() / blankEsp, false);
+
+  EXPECT_TRUE(plugin1.GetOverlappingAssets(plugin2).empty());
+  EXPECT_TRUE(plugin2.GetOverlappingAssets(plugin1).empty());
+}
+
 TEST_P(PluginTest, getOverlapSizeShouldCountEachRecordOnce) {
   Plugin plugin1(
       game_.Type(), game_.GetCache(), game_.DataPath() / blankEsm, false);
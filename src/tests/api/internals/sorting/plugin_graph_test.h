@@ -137,6 +137,47 @@ TEST_P(PluginGraphTest, topologicalSortWithNoLoadedPluginsShouldReturnAnEmptyLis
 
   EXPECT_TRUE(sorted.empty());
 }
+
+TEST_P(PluginGraphTest,
+       minimiseMovementShouldRestoreThePreviousOrderOfUnconstrainedPlugins) {
+  ASSERT_NO_THROW(loadInstalledPlugins(game_, true));
+
+  std::vector<std::string> loadOrder({
+      masterFile,
+      blankEsm,
+      blankDifferentEsp,
+      blankEsp,
+  });
+
+  PluginGraph graph;
+  graph.AddPluginVertices(game_, loadOrder);
+  graph.AddSpecificEdges();
+
+  auto result = graph.MinimiseMovement(
+      {masterFile, blankEsm, blankEsp, blankDifferentEsp}, loadOrder);
+
+  EXPECT_EQ(loadOrder, result);
+}
+
+TEST_P(PluginGraphTest, minimiseMovementShouldNotMovePluginsPastTheirMasters) {
+  ASSERT_NO_THROW(loadInstalledPlugins(game_, true));
+
+  std::vector<std::string> loadOrder({
+      blankMasterDependentEsp,
+      masterFile,
+      blankEsm,
+  });
+
+  PluginGraph graph;
+  graph.AddPluginVertices(game_, loadOrder);
+  graph.AddSpecificEdges();
+
+  std::vector<std::string> sortedPlugins(
+      {masterFile, blankEsm, blankMasterDependentEsp});
+  auto result = graph.MinimiseMovement(sortedPlugins, loadOrder);
+
+  EXPECT_EQ(sortedPlugins, result);
+}
 }
 }
 
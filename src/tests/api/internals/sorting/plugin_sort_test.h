@@ -522,6 +522,30 @@ TEST_P(PluginSortTest,
   EXPECT_EQ(expectedSortedOrder, sorted);
 }
 
+TEST_P(PluginSortTest,
+       sortingShouldNotCountAnyMovedPluginsForAnExistingValidLoadOrder) {
+  ASSERT_NO_THROW(loadInstalledPlugins(game_, false));
+
+  auto loadOrder = game_.GetLoadOrder();
+  std::vector<std::string> sorted = SortPlugins(game_, loadOrder);
+
+  EXPECT_EQ(0, CountMovedPlugins(loadOrder, sorted));
+}
+
+TEST_P(PluginSortTest,
+       countMovedPluginsShouldReturnTheFewestPluginsThatNeedToBeMoved) {
+  EXPECT_EQ(0, CountMovedPlugins({"A.esp", "B.esp"}, {"A.esp", "B.esp"}));
+  EXPECT_EQ(2,
+            CountMovedPlugins({"A.esp", "B.esp", "C.esp", "D.esp"},
+                              {"C.esp", "D.esp", "A.esp", "B.esp"}));
+}
+
+TEST_P(PluginSortTest, countMovedPluginsShouldIgnorePluginsNotInBothLists) {
+  EXPECT_EQ(1,
+            CountMovedPlugins({"A.esp", "B.esp", "C.esp"},
+                              {"B.esp", "D.esp", "A.esp"}));
+}
+
 TEST_P(PluginSortTest, sortingShouldThrowIfACyclicInteractionIsEncountered) {
   ASSERT_NO_THROW(loadInstalledPlugins(game_, false));
   PluginMetadata plugin(blankEsm);
@@ -0,0 +1,260 @@
+/*  LOOT
+
+    A load order optimisation tool for Oblivion, Skyrim, Fallout 3 and
+    Fallout: New Vegas.
+
+    Copyright (C) 2021    WrinklyNinja
+
+    This file is part of LOOT.
+
+    LOOT is free software: you can redistribute
+    it and/or modify it under the terms of the GNU General Public License
+    as published by the Free Software Foundation, either version 3 of
+    the License, or (at your option) any later version.
+
+    LOOT is distributed in the hope that it will
+    be useful, but WITHOUT ANY WARRANTY; without even the implied warranty of
+    MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+    GNU General Public License for more details.
+
+    You should have received a copy of the GNU General Public License
+    along with LOOT.  If not, see
+    <https://www.gnu.org/licenses/>.
+    */
+
+#ifndef LOOT_TESTS_PLUGIN_GENERATOR
+#define LOOT_TESTS_PLUGIN_GENERATOR
+
+#include <cstdint>
+#include <cstring>
+#include <filesystem>
+#include <fstream>
+#include <optional>
+#include <string>
+#include <vector>
+
+#include "loot/enum/game_type.h"
+
+namespace loot {
+namespace test {
+struct GeneratedPlugin {
+  std::vector<std::string> masters;
+  std::string description;
+  size_t newRecordCount = 0;
+  // Overrides are given FormIDs that belong to the first master, so they are
+  // ignored if there are no masters. Morrowind plugins don't have FormIDs, so
+  // their overrides are ignored too.
+  size_t overrideRecordCount = 0;
+  bool isMaster = false;
+  bool isLight = false;
+  // If not given, the game's usual header version is used.
+  std::optional<float> headerVersion;
+  uint32_t firstNewObjectId = 0x800;
+};
+
+class PluginWriter {
+public:
+  explicit PluginWriter(GameType gameType) : gameType_(gameType) {}
+
+  std::string Write(const GeneratedPlugin& plugin) const {
+    if (gameType_ == GameType::tes3) {
+      return WriteMorrowindPlugin(plugin);
+    }
+
+    std::string records;
+    uint32_t nextObjectId = plugin.firstNewObjectId;
+    uint32_t recordCount = 0;
+
+    if (!plugin.masters.empty()) {
+      for (size_t i = 0; i < plugin.overrideRecordCount; ++i) {
+        records += GlobalRecord(0x800 + (uint32_t)i, recordCount);
+        recordCount += 1;
+      }
+    }
+
+    uint32_t modIndex = (uint32_t)plugin.masters.size() << 24;
+    for (size_t i = 0; i < plugin.newRecordCount; ++i) {
+      records += GlobalRecord(modIndex | nextObjectId, recordCount);
+      nextObjectId += 1;
+      recordCount += 1;
+    }
+
+    uint32_t groupCount = 0;
+    if (!records.empty()) {
+      records = Group("GLOB", records);
+      groupCount = 1;
+    }
+
+    std::string hedr;
+    AppendFloat(hedr, plugin.headerVersion.value_or(GetHeaderVersion()));
+    AppendInt(hedr, recordCount + groupCount);
+    AppendInt(hedr, nextObjectId);
+
+    std::string headerData = Subrecord("HEDR", hedr) +
+                             Subrecord("CNAM", ZString("libloot tests"));
+    if (!plugin.description.empty()) {
+      headerData += Subrecord("SNAM", ZString(plugin.description));
+    }
+    for (const auto& master : plugin.masters) {
+      headerData += Subrecord("MAST", ZString(master)) +
+                    Subrecord("DATA", std::string(8, '\0'));
+    }
+
+    uint32_t flags = 0;
+    if (plugin.isMaster) {
+      flags |= 0x1;
+    }
+    if (plugin.isLight) {
+      flags |= 0x200;
+    }
+
+    return Record("TES4", 0, headerData, flags) + records;
+  }
+
+  void Write(const GeneratedPlugin& plugin,
+             const std::filesystem::path& path) const {
+    std::ofstream out(path, std::ios::binary);
+    out << Write(plugin);
+    out.close();
+  }
+
+private:
+  const GameType gameType_;
+
+  bool HasShortHeaders() const { return gameType_ == GameType::tes4; }
+
+  float GetHeaderVersion() const {
+    switch (gameType_) {
+      case GameType::tes4:
+        return 0.8f;
+      case GameType::fonv:
+        return 1.34f;
+      case GameType::fo4:
+      case GameType::fo4vr:
+        return 0.95f;
+      case GameType::tes5se:
+      case GameType::tes5vr:
+        return 1.7f;
+      default:
+        return 0.94f;
+    }
+  }
+
+  std::string Record(const std::string& type,
+                     uint32_t formId,
+                     const std::string& data,
+                     uint32_t flags = 0) const {
+    std::string record = type;
+    AppendInt(record, (uint32_t)data.size());
+    AppendInt(record, flags);
+    AppendInt(record, formId);
+    AppendInt(record, 0);
+    if (!HasShortHeaders()) {
+      AppendInt(record, 0);
+    }
+
+    return record + data;
+  }
+
+  std::string GlobalRecord(uint32_t formId, uint32_t index) const {
+    return Record("GLOB", formId, Subrecord("EDID", EditorId(index)));
+  }
+
+  std::string Group(const std::string& label, const std::string& data) const {
+    const uint32_t headerSize = HasShortHeaders() ? 20 : 24;
+
+    std::string group = "GRUP";
+    AppendInt(group, headerSize + (uint32_t)data.size());
+    group += label;
+    AppendInt(group, 0);
+    AppendInt(group, 0);
+    if (!HasShortHeaders()) {
+      AppendInt(group, 0);
+    }
+
+    return group + data;
+  }
+
+  std::string WriteMorrowindPlugin(const GeneratedPlugin& plugin) const {
+    std::string hedr;
+    AppendFloat(hedr, 1.3f);
+    AppendInt(hedr, plugin.isMaster ? 1 : 0);
+    hedr += FixedString("libloot tests", 32);
+    hedr += FixedString(plugin.description, 256);
+    AppendInt(hedr, (uint32_t)plugin.newRecordCount);
+
+    std::string headerData = MorrowindSubrecord("HEDR", hedr);
+    for (const auto& master : plugin.masters) {
+      headerData += MorrowindSubrecord("MAST", ZString(master)) +
+                    MorrowindSubrecord("DATA", std::string(8, '\0'));
+    }
+
+    std::string data =
+        MorrowindRecord("TES3", headerData, plugin.isMaster ? 0x1 : 0);
+    for (size_t i = 0; i < plugin.newRecordCount; ++i) {
+      data += MorrowindRecord(
+          "GLOB", MorrowindSubrecord("NAME", EditorId((uint32_t)i)));
+    }
+
+    return data;
+  }
+
+  static std::string MorrowindRecord(const std::string& type,
+                                     const std::string& data,
+                                     uint32_t flags = 0) {
+    std::string record = type;
+    AppendInt(record, (uint32_t)data.size());
+    AppendInt(record, 0);
+    AppendInt(record, flags);
+
+    return record + data;
+  }
+
+  static std::string MorrowindSubrecord(const std::string& type,
+                                        const std::string& data) {
+    std::string subrecord = type;
+    AppendInt(subrecord, (uint32_t)data.size());
+
+    return subrecord + data;
+  }
+
+  static std::string Subrecord(const std::string& type,
+                               const std::string& data) {
+    std::string subrecord = type;
+    subrecord += (char)(data.size() & 0xFF);
+    subrecord += (char)((data.size() >> 8) & 0xFF);
+
+    return subrecord + data;
+  }
+
+  static std::string EditorId(uint32_t index) {
+    return ZString("GeneratedRecord" + std::to_string(index));
+  }
+
+  static std::string ZString(const std::string& value) {
+    return value + '\0';
+  }
+
+  static std::string FixedString(const std::string& value, size_t length) {
+    std::string fixed = value.substr(0, length);
+    fixed.resize(length, '\0');
+
+    return fixed;
+  }
+
+  static void AppendInt(std::string& buffer, uint32_t value) {
+    for (int i = 0; i < 4; ++i) {
+      buffer += (char)((value >> (8 * i)) & 0xFF);
+    }
+  }
+
+  static void AppendFloat(std::string& buffer, float value) {
+    uint32_t bits;
+    std::memcpy(&bits, &value, sizeof(bits));
+    AppendInt(buffer, bits);
+  }
+};
+}
+}
+
+#endif